@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::wasm_bindgen;
 
 /// Algorithms that used to sign and verify content
-#[derive(PartialEq, Eq, Serialize, Deserialize, Copy, Clone)]
+#[derive(PartialEq, Eq, Serialize, Deserialize, Copy, Clone, Debug)]
 #[wasm_bindgen]
 pub enum Algorithm {
     /// Sha-256 hash function based HMAC hash algotithm
@@ -31,8 +31,45 @@ pub enum Algorithm {
     ES512,
     /// Elliptic curve with Secp256k1
     ES256K,
+    /// Elliptic curve with brainpoolP256r1 and SHA-256
+    BP256R1,
+    /// Elliptic curve with brainpoolP384r1 and SHA-384
+    BP384R1,
+    /// Elliptic curve with brainpoolP512r1 and SHA-512
+    BP512R1,
     /// Elliptic curve with Ed25519
     EdDSA,
+    /// Module-lattice based ML-DSA-44 (FIPS 204)
+    #[serde(rename = "ML-DSA-44")]
+    MLDSA44,
+    /// Module-lattice based ML-DSA-65 (FIPS 204)
+    #[serde(rename = "ML-DSA-65")]
+    MLDSA65,
+    /// Module-lattice based ML-DSA-87 (FIPS 204)
+    #[serde(rename = "ML-DSA-87")]
+    MLDSA87,
+    /// Composite of ML-DSA-44 and ECDSA P-256 with SHA-256
+    #[serde(rename = "ML-DSA-44-ES256")]
+    MLDSA44ES256,
+    /// Composite of ML-DSA-65 and ECDSA P-256 with SHA-512
+    #[serde(rename = "ML-DSA-65-ES256")]
+    MLDSA65ES256,
+    /// Composite of ML-DSA-87 and ECDSA P-384 with SHA-512
+    #[serde(rename = "ML-DSA-87-ES384")]
+    MLDSA87ES384,
+    /// Composite of ML-DSA-44 and Ed25519 with SHA-512
+    #[serde(rename = "ML-DSA-44-Ed25519")]
+    MLDSA44Ed25519,
+    /// Composite of ML-DSA-65 and Ed25519 with SHA-512
+    #[serde(rename = "ML-DSA-65-Ed25519")]
+    MLDSA65Ed25519,
+    /// Composite of ML-DSA-87 and Ed448 with SHAKE256
+    #[serde(rename = "ML-DSA-87-Ed448")]
+    MLDSA87Ed448,
+    /// BLS12-381 with public keys in G1 and signatures in G2 (IETF BLS, proof of possession)
+    BLS12381MinPk,
+    /// BLS12-381 with public keys in G2 and signatures in G1 (IETF BLS, proof of possession)
+    BLS12381MinSig,
 }
 
 impl Algorithm {
@@ -51,7 +88,21 @@ impl Algorithm {
             Algorithm::ES384 => "ES384",
             Algorithm::ES512 => "ES512",
             Algorithm::ES256K => "ES256K",
+            Algorithm::BP256R1 => "BP256R1",
+            Algorithm::BP384R1 => "BP384R1",
+            Algorithm::BP512R1 => "BP512R1",
             Algorithm::EdDSA => "EdDSA",
+            Algorithm::MLDSA44 => "ML-DSA-44",
+            Algorithm::MLDSA65 => "ML-DSA-65",
+            Algorithm::MLDSA87 => "ML-DSA-87",
+            Algorithm::MLDSA44ES256 => "ML-DSA-44-ES256",
+            Algorithm::MLDSA65ES256 => "ML-DSA-65-ES256",
+            Algorithm::MLDSA87ES384 => "ML-DSA-87-ES384",
+            Algorithm::MLDSA44Ed25519 => "ML-DSA-44-Ed25519",
+            Algorithm::MLDSA65Ed25519 => "ML-DSA-65-Ed25519",
+            Algorithm::MLDSA87Ed448 => "ML-DSA-87-Ed448",
+            Algorithm::BLS12381MinPk => "BLS12381MinPk",
+            Algorithm::BLS12381MinSig => "BLS12381MinSig",
         }
     }
 
@@ -70,7 +121,87 @@ impl Algorithm {
             "ES384" => Some(Algorithm::ES384),
             "ES512" => Some(Algorithm::ES512),
             "ES256K" => Some(Algorithm::ES256K),
+            "BP256R1" => Some(Algorithm::BP256R1),
+            "BP384R1" => Some(Algorithm::BP384R1),
+            "BP512R1" => Some(Algorithm::BP512R1),
             "EdDSA" => Some(Algorithm::EdDSA),
+            "ML-DSA-44" => Some(Algorithm::MLDSA44),
+            "ML-DSA-65" => Some(Algorithm::MLDSA65),
+            "ML-DSA-87" => Some(Algorithm::MLDSA87),
+            "ML-DSA-44-ES256" => Some(Algorithm::MLDSA44ES256),
+            "ML-DSA-65-ES256" => Some(Algorithm::MLDSA65ES256),
+            "ML-DSA-87-ES384" => Some(Algorithm::MLDSA87ES384),
+            "ML-DSA-44-Ed25519" => Some(Algorithm::MLDSA44Ed25519),
+            "ML-DSA-65-Ed25519" => Some(Algorithm::MLDSA65Ed25519),
+            "ML-DSA-87-Ed448" => Some(Algorithm::MLDSA87Ed448),
+            "BLS12381MinPk" => Some(Algorithm::BLS12381MinPk),
+            "BLS12381MinSig" => Some(Algorithm::BLS12381MinSig),
+            _ => None,
+        }
+    }
+
+    /// COSE algorithm identifier (IANA COSE Algorithms registry). Brainpool, composite and
+    /// BLS algorithms don't have registered identifiers yet.
+    pub fn to_cose_id(&self) -> Option<i64> {
+        let id = match self {
+            Algorithm::HS256 => 5,
+            Algorithm::HS384 => 6,
+            Algorithm::HS512 => 7,
+            Algorithm::RS256 => -257,
+            Algorithm::RS384 => -258,
+            Algorithm::RS512 => -259,
+            Algorithm::PS256 => -37,
+            Algorithm::PS384 => -38,
+            Algorithm::PS512 => -39,
+            Algorithm::ES256 => -7,
+            Algorithm::ES384 => -35,
+            Algorithm::ES512 => -36,
+            Algorithm::ES256K => -47,
+            Algorithm::EdDSA => -8,
+            Algorithm::MLDSA44 => -48,
+            Algorithm::MLDSA65 => -49,
+            Algorithm::MLDSA87 => -50,
+            _ => return None,
+        };
+
+        Some(id)
+    }
+
+    /// Algorithm of a COSE algorithm identifier
+    pub fn from_cose_id(id: i64) -> Option<Self> {
+        match id {
+            5 => Some(Algorithm::HS256),
+            6 => Some(Algorithm::HS384),
+            7 => Some(Algorithm::HS512),
+            -257 => Some(Algorithm::RS256),
+            -258 => Some(Algorithm::RS384),
+            -259 => Some(Algorithm::RS512),
+            -37 => Some(Algorithm::PS256),
+            -38 => Some(Algorithm::PS384),
+            -39 => Some(Algorithm::PS512),
+            -7 => Some(Algorithm::ES256),
+            -35 => Some(Algorithm::ES384),
+            -36 => Some(Algorithm::ES512),
+            -47 => Some(Algorithm::ES256K),
+            -8 => Some(Algorithm::EdDSA),
+            -48 => Some(Algorithm::MLDSA44),
+            -49 => Some(Algorithm::MLDSA65),
+            -50 => Some(Algorithm::MLDSA87),
+            _ => None,
+        }
+    }
+
+    /// Output length in bytes of the hash signed by the RSA and ECDSA algorithms. Other
+    /// algorithms don't sign a plain digest.
+    pub fn digest_length(&self) -> Option<usize> {
+        match self {
+            Algorithm::RS256
+            | Algorithm::PS256
+            | Algorithm::ES256
+            | Algorithm::ES256K
+            | Algorithm::BP256R1 => Some(32),
+            Algorithm::RS384 | Algorithm::PS384 | Algorithm::ES384 | Algorithm::BP384R1 => Some(48),
+            Algorithm::RS512 | Algorithm::PS512 | Algorithm::ES512 | Algorithm::BP512R1 => Some(64),
             _ => None,
         }
     }
@@ -90,7 +221,21 @@ impl Algorithm {
             Algorithm::ES384 => AlgorithmFamily::EC,
             Algorithm::ES512 => AlgorithmFamily::EC,
             Algorithm::ES256K => AlgorithmFamily::EC,
+            Algorithm::BP256R1 => AlgorithmFamily::EC,
+            Algorithm::BP384R1 => AlgorithmFamily::EC,
+            Algorithm::BP512R1 => AlgorithmFamily::EC,
             Algorithm::EdDSA => AlgorithmFamily::OKP,
+            Algorithm::MLDSA44 => AlgorithmFamily::MLDSA,
+            Algorithm::MLDSA65 => AlgorithmFamily::MLDSA,
+            Algorithm::MLDSA87 => AlgorithmFamily::MLDSA,
+            Algorithm::MLDSA44ES256 => AlgorithmFamily::Composite,
+            Algorithm::MLDSA65ES256 => AlgorithmFamily::Composite,
+            Algorithm::MLDSA87ES384 => AlgorithmFamily::Composite,
+            Algorithm::MLDSA44Ed25519 => AlgorithmFamily::Composite,
+            Algorithm::MLDSA65Ed25519 => AlgorithmFamily::Composite,
+            Algorithm::MLDSA87Ed448 => AlgorithmFamily::Composite,
+            Algorithm::BLS12381MinPk => AlgorithmFamily::BLS,
+            Algorithm::BLS12381MinSig => AlgorithmFamily::BLS,
         }
     }
 }
@@ -106,6 +251,9 @@ pub enum AlgorithmFamily {
     /// [`crate::algorithms::Algorithm::ES384`]
     /// [`crate::algorithms::Algorithm::ES512`]
     /// [`crate::algorithms::Algorithm::ES256K`]
+    /// [`crate::algorithms::Algorithm::BP256R1`]
+    /// [`crate::algorithms::Algorithm::BP384R1`]
+    /// [`crate::algorithms::Algorithm::BP512R1`]
     EC,
     /// [`crate::algorithms::Algorithm::RS256`]
     /// [`crate::algorithms::Algorithm::RS384`]
@@ -116,6 +264,20 @@ pub enum AlgorithmFamily {
     RSA,
     /// [`crate::algorithms::Algorithm::EdDSA`]
     OKP,
+    /// [`crate::algorithms::Algorithm::MLDSA44`]
+    /// [`crate::algorithms::Algorithm::MLDSA65`]
+    /// [`crate::algorithms::Algorithm::MLDSA87`]
+    MLDSA,
+    /// [`crate::algorithms::Algorithm::MLDSA44ES256`]
+    /// [`crate::algorithms::Algorithm::MLDSA65ES256`]
+    /// [`crate::algorithms::Algorithm::MLDSA87ES384`]
+    /// [`crate::algorithms::Algorithm::MLDSA44Ed25519`]
+    /// [`crate::algorithms::Algorithm::MLDSA65Ed25519`]
+    /// [`crate::algorithms::Algorithm::MLDSA87Ed448`]
+    Composite,
+    /// [`crate::algorithms::Algorithm::BLS12381MinPk`]
+    /// [`crate::algorithms::Algorithm::BLS12381MinSig`]
+    BLS,
     None,
 }
 
@@ -126,6 +288,9 @@ impl AlgorithmFamily {
             AlgorithmFamily::EC => "EC",
             AlgorithmFamily::RSA => "RSA",
             AlgorithmFamily::OKP => "OKP",
+            AlgorithmFamily::MLDSA => "ML-DSA",
+            AlgorithmFamily::Composite => "Composite",
+            AlgorithmFamily::BLS => "BLS",
             _ => "None",
         }
     }
@@ -0,0 +1,646 @@
+use crate::{
+    algorithms::{Algorithm, AlgorithmFamily},
+    crypto::{
+        bls::{BlsVariant, BlsVerifyingKey},
+        composite::CompositeVerifyingKey,
+        ecdsa::{
+            brainpool::{BrainpoolCurve, BrainpoolSigningKey, BrainpoolVerifyingKey},
+            _256k::{P256kSigningKey, P256kVerifyingKey},
+            _256::{P256SigningKey, P256VerifyingKey},
+            _384::{P384SigningKey, P384VerifyingKey},
+            _512::{P512SigningKey, P512VerifyingKey},
+        },
+        ed448::{Ed448SigningKey, Ed448VerifyingKey},
+        eddsa::{EDDSASigningKey, EDDSAVerifyingKey},
+        mldsa::{MlDsaParameterSet, MlDsaSigningKey, MlDsaVerifyingKey},
+        policy::KeyPolicy,
+        rsa::{RsaSigningKey, RsaVerifyingKey},
+        SignFromKey, VerifyFromKey,
+    },
+    jwk::Jwk,
+};
+use elliptic_curve::pkcs8::{
+    der::pem::{self, LineEnding},
+    spki::SubjectPublicKeyInfoRef,
+    EncodePublicKey, ObjectIdentifier, PrivateKeyInfo,
+};
+use fi_common::error::Error;
+
+const OID_RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+const OID_RSASSA_PSS: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.10");
+const OID_EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+const OID_ED25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+const OID_ED448: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.113");
+
+const OID_NIST_P256: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+const OID_SECP256K1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.10");
+const OID_NIST_P384: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+const OID_NIST_P521: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.35");
+
+const PEM_PRIVATE_KEY: &str = "PRIVATE KEY";
+const PEM_RSA_PRIVATE_KEY: &str = "RSA PRIVATE KEY";
+const PEM_EC_PRIVATE_KEY: &str = "EC PRIVATE KEY";
+const PEM_PUBLIC_KEY: &str = "PUBLIC KEY";
+const PEM_RSA_PUBLIC_KEY: &str = "RSA PUBLIC KEY";
+
+/// Key type and curve detected from the key encoding
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum KeyKind {
+    Rsa,
+    RsaPss,
+    P256,
+    P256k,
+    P384,
+    P512,
+    Brainpool(BrainpoolCurve),
+    Ed25519,
+    Ed448,
+    MlDsa(MlDsaParameterSet),
+    Composite(Algorithm),
+    Bls(BlsVariant),
+}
+
+impl KeyKind {
+    fn from_oids(
+        algorithm: ObjectIdentifier,
+        parameters: Option<ObjectIdentifier>,
+    ) -> Result<KeyKind, Error> {
+        if algorithm == OID_RSA_ENCRYPTION {
+            Ok(KeyKind::Rsa)
+        } else if algorithm == OID_ED25519 {
+            Ok(KeyKind::Ed25519)
+        } else if algorithm == OID_ED448 {
+            Ok(KeyKind::Ed448)
+        } else if algorithm == OID_EC_PUBLIC_KEY {
+            match parameters {
+                Some(curve) => KeyKind::from_curve_oid(curve),
+                None => Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE)),
+            }
+        } else if let Some(parameter_set) = MlDsaParameterSet::from_oid(algorithm) {
+            Ok(KeyKind::MlDsa(parameter_set))
+        } else {
+            fi_common::logger::error(format!("Unknown key algorithm OID {}", algorithm).as_str());
+            Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE))
+        }
+    }
+
+    fn from_curve_oid(curve: ObjectIdentifier) -> Result<KeyKind, Error> {
+        if curve == OID_NIST_P256 {
+            Ok(KeyKind::P256)
+        } else if curve == OID_SECP256K1 {
+            Ok(KeyKind::P256k)
+        } else if curve == OID_NIST_P384 {
+            Ok(KeyKind::P384)
+        } else if curve == OID_NIST_P521 {
+            Ok(KeyKind::P512)
+        } else if let Some(brainpool_curve) = BrainpoolCurve::from_oid(curve) {
+            Ok(KeyKind::Brainpool(brainpool_curve))
+        } else {
+            fi_common::logger::error(format!("Unknown curve OID {}", curve).as_str());
+            Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE))
+        }
+    }
+
+    fn from_private_key_der(der: &[u8]) -> Result<KeyKind, Error> {
+        let key_info = match PrivateKeyInfo::try_from(der) {
+            Ok(val) => val,
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+            }
+        };
+
+        // RSASSA-PSS-params aren't an object identifier
+        if key_info.algorithm.oid == OID_RSASSA_PSS {
+            return Ok(KeyKind::RsaPss);
+        }
+
+        match key_info.algorithm.oids() {
+            Ok((algorithm, parameters)) => KeyKind::from_oids(algorithm, parameters),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR))
+            }
+        }
+    }
+
+    fn from_sec1_der(der: &[u8]) -> Result<KeyKind, Error> {
+        let ec_key = match sec1::EcPrivateKey::try_from(der) {
+            Ok(val) => val,
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                return Err(Error::new(crate::errors::EC_PEM_ERROR));
+            }
+        };
+
+        match ec_key.parameters.and_then(|params| params.named_curve()) {
+            Some(curve) => KeyKind::from_curve_oid(curve),
+            None => Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE)),
+        }
+    }
+
+    fn from_public_key_der(der: &[u8]) -> Result<KeyKind, Error> {
+        let key_info = match SubjectPublicKeyInfoRef::try_from(der) {
+            Ok(val) => val,
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR));
+            }
+        };
+
+        // RSASSA-PSS-params aren't an object identifier
+        if key_info.algorithm.oid == OID_RSASSA_PSS {
+            return Ok(KeyKind::RsaPss);
+        }
+
+        match key_info.algorithm.oids() {
+            Ok((algorithm, parameters)) => KeyKind::from_oids(algorithm, parameters),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR))
+            }
+        }
+    }
+
+    fn algorithms(&self) -> Vec<Algorithm> {
+        match self {
+            KeyKind::Rsa => vec![
+                Algorithm::RS256,
+                Algorithm::RS384,
+                Algorithm::RS512,
+                Algorithm::PS256,
+                Algorithm::PS384,
+                Algorithm::PS512,
+            ],
+            KeyKind::RsaPss => vec![Algorithm::PS256, Algorithm::PS384, Algorithm::PS512],
+            KeyKind::P256 => vec![Algorithm::ES256],
+            KeyKind::P256k => vec![Algorithm::ES256K],
+            KeyKind::P384 => vec![Algorithm::ES384],
+            KeyKind::P512 => vec![Algorithm::ES512],
+            KeyKind::Brainpool(curve) => vec![curve.algorithm()],
+            KeyKind::Ed25519 => vec![Algorithm::EdDSA],
+            KeyKind::Ed448 => vec![Algorithm::EdDSA],
+            KeyKind::MlDsa(parameter_set) => vec![parameter_set.algorithm()],
+            KeyKind::Composite(alg) => vec![*alg],
+            KeyKind::Bls(variant) => vec![variant.algorithm()],
+        }
+    }
+}
+
+fn decode_pem(key_str: &str) -> Result<(String, Vec<u8>), Error> {
+    match pem::decode_vec(key_str.trim().as_bytes()) {
+        Ok((label, der)) => Ok((String::from(label), der)),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::DECODING_ERROR))
+        }
+    }
+}
+
+fn encode_pem(label: &str, der: &[u8]) -> Result<String, Error> {
+    match pem::encode_string(label, LineEnding::LF, der) {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::ENCODING_ERROR))
+        }
+    }
+}
+
+fn decode_jwk_field(value: &Option<String>) -> Result<Vec<u8>, Error> {
+    let value = match value {
+        Some(val) => val,
+        None => return Err(Error::new(crate::errors::MISSING_FIELD)),
+    };
+
+    match base64_url::decode(value.as_bytes()) {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::DECODING_ERROR))
+        }
+    }
+}
+
+/// Signing key of any supported key type. The key type and curve are detected
+/// from the PEM label or the <b>pkcs8</b> algorithm OID.
+pub enum AnySigningKey {
+    /// RSA private key
+    Rsa(RsaSigningKey),
+    /// NistP256 private key
+    P256(P256SigningKey),
+    /// Secp256k1 private key
+    P256k(P256kSigningKey),
+    /// NistP384 private key
+    P384(P384SigningKey),
+    /// NistP521 private key
+    P512(P512SigningKey),
+    /// Brainpool private key
+    Brainpool(BrainpoolSigningKey),
+    /// Ed25519 private key
+    EdDSA(EDDSASigningKey),
+    /// Ed448 private key
+    Ed448(Ed448SigningKey),
+    /// ML-DSA private key
+    MlDsa(MlDsaSigningKey),
+}
+
+impl AnySigningKey {
+    /// Create signing key from a pem formatted private key. <b>pkcs8</b>, <b>pkcs1</b> (RSA)
+    /// and <b>sec1</b> (EC) are detected from the pem label.
+    pub fn from_pem(key_str: &str) -> Result<AnySigningKey, Error> {
+        let (label, der) = match decode_pem(key_str) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let kind = match label.as_str() {
+            PEM_PRIVATE_KEY => KeyKind::from_private_key_der(&der),
+            PEM_RSA_PRIVATE_KEY => Ok(KeyKind::Rsa),
+            PEM_EC_PRIVATE_KEY => KeyKind::from_sec1_der(&der),
+            _ => {
+                fi_common::logger::error(format!("Unknown pem label {}", label).as_str());
+                Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE))
+            }
+        };
+
+        match kind {
+            Ok(val) => AnySigningKey::from_kind(val, key_str),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create signing key from a <b>pkcs8</b> DER encoded private key.
+    pub fn from_der(bytes: &[u8]) -> Result<AnySigningKey, Error> {
+        let kind = match KeyKind::from_private_key_der(bytes) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let key_str = match encode_pem(PEM_PRIVATE_KEY, bytes) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        AnySigningKey::from_kind(kind, key_str.as_str())
+    }
+
+    fn from_kind(kind: KeyKind, key_str: &str) -> Result<AnySigningKey, Error> {
+        match kind {
+            KeyKind::Rsa | KeyKind::RsaPss => {
+                RsaSigningKey::from_pem(key_str).map(AnySigningKey::Rsa)
+            }
+            KeyKind::P256 => P256SigningKey::from_pem(key_str).map(AnySigningKey::P256),
+            KeyKind::P256k => P256kSigningKey::from_pem(key_str).map(AnySigningKey::P256k),
+            KeyKind::P384 => P384SigningKey::from_pem(key_str).map(AnySigningKey::P384),
+            KeyKind::P512 => P512SigningKey::from_pem(key_str).map(AnySigningKey::P512),
+            KeyKind::Brainpool(_) => {
+                BrainpoolSigningKey::from_pem(key_str).map(AnySigningKey::Brainpool)
+            }
+            KeyKind::Ed25519 => EDDSASigningKey::from_pem(key_str).map(AnySigningKey::EdDSA),
+            KeyKind::Ed448 => Ed448SigningKey::from_pem(key_str).map(AnySigningKey::Ed448),
+            KeyKind::MlDsa(_) => MlDsaSigningKey::from_pem(key_str).map(AnySigningKey::MlDsa),
+            KeyKind::Composite(_) | KeyKind::Bls(_) => {
+                Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE))
+            }
+        }
+    }
+
+    fn kind(&self) -> KeyKind {
+        match self {
+            AnySigningKey::Rsa(key) if key.is_pss_key() => KeyKind::RsaPss,
+            AnySigningKey::Rsa(_) => KeyKind::Rsa,
+            AnySigningKey::P256(_) => KeyKind::P256,
+            AnySigningKey::P256k(_) => KeyKind::P256k,
+            AnySigningKey::P384(_) => KeyKind::P384,
+            AnySigningKey::P512(_) => KeyKind::P512,
+            AnySigningKey::Brainpool(key) => KeyKind::Brainpool(key.curve()),
+            AnySigningKey::EdDSA(_) => KeyKind::Ed25519,
+            AnySigningKey::Ed448(_) => KeyKind::Ed448,
+            AnySigningKey::MlDsa(key) => KeyKind::MlDsa(key.parameter_set()),
+        }
+    }
+
+    /// Algorithms that can be used with this key
+    pub fn supported_algorithms(&self) -> Vec<Algorithm> {
+        self.kind().algorithms()
+    }
+
+    /// Check if the key can be used with the provided algorithm
+    pub fn supports(&self, alg: Algorithm) -> bool {
+        self.supported_algorithms().contains(&alg)
+    }
+
+    /// Algorithm family of the key
+    pub fn get_family(&self) -> AlgorithmFamily {
+        self.supported_algorithms()[0].get_family()
+    }
+}
+
+impl SignFromKey for AnySigningKey {
+    fn sign_bytes(&self, content: &[u8], alg: Algorithm) -> Result<Vec<u8>, Error> {
+        if !self.supports(alg) {
+            return Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH));
+        }
+
+        match self {
+            AnySigningKey::Rsa(key) => key.sign_bytes(content, alg),
+            AnySigningKey::P256(key) => key.sign_bytes(content, alg),
+            AnySigningKey::P256k(key) => key.sign_bytes(content, alg),
+            AnySigningKey::P384(key) => key.sign_bytes(content, alg),
+            AnySigningKey::P512(key) => key.sign_bytes(content, alg),
+            AnySigningKey::Brainpool(key) => key.sign_bytes(content, alg),
+            AnySigningKey::EdDSA(key) => key.sign_bytes(content, alg),
+            AnySigningKey::Ed448(key) => key.sign_bytes(content, alg),
+            AnySigningKey::MlDsa(key) => key.sign_bytes(content, alg),
+        }
+    }
+}
+
+/// Verifying key of any supported key type. The key type and curve are detected
+/// from the PEM label or the <b>spki</b> algorithm OID.
+pub enum AnyVerifyingKey {
+    /// RSA public key
+    Rsa(RsaVerifyingKey),
+    /// NistP256 public key
+    P256(P256VerifyingKey),
+    /// Secp256k1 public key
+    P256k(P256kVerifyingKey),
+    /// NistP384 public key
+    P384(P384VerifyingKey),
+    /// NistP521 public key
+    P512(P512VerifyingKey),
+    /// Brainpool public key
+    Brainpool(BrainpoolVerifyingKey),
+    /// Ed25519 public key
+    EdDSA(EDDSAVerifyingKey),
+    /// Ed448 public key
+    Ed448(Ed448VerifyingKey),
+    /// ML-DSA public key
+    MlDsa(MlDsaVerifyingKey),
+    /// Composite ML-DSA public key
+    Composite(CompositeVerifyingKey),
+    /// BLS12-381 public key
+    Bls(BlsVerifyingKey),
+}
+
+impl AnyVerifyingKey {
+    /// Create verifying key from a pem formatted public key. <b>spki</b> and <b>pkcs1</b> (RSA)
+    /// are detected from the pem label.
+    pub fn from_pem(key_str: &str) -> Result<AnyVerifyingKey, Error> {
+        let (label, der) = match decode_pem(key_str) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let kind = match label.as_str() {
+            PEM_PUBLIC_KEY => KeyKind::from_public_key_der(&der),
+            PEM_RSA_PUBLIC_KEY => Ok(KeyKind::Rsa),
+            _ => {
+                fi_common::logger::error(format!("Unknown pem label {}", label).as_str());
+                Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE))
+            }
+        };
+
+        match kind {
+            Ok(val) => AnyVerifyingKey::from_kind(val, key_str),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create verifying key from a <b>spki</b> DER encoded public key.
+    pub fn from_der(bytes: &[u8]) -> Result<AnyVerifyingKey, Error> {
+        let kind = match KeyKind::from_public_key_der(bytes) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let key_str = match encode_pem(PEM_PUBLIC_KEY, bytes) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        AnyVerifyingKey::from_kind(kind, key_str.as_str())
+    }
+
+    /// Create verifying key from a public JWK. <b>RSA</b>, <b>EC</b>, <b>OKP</b> (Ed25519,
+    /// Ed448 and BLS12-381) and <b>AKP</b> (ML-DSA and composite ML-DSA) keys are supported.
+    /// Composite keys need the <b>alg</b> field.
+    pub fn from_jwk(jwk: &Jwk) -> Result<AnyVerifyingKey, Error> {
+        match jwk.kty.as_str() {
+            "RSA" => {
+                let (n, e) = match (decode_jwk_field(&jwk.n), decode_jwk_field(&jwk.e)) {
+                    (Ok(n), Ok(e)) => (n, e),
+                    (Err(error), _) | (_, Err(error)) => return Err(error),
+                };
+
+                let public_key = match rsa::RsaPublicKey::new(
+                    rsa::BigUint::from_bytes_be(&n),
+                    rsa::BigUint::from_bytes_be(&e),
+                ) {
+                    Ok(val) => val,
+                    Err(error) => {
+                        fi_common::logger::error(error.to_string().as_str());
+                        return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR));
+                    }
+                };
+
+                match public_key.to_public_key_der() {
+                    Ok(der) => {
+                        RsaVerifyingKey::from_bytes(der.as_bytes()).map(AnyVerifyingKey::Rsa)
+                    }
+                    Err(error) => {
+                        fi_common::logger::error(error.to_string().as_str());
+                        Err(Error::new(crate::errors::ENCODING_ERROR))
+                    }
+                }
+            }
+            "EC" => {
+                let (x, y) = match (decode_jwk_field(&jwk.x), decode_jwk_field(&jwk.y)) {
+                    (Ok(x), Ok(y)) => (x, y),
+                    (Err(error), _) | (_, Err(error)) => return Err(error),
+                };
+
+                let mut point = vec![0x04];
+                point.extend_from_slice(&x);
+                point.extend_from_slice(&y);
+
+                match jwk.crv.as_deref() {
+                    Some("P-256") => {
+                        P256VerifyingKey::from_bytes(&point).map(AnyVerifyingKey::P256)
+                    }
+                    Some("secp256k1") => {
+                        P256kVerifyingKey::from_bytes(&point).map(AnyVerifyingKey::P256k)
+                    }
+                    Some("P-384") => {
+                        P384VerifyingKey::from_bytes(&point).map(AnyVerifyingKey::P384)
+                    }
+                    Some("P-521") => {
+                        P512VerifyingKey::from_bytes(&point).map(AnyVerifyingKey::P512)
+                    }
+                    Some("brainpoolP256r1") => {
+                        BrainpoolVerifyingKey::from_bytes(BrainpoolCurve::P256r1, &point)
+                            .map(AnyVerifyingKey::Brainpool)
+                    }
+                    Some("brainpoolP384r1") => {
+                        BrainpoolVerifyingKey::from_bytes(BrainpoolCurve::P384r1, &point)
+                            .map(AnyVerifyingKey::Brainpool)
+                    }
+                    Some("brainpoolP512r1") => {
+                        BrainpoolVerifyingKey::from_bytes(BrainpoolCurve::P512r1, &point)
+                            .map(AnyVerifyingKey::Brainpool)
+                    }
+                    _ => Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE)),
+                }
+            }
+            "OKP" => {
+                let mut x = match decode_jwk_field(&jwk.x) {
+                    Ok(val) => val,
+                    Err(error) => return Err(error),
+                };
+
+                match jwk.crv.as_deref() {
+                    Some("Ed25519") => {
+                        EDDSAVerifyingKey::from_bytes(&mut x).map(AnyVerifyingKey::EdDSA)
+                    }
+                    Some("Ed448") => {
+                        Ed448VerifyingKey::from_bytes(&mut x).map(AnyVerifyingKey::Ed448)
+                    }
+                    Some("BLS12381G1") if x.len() == BlsVariant::MinPk.public_key_length() => {
+                        BlsVerifyingKey::from_bytes(&x).map(AnyVerifyingKey::Bls)
+                    }
+                    Some("BLS12381G2") if x.len() == BlsVariant::MinSig.public_key_length() => {
+                        BlsVerifyingKey::from_bytes(&x).map(AnyVerifyingKey::Bls)
+                    }
+                    _ => Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE)),
+                }
+            }
+            "AKP" => {
+                let public = match decode_jwk_field(&jwk.public) {
+                    Ok(val) => val,
+                    Err(error) => return Err(error),
+                };
+
+                let alg = jwk.alg.as_deref().map(Algorithm::from_str);
+                if let Some(Some(alg)) = alg {
+                    if let AlgorithmFamily::Composite = alg.get_family() {
+                        return CompositeVerifyingKey::from_bytes(alg, &public)
+                            .map(AnyVerifyingKey::Composite);
+                    }
+                }
+
+                let key = match MlDsaVerifyingKey::from_bytes(&public) {
+                    Ok(val) => val,
+                    Err(error) => return Err(error),
+                };
+
+                match alg {
+                    Some(alg) if alg != Some(key.parameter_set().algorithm()) => {
+                        Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH))
+                    }
+                    _ => Ok(AnyVerifyingKey::MlDsa(key)),
+                }
+            }
+            _ => {
+                fi_common::logger::error(format!("Unknown JWK key type {}", jwk.kty).as_str());
+                Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE))
+            }
+        }
+    }
+
+    fn from_kind(kind: KeyKind, key_str: &str) -> Result<AnyVerifyingKey, Error> {
+        match kind {
+            KeyKind::Rsa | KeyKind::RsaPss => {
+                RsaVerifyingKey::from_pem(key_str).map(AnyVerifyingKey::Rsa)
+            }
+            KeyKind::P256 => P256VerifyingKey::from_pem(key_str).map(AnyVerifyingKey::P256),
+            KeyKind::P256k => P256kVerifyingKey::from_pem(key_str).map(AnyVerifyingKey::P256k),
+            KeyKind::P384 => P384VerifyingKey::from_pem(key_str).map(AnyVerifyingKey::P384),
+            KeyKind::P512 => P512VerifyingKey::from_pem(key_str).map(AnyVerifyingKey::P512),
+            KeyKind::Brainpool(_) => {
+                BrainpoolVerifyingKey::from_pem(key_str).map(AnyVerifyingKey::Brainpool)
+            }
+            KeyKind::Ed25519 => EDDSAVerifyingKey::from_pem(key_str).map(AnyVerifyingKey::EdDSA),
+            KeyKind::Ed448 => Ed448VerifyingKey::from_pem(key_str).map(AnyVerifyingKey::Ed448),
+            KeyKind::MlDsa(_) => MlDsaVerifyingKey::from_pem(key_str).map(AnyVerifyingKey::MlDsa),
+            KeyKind::Composite(_) | KeyKind::Bls(_) => {
+                Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE))
+            }
+        }
+    }
+
+    fn kind(&self) -> KeyKind {
+        match self {
+            AnyVerifyingKey::Rsa(key) if key.is_pss_key() => KeyKind::RsaPss,
+            AnyVerifyingKey::Rsa(_) => KeyKind::Rsa,
+            AnyVerifyingKey::P256(_) => KeyKind::P256,
+            AnyVerifyingKey::P256k(_) => KeyKind::P256k,
+            AnyVerifyingKey::P384(_) => KeyKind::P384,
+            AnyVerifyingKey::P512(_) => KeyKind::P512,
+            AnyVerifyingKey::Brainpool(key) => KeyKind::Brainpool(key.curve()),
+            AnyVerifyingKey::EdDSA(_) => KeyKind::Ed25519,
+            AnyVerifyingKey::Ed448(_) => KeyKind::Ed448,
+            AnyVerifyingKey::MlDsa(key) => KeyKind::MlDsa(key.parameter_set()),
+            AnyVerifyingKey::Composite(key) => KeyKind::Composite(key.algorithm()),
+            AnyVerifyingKey::Bls(key) => KeyKind::Bls(key.variant()),
+        }
+    }
+
+    /// Algorithms that can be used with this key
+    pub fn supported_algorithms(&self) -> Vec<Algorithm> {
+        self.kind().algorithms()
+    }
+
+    /// Check if the key can be used with the provided algorithm
+    pub fn supports(&self, alg: Algorithm) -> bool {
+        self.supported_algorithms().contains(&alg)
+    }
+
+    /// Algorithm family of the key
+    pub fn get_family(&self) -> AlgorithmFamily {
+        self.supported_algorithms()[0].get_family()
+    }
+}
+
+impl VerifyFromKey for AnyVerifyingKey {
+    fn verify_bytes(
+        &self,
+        content: &[u8],
+        signature: &[u8],
+        alg: Algorithm,
+    ) -> Result<bool, Error> {
+        if !self.supports(alg) {
+            return Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH));
+        }
+
+        match self {
+            AnyVerifyingKey::Rsa(key) => key.verify_bytes(content, signature, alg),
+            AnyVerifyingKey::P256(key) => key.verify_bytes(content, signature, alg),
+            AnyVerifyingKey::P256k(key) => key.verify_bytes(content, signature, alg),
+            AnyVerifyingKey::P384(key) => key.verify_bytes(content, signature, alg),
+            AnyVerifyingKey::P512(key) => key.verify_bytes(content, signature, alg),
+            AnyVerifyingKey::Brainpool(key) => key.verify_bytes(content, signature, alg),
+            AnyVerifyingKey::EdDSA(key) => key.verify_bytes(content, signature, alg),
+            AnyVerifyingKey::Ed448(key) => key.verify_bytes(content, signature, alg),
+            AnyVerifyingKey::MlDsa(key) => key.verify_bytes(content, signature, alg),
+            AnyVerifyingKey::Composite(key) => key.verify_bytes(content, signature, alg),
+            AnyVerifyingKey::Bls(key) => key.verify_bytes(content, signature, alg),
+        }
+    }
+
+    fn check_key_policy(&self, policy: &KeyPolicy) -> Result<(), Error> {
+        match self {
+            AnyVerifyingKey::Rsa(key) => key.check_key_policy(policy),
+            AnyVerifyingKey::P256(key) => key.check_key_policy(policy),
+            AnyVerifyingKey::P256k(key) => key.check_key_policy(policy),
+            AnyVerifyingKey::P384(key) => key.check_key_policy(policy),
+            AnyVerifyingKey::P512(key) => key.check_key_policy(policy),
+            AnyVerifyingKey::Brainpool(key) => key.check_key_policy(policy),
+            AnyVerifyingKey::EdDSA(key) => key.check_key_policy(policy),
+            AnyVerifyingKey::Ed448(key) => key.check_key_policy(policy),
+            AnyVerifyingKey::MlDsa(key) => key.check_key_policy(policy),
+            AnyVerifyingKey::Composite(key) => key.check_key_policy(policy),
+            AnyVerifyingKey::Bls(key) => key.check_key_policy(policy),
+        }
+    }
+}
@@ -0,0 +1,688 @@
+use bls12_381::{
+    hash_to_curve::{ExpandMessageState, ExpandMsgXmd, HashToCurve, InitExpandMessage},
+    multi_miller_loop, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective, Gt, Scalar,
+};
+use fi_common::error::Error;
+use rand::{rngs::OsRng, RngCore};
+use zeroize::Zeroizing;
+
+use super::policy::{get_key_policy, Curve};
+
+/// Length of the raw private key
+pub const PRIVATE_KEY_LENGTH: usize = 32;
+
+/// Length of the compressed public key
+pub const PUBLIC_KEY_LENGTH: usize = 96;
+
+/// Length of a signature, the compressed point `A` followed by the scalar `e`
+pub const SIGNATURE_LENGTH: usize = 80;
+
+/// Minimum length of the key material of [`BbsSigningKey::from_key_material`]
+pub const MIN_KEY_MATERIAL_LENGTH: usize = 32;
+
+const API_ID: &[u8] = b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_H2G_HM2S_";
+
+const EXPAND_LEN: usize = 48;
+const SCALAR_LENGTH: usize = 32;
+const POINT_LENGTH: usize = 48;
+
+/// Proof length without the undisclosed message commitments: Abar, Bbar, D, e^, r1^, r3^ and
+/// the challenge
+const PROOF_BASE_LENGTH: usize = 3 * POINT_LENGTH + 4 * SCALAR_LENGTH;
+
+/// Base point of the BLS12-381-SHA-256 ciphersuite, the generator of the
+/// `BP_MESSAGE_GENERATOR_SEED` seed
+const P1: [u8; POINT_LENGTH] = [
+    0xa8, 0xce, 0x25, 0x61, 0x02, 0x84, 0x08, 0x21, 0xa3, 0xe9, 0x4e, 0xa9, 0x02, 0x5e, 0x46, 0x62,
+    0xb2, 0x05, 0x76, 0x2f, 0x97, 0x76, 0xb3, 0xa7, 0x66, 0xc8, 0x72, 0xb9, 0x48, 0xf1, 0xfd, 0x22,
+    0x5e, 0x7c, 0x59, 0x69, 0x85, 0x88, 0xe7, 0x0d, 0x11, 0x40, 0x6d, 0x16, 0x1b, 0x4e, 0x28, 0xc9,
+];
+
+/// Proof of knowledge of a signature, with the undisclosed message commitments in the order of
+/// the undisclosed indexes
+struct Proof {
+    a_bar: G1Affine,
+    b_bar: G1Affine,
+    d: G1Affine,
+    e_hat: Scalar,
+    r1_hat: Scalar,
+    r3_hat: Scalar,
+    commitments: Vec<Scalar>,
+    challenge: Scalar,
+}
+
+/// Output of ProofInit and ProofVerifyInit
+struct ProofInit {
+    a_bar: G1Affine,
+    b_bar: G1Affine,
+    d: G1Affine,
+    t1: G1Affine,
+    t2: G1Affine,
+    domain: Scalar,
+}
+
+fn api_dst(suffix: &[u8]) -> Vec<u8> {
+    let mut dst = API_ID.to_vec();
+    dst.extend_from_slice(suffix);
+    dst
+}
+
+fn expand_message(message: &[u8], dst: &[u8], length: usize) -> Vec<u8> {
+    let mut output = vec![0u8; length];
+    let mut expander =
+        <ExpandMsgXmd<sha2_09::Sha256> as InitExpandMessage>::init_expand(message, dst, length);
+    expander.read_into(&mut output);
+    output
+}
+
+/// OS2IP of 48 big endian bytes modulo r
+fn scalar_from_wide(bytes: &[u8]) -> Scalar {
+    // the wide reduction takes 64 little endian bytes
+    let mut wide = Zeroizing::new([0u8; 64]);
+    for (index, byte) in bytes.iter().rev().enumerate() {
+        wide[index] = *byte;
+    }
+
+    Scalar::from_bytes_wide(&wide)
+}
+
+fn hash_to_scalar(message: &[u8], dst: &[u8]) -> Scalar {
+    scalar_from_wide(&expand_message(message, dst, EXPAND_LEN))
+}
+
+fn scalar_to_bytes(scalar: &Scalar) -> [u8; SCALAR_LENGTH] {
+    let mut bytes = scalar.to_bytes();
+    bytes.reverse();
+    bytes
+}
+
+/// Big endian scalar, rejecting zero and values of at least r
+fn scalar_from_bytes(bytes: &[u8]) -> Option<Scalar> {
+    if bytes.len() != SCALAR_LENGTH {
+        return None;
+    }
+
+    let mut le_bytes = Zeroizing::new([0u8; SCALAR_LENGTH]);
+    for (index, byte) in bytes.iter().rev().enumerate() {
+        le_bytes[index] = *byte;
+    }
+
+    Option::<Scalar>::from(Scalar::from_bytes(&le_bytes)).filter(|val| *val != Scalar::zero())
+}
+
+/// Compressed G1 point in the prime order subgroup, rejecting the identity
+fn g1_from_bytes(bytes: &[u8]) -> Option<G1Affine> {
+    let mut compressed = [0u8; POINT_LENGTH];
+    if bytes.len() != POINT_LENGTH {
+        return None;
+    }
+    compressed.copy_from_slice(bytes);
+
+    Option::<G1Affine>::from(G1Affine::from_compressed(&compressed))
+        .filter(|point| !bool::from(point.is_identity()))
+}
+
+fn random_scalars(count: usize) -> Vec<Scalar> {
+    (0..count)
+        .map(|_| {
+            let mut bytes = Zeroizing::new([0u8; EXPAND_LEN]);
+            OsRng.fill_bytes(bytes.as_mut_slice());
+            scalar_from_wide(bytes.as_slice())
+        })
+        .collect()
+}
+
+fn pairing_product_is_identity(terms: &[(G1Affine, G2Affine)]) -> bool {
+    let prepared: Vec<(G1Affine, G2Prepared)> = terms
+        .iter()
+        .map(|(g1, g2)| (*g1, G2Prepared::from(*g2)))
+        .collect();
+    let refs: Vec<(&G1Affine, &G2Prepared)> = prepared.iter().map(|(g1, g2)| (g1, g2)).collect();
+
+    multi_miller_loop(refs.as_slice()).final_exponentiation() == Gt::identity()
+}
+
+/// create_generators of the draft: the domain generator Q_1 followed by one generator per
+/// message
+fn create_generators(count: usize) -> Vec<G1Affine> {
+    let seed_dst = api_dst(b"SIG_GENERATOR_SEED_");
+    let generator_dst = api_dst(b"SIG_GENERATOR_DST_");
+
+    let mut v = expand_message(&api_dst(b"MESSAGE_GENERATOR_SEED"), &seed_dst, EXPAND_LEN);
+    let mut generators = Vec::with_capacity(count);
+    for index in 1..=count {
+        v.extend_from_slice(&(index as u64).to_be_bytes());
+        v = expand_message(&v, &seed_dst, EXPAND_LEN);
+        generators.push(G1Affine::from(<G1Projective as HashToCurve<
+            ExpandMsgXmd<sha2_09::Sha256>,
+        >>::hash_to_curve(&v, &generator_dst)));
+    }
+
+    generators
+}
+
+fn messages_to_scalars(messages: &[impl AsRef<[u8]>]) -> Vec<Scalar> {
+    let dst = api_dst(b"MAP_MSG_TO_SCALAR_AS_HASH_");
+    messages
+        .iter()
+        .map(|message| hash_to_scalar(message.as_ref(), &dst))
+        .collect()
+}
+
+fn calculate_domain(public_key: &G2Affine, generators: &[G1Affine], header: &[u8]) -> Scalar {
+    let mut input = public_key.to_compressed().to_vec();
+    input.extend_from_slice(&((generators.len() - 1) as u64).to_be_bytes());
+    for generator in generators {
+        input.extend_from_slice(&generator.to_compressed());
+    }
+    input.extend_from_slice(API_ID);
+    input.extend_from_slice(&(header.len() as u64).to_be_bytes());
+    input.extend_from_slice(header);
+
+    hash_to_scalar(&input, &api_dst(b"H2S_"))
+}
+
+/// P1 + Q_1 * domain + the sum of the given message generators times their messages
+fn commitment<'a>(
+    generators: &[G1Affine],
+    domain: &Scalar,
+    messages: impl Iterator<Item = (usize, &'a Scalar)>,
+) -> G1Projective {
+    let mut b = G1Projective::from(base_point()) + generators[0] * domain;
+    for (index, message) in messages {
+        b += generators[index + 1] * message;
+    }
+    b
+}
+
+fn base_point() -> G1Affine {
+    // P1 is a constant of the ciphersuite, decompressing it can't fail
+    G1Affine::from_compressed(&P1).unwrap()
+}
+
+fn get_private_key_from_bytes(bytes: &[u8]) -> Result<Zeroizing<Scalar>, Error> {
+    if let Err(error) = get_key_policy().check_curve(Curve::Bls12381G2) {
+        return Err(error);
+    }
+
+    match scalar_from_bytes(bytes) {
+        Some(val) => Ok(Zeroizing::new(val)),
+        None => Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+    }
+}
+
+fn get_public_key_from_bytes(bytes: &[u8]) -> Result<G2Affine, Error> {
+    if let Err(error) = get_key_policy().check_curve(Curve::Bls12381G2) {
+        return Err(error);
+    }
+
+    if bytes.len() != PUBLIC_KEY_LENGTH {
+        return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR));
+    }
+
+    let mut compressed = [0u8; PUBLIC_KEY_LENGTH];
+    compressed.copy_from_slice(bytes);
+
+    match Option::<G2Affine>::from(G2Affine::from_compressed(&compressed)) {
+        Some(val) if !bool::from(val.is_identity()) => Ok(val),
+        _ => Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+    }
+}
+
+fn get_signature_from_bytes(bytes: &[u8]) -> Result<(G1Affine, Scalar), Error> {
+    if bytes.len() != SIGNATURE_LENGTH {
+        return Err(Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED));
+    }
+
+    match (
+        g1_from_bytes(&bytes[..POINT_LENGTH]),
+        scalar_from_bytes(&bytes[POINT_LENGTH..]),
+    ) {
+        (Some(a), Some(e)) => Ok((a, e)),
+        _ => Err(Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED)),
+    }
+}
+
+fn get_proof_from_bytes(bytes: &[u8]) -> Result<Proof, Error> {
+    if bytes.len() < PROOF_BASE_LENGTH || (bytes.len() - PROOF_BASE_LENGTH) % SCALAR_LENGTH != 0 {
+        return Err(Error::new(crate::errors::BBS_PROOF_IDENTIFICATION_FAILED));
+    }
+
+    let mut points = Vec::with_capacity(3);
+    for chunk in bytes[..3 * POINT_LENGTH].chunks(POINT_LENGTH) {
+        match g1_from_bytes(chunk) {
+            Some(val) => points.push(val),
+            None => return Err(Error::new(crate::errors::BBS_PROOF_IDENTIFICATION_FAILED)),
+        }
+    }
+
+    let mut scalars = Vec::with_capacity((bytes.len() - 3 * POINT_LENGTH) / SCALAR_LENGTH);
+    for chunk in bytes[3 * POINT_LENGTH..].chunks(SCALAR_LENGTH) {
+        match scalar_from_bytes(chunk) {
+            Some(val) => scalars.push(val),
+            None => return Err(Error::new(crate::errors::BBS_PROOF_IDENTIFICATION_FAILED)),
+        }
+    }
+
+    let challenge = scalars[scalars.len() - 1];
+    Ok(Proof {
+        a_bar: points[0],
+        b_bar: points[1],
+        d: points[2],
+        e_hat: scalars[0],
+        r1_hat: scalars[1],
+        r3_hat: scalars[2],
+        commitments: scalars[3..scalars.len() - 1].to_vec(),
+        challenge,
+    })
+}
+
+impl Proof {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(PROOF_BASE_LENGTH + self.commitments.len() * SCALAR_LENGTH);
+        bytes.extend_from_slice(&self.a_bar.to_compressed());
+        bytes.extend_from_slice(&self.b_bar.to_compressed());
+        bytes.extend_from_slice(&self.d.to_compressed());
+        for scalar in [&self.e_hat, &self.r1_hat, &self.r3_hat]
+            .into_iter()
+            .chain(self.commitments.iter())
+            .chain([&self.challenge])
+        {
+            bytes.extend_from_slice(&scalar_to_bytes(scalar));
+        }
+        bytes
+    }
+}
+
+/// Checks that the indexes are ascending, without duplicates and below the number of messages
+fn check_disclosed_indexes(disclosed_indexes: &[usize], count: usize) -> Result<(), Error> {
+    let ascending = disclosed_indexes.windows(2).all(|pair| pair[0] < pair[1]);
+
+    match ascending && disclosed_indexes.iter().all(|index| *index < count) {
+        true => Ok(()),
+        false => Err(Error::new(crate::errors::BBS_DISCLOSED_INDEXES)),
+    }
+}
+
+/// CoreSign of the draft
+fn core_sign(
+    secret: &Scalar,
+    public_key: &G2Affine,
+    header: &[u8],
+    messages: &[Scalar],
+) -> Result<Vec<u8>, Error> {
+    let generators = create_generators(messages.len() + 1);
+    let domain = calculate_domain(public_key, &generators, header);
+
+    let mut input = Zeroizing::new(scalar_to_bytes(secret).to_vec());
+    for message in messages.iter().chain([&domain]) {
+        input.extend_from_slice(&scalar_to_bytes(message));
+    }
+    let e = hash_to_scalar(&input, &api_dst(b"H2S_"));
+
+    let b = commitment(&generators, &domain, messages.iter().enumerate());
+    let a = match Option::<Scalar>::from((secret + e).invert()) {
+        Some(val) => G1Affine::from(b * val),
+        None => return Err(Error::new(crate::errors::SIGNING_FAILED)),
+    };
+
+    let mut signature = a.to_compressed().to_vec();
+    signature.extend_from_slice(&scalar_to_bytes(&e));
+    Ok(signature)
+}
+
+/// CoreVerify of the draft
+fn core_verify(
+    public_key: &G2Affine,
+    signature: &[u8],
+    header: &[u8],
+    messages: &[Scalar],
+) -> Result<bool, Error> {
+    let (a, e) = match get_signature_from_bytes(signature) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    let generators = create_generators(messages.len() + 1);
+    let domain = calculate_domain(public_key, &generators, header);
+    let b = commitment(&generators, &domain, messages.iter().enumerate());
+
+    Ok(pairing_product_is_identity(&[
+        (
+            a,
+            G2Affine::from(G2Projective::generator() * e + public_key),
+        ),
+        (G1Affine::from(b), -G2Affine::generator()),
+    ]))
+}
+
+/// ProofChallengeCalculate of the draft
+fn proof_challenge(
+    init: &ProofInit,
+    disclosed_indexes: &[usize],
+    disclosed_messages: &[Scalar],
+    presentation_header: &[u8],
+) -> Scalar {
+    let mut input = (disclosed_indexes.len() as u64).to_be_bytes().to_vec();
+    for (index, message) in disclosed_indexes.iter().zip(disclosed_messages.iter()) {
+        input.extend_from_slice(&(*index as u64).to_be_bytes());
+        input.extend_from_slice(&scalar_to_bytes(message));
+    }
+    for point in [&init.a_bar, &init.b_bar, &init.d, &init.t1, &init.t2] {
+        input.extend_from_slice(&point.to_compressed());
+    }
+    input.extend_from_slice(&scalar_to_bytes(&init.domain));
+    input.extend_from_slice(&(presentation_header.len() as u64).to_be_bytes());
+    input.extend_from_slice(presentation_header);
+
+    hash_to_scalar(&input, &api_dst(b"H2S_"))
+}
+
+/// CoreProofGen of the draft with the given random scalars (r1, r2, e~, r1~, r3~ and one m~ per
+/// undisclosed message)
+fn core_proof_gen(
+    public_key: &G2Affine,
+    signature: &[u8],
+    header: &[u8],
+    presentation_header: &[u8],
+    messages: &[Scalar],
+    disclosed_indexes: &[usize],
+    random_scalars: &[Scalar],
+) -> Result<Vec<u8>, Error> {
+    if let Err(error) = check_disclosed_indexes(disclosed_indexes, messages.len()) {
+        return Err(error);
+    }
+
+    let (a, e) = match get_signature_from_bytes(signature) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    let undisclosed_indexes: Vec<usize> = (0..messages.len())
+        .filter(|index| !disclosed_indexes.contains(index))
+        .collect();
+    let (r1, r2, e_tilde, r1_tilde, r3_tilde) = (
+        random_scalars[0],
+        random_scalars[1],
+        random_scalars[2],
+        random_scalars[3],
+        random_scalars[4],
+    );
+    let m_tilde = &random_scalars[5..];
+
+    let generators = create_generators(messages.len() + 1);
+    let domain = calculate_domain(public_key, &generators, header);
+    let b = commitment(&generators, &domain, messages.iter().enumerate());
+
+    let d = b * r2;
+    let a_bar = a * (r1 * r2);
+    let b_bar = d * r1 - a_bar * e;
+    let t1 = a_bar * e_tilde + d * r1_tilde;
+    let mut t2 = d * r3_tilde;
+    for (index, tilde) in undisclosed_indexes.iter().zip(m_tilde.iter()) {
+        t2 += generators[index + 1] * tilde;
+    }
+
+    let init = ProofInit {
+        a_bar: G1Affine::from(a_bar),
+        b_bar: G1Affine::from(b_bar),
+        d: G1Affine::from(d),
+        t1: G1Affine::from(t1),
+        t2: G1Affine::from(t2),
+        domain,
+    };
+
+    let disclosed_messages: Vec<Scalar> = disclosed_indexes
+        .iter()
+        .map(|index| messages[*index])
+        .collect();
+    let challenge = proof_challenge(
+        &init,
+        disclosed_indexes,
+        &disclosed_messages,
+        presentation_header,
+    );
+
+    let r3 = match Option::<Scalar>::from(r2.invert()) {
+        Some(val) => val,
+        None => return Err(Error::new(crate::errors::SIGNING_FAILED)),
+    };
+
+    let proof = Proof {
+        a_bar: init.a_bar,
+        b_bar: init.b_bar,
+        d: init.d,
+        e_hat: e_tilde + e * challenge,
+        r1_hat: r1_tilde - r1 * challenge,
+        r3_hat: r3_tilde - r3 * challenge,
+        commitments: undisclosed_indexes
+            .iter()
+            .zip(m_tilde.iter())
+            .map(|(index, tilde)| tilde + messages[*index] * challenge)
+            .collect(),
+        challenge,
+    };
+
+    Ok(proof.to_bytes())
+}
+
+/// CoreProofVerify of the draft
+fn core_proof_verify(
+    public_key: &G2Affine,
+    proof: &[u8],
+    header: &[u8],
+    presentation_header: &[u8],
+    disclosed_messages: &[Scalar],
+    disclosed_indexes: &[usize],
+) -> Result<bool, Error> {
+    let proof = match get_proof_from_bytes(proof) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    let count = disclosed_indexes.len() + proof.commitments.len();
+    if disclosed_messages.len() != disclosed_indexes.len() {
+        return Err(Error::new(crate::errors::BBS_DISCLOSED_INDEXES));
+    }
+
+    if let Err(error) = check_disclosed_indexes(disclosed_indexes, count) {
+        return Err(error);
+    }
+
+    let undisclosed_indexes: Vec<usize> = (0..count)
+        .filter(|index| !disclosed_indexes.contains(index))
+        .collect();
+
+    let generators = create_generators(count + 1);
+    let domain = calculate_domain(public_key, &generators, header);
+    let disclosed = commitment(
+        &generators,
+        &domain,
+        disclosed_indexes
+            .iter()
+            .copied()
+            .zip(disclosed_messages.iter()),
+    );
+
+    let t1 = proof.b_bar * proof.challenge + proof.a_bar * proof.e_hat + proof.d * proof.r1_hat;
+    let mut t2 = disclosed * proof.challenge + proof.d * proof.r3_hat;
+    for (index, commitment) in undisclosed_indexes.iter().zip(proof.commitments.iter()) {
+        t2 += generators[index + 1] * commitment;
+    }
+
+    let init = ProofInit {
+        a_bar: proof.a_bar,
+        b_bar: proof.b_bar,
+        d: proof.d,
+        t1: G1Affine::from(t1),
+        t2: G1Affine::from(t2),
+        domain,
+    };
+
+    let challenge = proof_challenge(
+        &init,
+        disclosed_indexes,
+        disclosed_messages,
+        presentation_header,
+    );
+    if challenge != proof.challenge {
+        return Ok(false);
+    }
+
+    Ok(pairing_product_is_identity(&[
+        (proof.a_bar, *public_key),
+        (proof.b_bar, -G2Affine::generator()),
+    ]))
+}
+
+/// BBS signing key (BLS12-381-SHA-256 ciphersuite with messages hashed to scalars)
+pub struct BbsSigningKey {
+    secret: Zeroizing<Scalar>,
+}
+
+impl BbsSigningKey {
+    /// Create a signing key from new random key material
+    pub fn generate() -> Result<BbsSigningKey, Error> {
+        let mut key_material = Zeroizing::new([0u8; MIN_KEY_MATERIAL_LENGTH]);
+        OsRng.fill_bytes(key_material.as_mut_slice());
+
+        BbsSigningKey::from_key_material(key_material.as_slice(), &[], None)
+    }
+
+    /// Derive a signing key from at least 32 bytes of key material with the KeyGen procedure of
+    /// the draft. The key DST defaults to the one of the ciphersuite.
+    pub fn from_key_material(
+        key_material: &[u8],
+        key_info: &[u8],
+        key_dst: Option<&[u8]>,
+    ) -> Result<BbsSigningKey, Error> {
+        if key_material.len() < MIN_KEY_MATERIAL_LENGTH {
+            return Err(Error::new(crate::errors::BBS_KEY_MATERIAL_TOO_SHORT));
+        }
+
+        if key_info.len() > u16::MAX as usize {
+            return Err(Error::new(crate::errors::KEY_GENERATION_FAILED));
+        }
+
+        let mut input = Zeroizing::new(key_material.to_vec());
+        input.extend_from_slice(&(key_info.len() as u16).to_be_bytes());
+        input.extend_from_slice(key_info);
+
+        let dst = match key_dst {
+            Some(val) => val.to_vec(),
+            None => api_dst(b"KEYGEN_DST_"),
+        };
+
+        let secret = Zeroizing::new(hash_to_scalar(&input, &dst));
+        if *secret == Scalar::zero() {
+            return Err(Error::new(crate::errors::KEY_GENERATION_FAILED));
+        }
+
+        Ok(BbsSigningKey { secret })
+    }
+
+    /// Create signing key from the 32 bytes big endian private key
+    pub fn from_bytes(bytes: &[u8]) -> Result<BbsSigningKey, Error> {
+        match get_private_key_from_bytes(bytes) {
+            Ok(val) => Ok(BbsSigningKey { secret: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Raw 32 bytes big endian private key
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(scalar_to_bytes(&self.secret).to_vec())
+    }
+
+    /// Verifying key of this key
+    pub fn verifying_key(&self) -> BbsVerifyingKey {
+        BbsVerifyingKey {
+            key: G2Affine::from(G2Projective::generator() * *self.secret),
+        }
+    }
+
+    /// Sign the messages, bound to the header
+    pub fn sign(&self, header: &[u8], messages: &[impl AsRef<[u8]>]) -> Result<Vec<u8>, Error> {
+        core_sign(
+            &self.secret,
+            &self.verifying_key().key,
+            header,
+            &messages_to_scalars(messages),
+        )
+    }
+}
+
+/// BBS verifying key, also used by holders to derive proofs
+pub struct BbsVerifyingKey {
+    key: G2Affine,
+}
+
+impl BbsVerifyingKey {
+    /// Create verifying key from the 96 bytes compressed public key
+    pub fn from_bytes(bytes: &[u8]) -> Result<BbsVerifyingKey, Error> {
+        match get_public_key_from_bytes(bytes) {
+            Ok(val) => Ok(BbsVerifyingKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Compressed public key
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.key.to_compressed().to_vec()
+    }
+
+    /// Verify a signature of all messages
+    pub fn verify(
+        &self,
+        signature: &[u8],
+        header: &[u8],
+        messages: &[impl AsRef<[u8]>],
+    ) -> Result<bool, Error> {
+        core_verify(&self.key, signature, header, &messages_to_scalars(messages))
+    }
+
+    /// Derive a zero-knowledge proof of a signature of this key that reveals only the messages
+    /// at the disclosed indexes. The indexes are zero based and ascending, the presentation
+    /// header binds the proof to a presentation.
+    pub fn create_proof(
+        &self,
+        signature: &[u8],
+        header: &[u8],
+        presentation_header: &[u8],
+        messages: &[impl AsRef<[u8]>],
+        disclosed_indexes: &[usize],
+    ) -> Result<Vec<u8>, Error> {
+        let count = messages.len().saturating_sub(disclosed_indexes.len());
+
+        core_proof_gen(
+            &self.key,
+            signature,
+            header,
+            presentation_header,
+            &messages_to_scalars(messages),
+            disclosed_indexes,
+            &random_scalars(5 + count),
+        )
+    }
+
+    /// Verify a proof created with [`BbsVerifyingKey::create_proof`] against the disclosed
+    /// messages and their indexes
+    pub fn verify_proof(
+        &self,
+        proof: &[u8],
+        header: &[u8],
+        presentation_header: &[u8],
+        disclosed_messages: &[impl AsRef<[u8]>],
+        disclosed_indexes: &[usize],
+    ) -> Result<bool, Error> {
+        core_proof_verify(
+            &self.key,
+            proof,
+            header,
+            presentation_header,
+            &messages_to_scalars(disclosed_messages),
+            disclosed_indexes,
+        )
+    }
+}
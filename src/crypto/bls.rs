@@ -0,0 +1,870 @@
+use crate::algorithms::Algorithm;
+use bls12_381::{
+    hash_to_curve::{ExpandMsgXmd, HashToCurve},
+    multi_miller_loop, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective, Gt, Scalar,
+};
+use fi_common::error::Error;
+use hkdf::Hkdf;
+#[cfg(feature = "wasm")]
+use js_sys::{Object, Uint8Array};
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use wasm_bindgen::prelude::wasm_bindgen;
+use zeroize::Zeroizing;
+
+use super::{
+    policy::{get_key_policy, Curve, KeyPolicy},
+    SignFromKey, VerifyFromKey,
+};
+
+/// Length of the raw private key
+pub const PRIVATE_KEY_LENGTH: usize = 32;
+
+/// Minimum length of the input keying material of [`BlsSigningKey::from_ikm`]
+pub const MIN_IKM_LENGTH: usize = 32;
+
+const DST_SIG_MIN_PK: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+const DST_POP_MIN_PK: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+const DST_SIG_MIN_SIG: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+const DST_POP_MIN_SIG: &[u8] = b"BLS_POP_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+
+const KEYGEN_SALT: &[u8] = b"BLS-SIG-KEYGEN-SALT-";
+
+/// BLS12-381 signature variants of the IETF BLS signature draft. Both use the proof of
+/// possession ciphersuite, so signatures over the same message can be fast aggregate verified.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Serialize, Deserialize, Copy, Clone, Debug)]
+pub enum BlsVariant {
+    /// Public keys in G1 (48 bytes), signatures in G2 (96 bytes)
+    MinPk,
+    /// Public keys in G2 (96 bytes), signatures in G1 (48 bytes)
+    MinSig,
+}
+
+impl BlsVariant {
+    /// [`Algorithm`] that signs with this variant
+    pub fn algorithm(&self) -> Algorithm {
+        match self {
+            BlsVariant::MinPk => Algorithm::BLS12381MinPk,
+            BlsVariant::MinSig => Algorithm::BLS12381MinSig,
+        }
+    }
+
+    /// Variant of a BLS [`Algorithm`]
+    pub fn from_algorithm(alg: Algorithm) -> Option<BlsVariant> {
+        match alg {
+            Algorithm::BLS12381MinPk => Some(BlsVariant::MinPk),
+            Algorithm::BLS12381MinSig => Some(BlsVariant::MinSig),
+            _ => None,
+        }
+    }
+
+    /// Length of the compressed public key
+    pub fn public_key_length(&self) -> usize {
+        match self {
+            BlsVariant::MinPk => 48,
+            BlsVariant::MinSig => 96,
+        }
+    }
+
+    /// Length of the compressed signature
+    pub fn signature_length(&self) -> usize {
+        match self {
+            BlsVariant::MinPk => 96,
+            BlsVariant::MinSig => 48,
+        }
+    }
+
+    /// Curve group of the public keys, checked against the key policy
+    pub fn policy_curve(&self) -> Curve {
+        match self {
+            BlsVariant::MinPk => Curve::Bls12381G1,
+            BlsVariant::MinSig => Curve::Bls12381G2,
+        }
+    }
+
+    fn from_public_key_length(length: usize) -> Option<BlsVariant> {
+        [BlsVariant::MinPk, BlsVariant::MinSig]
+            .into_iter()
+            .find(|variant| variant.public_key_length() == length)
+    }
+
+    fn from_signature_length(length: usize) -> Option<BlsVariant> {
+        [BlsVariant::MinPk, BlsVariant::MinSig]
+            .into_iter()
+            .find(|variant| variant.signature_length() == length)
+    }
+
+    fn signature_dst(&self) -> &'static [u8] {
+        match self {
+            BlsVariant::MinPk => DST_SIG_MIN_PK,
+            BlsVariant::MinSig => DST_SIG_MIN_SIG,
+        }
+    }
+
+    fn pop_dst(&self) -> &'static [u8] {
+        match self {
+            BlsVariant::MinPk => DST_POP_MIN_PK,
+            BlsVariant::MinSig => DST_POP_MIN_SIG,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct PrivateKey {
+    variant: BlsVariant,
+    secret: Zeroizing<Scalar>,
+}
+
+#[derive(Clone, Copy)]
+enum PublicKey {
+    MinPk(G1Affine),
+    MinSig(G2Affine),
+}
+
+#[derive(Clone, Copy)]
+enum Signature {
+    MinPk(G2Affine),
+    MinSig(G1Affine),
+}
+
+fn hash_to_g1(message: &[u8], dst: &[u8]) -> G1Affine {
+    G1Affine::from(<G1Projective as HashToCurve<
+        ExpandMsgXmd<sha2_09::Sha256>,
+    >>::hash_to_curve(message, dst))
+}
+
+fn hash_to_g2(message: &[u8], dst: &[u8]) -> G2Affine {
+    G2Affine::from(<G2Projective as HashToCurve<
+        ExpandMsgXmd<sha2_09::Sha256>,
+    >>::hash_to_curve(message, dst))
+}
+
+/// Checks that the product of the pairings of the terms is the identity of GT
+fn pairing_product_is_identity(terms: &[(G1Affine, G2Affine)]) -> bool {
+    let prepared: Vec<(G1Affine, G2Prepared)> = terms
+        .iter()
+        .map(|(g1, g2)| (*g1, G2Prepared::from(*g2)))
+        .collect();
+    let refs: Vec<(&G1Affine, &G2Prepared)> = prepared.iter().map(|(g1, g2)| (g1, g2)).collect();
+
+    multi_miller_loop(refs.as_slice()).final_exponentiation() == Gt::identity()
+}
+
+/// KeyGen of the IETF BLS signature draft, HKDF-SHA256 over the input keying material
+fn key_gen(ikm: &[u8], key_info: &[u8]) -> Result<Zeroizing<Scalar>, Error> {
+    if ikm.len() < MIN_IKM_LENGTH {
+        return Err(Error::new(crate::errors::BLS_IKM_TOO_SHORT));
+    }
+
+    let mut ikm_input = Zeroizing::new(ikm.to_vec());
+    ikm_input.push(0);
+
+    let mut info = key_info.to_vec();
+    info.extend_from_slice(&[0, 48]);
+
+    let mut salt = KEYGEN_SALT.to_vec();
+    loop {
+        salt = Sha256::digest(&salt).to_vec();
+
+        let mut okm = Zeroizing::new([0u8; 48]);
+        if let Err(error) = Hkdf::<Sha256>::new(Some(&salt), &ikm_input).expand(&info, okm.as_mut())
+        {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::KEY_GENERATION_FAILED));
+        }
+
+        // OKM is big endian and the wide reduction takes 64 little endian bytes
+        let mut wide = Zeroizing::new([0u8; 64]);
+        for (index, byte) in okm.iter().rev().enumerate() {
+            wide[index] = *byte;
+        }
+
+        let secret = Zeroizing::new(Scalar::from_bytes_wide(&wide));
+        if *secret != Scalar::zero() {
+            return Ok(secret);
+        }
+    }
+}
+
+fn get_private_key_from_bytes(variant: BlsVariant, bytes: &[u8]) -> Result<PrivateKey, Error> {
+    if let Err(error) = get_key_policy().check_curve(variant.policy_curve()) {
+        return Err(error);
+    }
+
+    if bytes.len() != PRIVATE_KEY_LENGTH {
+        return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+    }
+
+    let mut le_bytes = Zeroizing::new([0u8; PRIVATE_KEY_LENGTH]);
+    for (index, byte) in bytes.iter().rev().enumerate() {
+        le_bytes[index] = *byte;
+    }
+
+    match Option::<Scalar>::from(Scalar::from_bytes(&le_bytes)) {
+        Some(val) if val != Scalar::zero() => Ok(PrivateKey {
+            variant,
+            secret: Zeroizing::new(val),
+        }),
+        _ => Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+    }
+}
+
+fn get_public_key_from_bytes(bytes: &[u8]) -> Result<PublicKey, Error> {
+    let variant = BlsVariant::from_public_key_length(bytes.len());
+    if let Some(variant) = variant {
+        if let Err(error) = get_key_policy().check_curve(variant.policy_curve()) {
+            return Err(error);
+        }
+    }
+
+    let public_key = match variant {
+        Some(BlsVariant::MinPk) => {
+            let mut compressed = [0u8; 48];
+            compressed.copy_from_slice(bytes);
+            Option::<G1Affine>::from(G1Affine::from_compressed(&compressed))
+                .filter(|point| !bool::from(point.is_identity()))
+                .map(PublicKey::MinPk)
+        }
+        Some(BlsVariant::MinSig) => {
+            let mut compressed = [0u8; 96];
+            compressed.copy_from_slice(bytes);
+            Option::<G2Affine>::from(G2Affine::from_compressed(&compressed))
+                .filter(|point| !bool::from(point.is_identity()))
+                .map(PublicKey::MinSig)
+        }
+        None => None,
+    };
+
+    match public_key {
+        Some(val) => Ok(val),
+        None => Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+    }
+}
+
+fn get_signature_from_bytes(bytes: &[u8]) -> Result<Signature, Error> {
+    let signature = match BlsVariant::from_signature_length(bytes.len()) {
+        Some(BlsVariant::MinPk) => {
+            let mut compressed = [0u8; 96];
+            compressed.copy_from_slice(bytes);
+            Option::<G2Affine>::from(G2Affine::from_compressed(&compressed)).map(Signature::MinPk)
+        }
+        Some(BlsVariant::MinSig) => {
+            let mut compressed = [0u8; 48];
+            compressed.copy_from_slice(bytes);
+            Option::<G1Affine>::from(G1Affine::from_compressed(&compressed)).map(Signature::MinSig)
+        }
+        None => None,
+    };
+
+    match signature {
+        Some(val) => Ok(val),
+        None => Err(Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED)),
+    }
+}
+
+impl PrivateKey {
+    fn public_key(&self) -> PublicKey {
+        match self.variant {
+            BlsVariant::MinPk => {
+                PublicKey::MinPk(G1Affine::from(G1Projective::generator() * *self.secret))
+            }
+            BlsVariant::MinSig => {
+                PublicKey::MinSig(G2Affine::from(G2Projective::generator() * *self.secret))
+            }
+        }
+    }
+
+    fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut bytes = Zeroizing::new(self.secret.to_bytes().to_vec());
+        bytes.reverse();
+        bytes
+    }
+
+    fn sign(&self, message: &[u8], dst: &[u8]) -> Signature {
+        match self.variant {
+            BlsVariant::MinPk => {
+                Signature::MinPk(G2Affine::from(hash_to_g2(message, dst) * *self.secret))
+            }
+            BlsVariant::MinSig => {
+                Signature::MinSig(G1Affine::from(hash_to_g1(message, dst) * *self.secret))
+            }
+        }
+    }
+}
+
+impl PublicKey {
+    fn variant(&self) -> BlsVariant {
+        match self {
+            PublicKey::MinPk(_) => BlsVariant::MinPk,
+            PublicKey::MinSig(_) => BlsVariant::MinSig,
+        }
+    }
+
+    fn to_bytes(self) -> Vec<u8> {
+        match self {
+            PublicKey::MinPk(point) => point.to_compressed().to_vec(),
+            PublicKey::MinSig(point) => point.to_compressed().to_vec(),
+        }
+    }
+}
+
+impl Signature {
+    fn variant(&self) -> BlsVariant {
+        match self {
+            Signature::MinPk(_) => BlsVariant::MinPk,
+            Signature::MinSig(_) => BlsVariant::MinSig,
+        }
+    }
+
+    fn to_bytes(self) -> Vec<u8> {
+        match self {
+            Signature::MinPk(point) => point.to_compressed().to_vec(),
+            Signature::MinSig(point) => point.to_compressed().to_vec(),
+        }
+    }
+}
+
+/// CoreAggregateVerify of the IETF BLS signature draft. A single public key and message is
+/// CoreVerify.
+fn core_aggregate_verify(
+    public_keys: &[PublicKey],
+    messages: &[&[u8]],
+    signature: &Signature,
+    dst: &[u8],
+) -> bool {
+    let mut terms = Vec::with_capacity(public_keys.len() + 1);
+
+    for (public_key, message) in public_keys.iter().zip(messages.iter()) {
+        match (public_key, signature) {
+            (PublicKey::MinPk(public_key), Signature::MinPk(_)) => {
+                terms.push((*public_key, hash_to_g2(message, dst)))
+            }
+            (PublicKey::MinSig(public_key), Signature::MinSig(_)) => {
+                terms.push((hash_to_g1(message, dst), *public_key))
+            }
+            _ => return false,
+        }
+    }
+
+    match signature {
+        Signature::MinPk(signature) => terms.push((-G1Affine::generator(), *signature)),
+        Signature::MinSig(signature) => terms.push((-signature, G2Affine::generator())),
+    }
+
+    pairing_product_is_identity(&terms)
+}
+
+fn check_algorithm(variant: BlsVariant, alg: Algorithm) -> Result<(), Error> {
+    match variant.algorithm() == alg {
+        true => Ok(()),
+        false => Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH)),
+    }
+}
+
+fn check_signature_variant(variant: BlsVariant, signature: &Signature) -> Result<(), Error> {
+    match signature.variant() == variant {
+        true => Ok(()),
+        false => Err(Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED)),
+    }
+}
+
+/// Signing key for [`crate::algorithms::Algorithm::BLS12381MinPk`] and
+/// [`crate::algorithms::Algorithm::BLS12381MinSig`]
+#[wasm_bindgen]
+pub struct BlsSigningKey {
+    #[cfg(not(feature = "wasm"))]
+    key: PrivateKey,
+
+    #[cfg(feature = "wasm")]
+    key_bytes: Zeroizing<Vec<u8>>,
+    #[cfg(feature = "wasm")]
+    variant: BlsVariant,
+}
+
+impl SignFromKey for BlsSigningKey {
+    fn sign_bytes(&self, content: &[u8], alg: Algorithm) -> Result<Vec<u8>, Error> {
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if let Err(error) = check_algorithm(key.variant, alg) {
+            return Err(error);
+        }
+
+        Ok(key.sign(content, key.variant.signature_dst()).to_bytes())
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+impl BlsSigningKey {
+    /// Create signing key from the 32 bytes big endian private key
+    pub fn from_bytes(variant: BlsVariant, bytes: &[u8]) -> Result<BlsSigningKey, Error> {
+        match get_private_key_from_bytes(variant, bytes) {
+            Ok(val) => Ok(BlsSigningKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Variant of the key
+    pub fn variant(&self) -> BlsVariant {
+        self.key.variant
+    }
+
+    fn get_key(&self) -> Result<&PrivateKey, Error> {
+        Ok(&self.key)
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl BlsSigningKey {
+    /// Create signing key from the 32 bytes big endian private key
+    #[wasm_bindgen(js_name = "fromBytes")]
+    pub fn from_bytes(variant: BlsVariant, bytes: &[u8]) -> BlsSigningKey {
+        BlsSigningKey {
+            key_bytes: Zeroizing::new(bytes.to_vec()),
+            variant,
+        }
+    }
+
+    /// Variant of the key
+    pub fn variant(&self) -> BlsVariant {
+        self.variant
+    }
+
+    fn get_key(&self) -> Result<PrivateKey, Error> {
+        get_private_key_from_bytes(self.variant, self.key_bytes.as_slice())
+    }
+
+    /// Key from the raw private key bytes of the variant of the algorithm
+    pub fn from_js_object(value: Object, alg: Algorithm) -> Result<BlsSigningKey, Error> {
+        if !value.is_array() {
+            return Err(Error::new(crate::errors::MISSING_FIELD));
+        }
+
+        let variant = match BlsVariant::from_algorithm(alg) {
+            Some(val) => val,
+            None => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+        };
+        let arr = Zeroizing::new(Uint8Array::new(&value).to_vec());
+
+        Ok(BlsSigningKey::from_bytes(variant, arr.as_slice()))
+    }
+}
+
+#[wasm_bindgen]
+impl BlsSigningKey {
+    /// Create a signing key from new random input keying material
+    pub fn generate(variant: BlsVariant) -> Result<BlsSigningKey, Error> {
+        let mut ikm = Zeroizing::new([0u8; MIN_IKM_LENGTH]);
+        OsRng.fill_bytes(ikm.as_mut_slice());
+
+        BlsSigningKey::from_ikm(variant, ikm.as_slice(), &[])
+    }
+
+    /// Derive a signing key from at least 32 bytes of input keying material with the KeyGen
+    /// procedure of the IETF BLS signature draft
+    #[wasm_bindgen(js_name = "fromIkm")]
+    pub fn from_ikm(
+        variant: BlsVariant,
+        ikm: &[u8],
+        key_info: &[u8],
+    ) -> Result<BlsSigningKey, Error> {
+        let secret = match key_gen(ikm, key_info) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let key = PrivateKey { variant, secret };
+
+        #[cfg(not(feature = "wasm"))]
+        return Ok(BlsSigningKey { key });
+
+        #[cfg(feature = "wasm")]
+        return Ok(BlsSigningKey::from_bytes(
+            variant,
+            key.to_bytes().as_slice(),
+        ));
+    }
+
+    /// Raw 32 bytes big endian private key
+    #[wasm_bindgen(js_name = "toBytes")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.to_bytes().to_vec()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Verifying key of this key
+    #[wasm_bindgen(js_name = "verifyingKey")]
+    pub fn verifying_key(&self) -> Result<BlsVerifyingKey, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(BlsVerifyingKey::from_key(val.public_key())),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Raw signature of the message, ready for [`aggregate_signatures`]
+    #[wasm_bindgen(js_name = "signMessage")]
+    pub fn sign_message(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.sign(message, val.variant.signature_dst()).to_bytes()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Proof of possession of this key (PopProve). Verifiers should check it with
+    /// [`BlsVerifyingKey::verify_possession`] before using the public key in
+    /// [`fast_aggregate_verify`].
+    #[wasm_bindgen(js_name = "provePossession")]
+    pub fn prove_possession(&self) -> Result<Vec<u8>, Error> {
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let public_key = key.public_key().to_bytes();
+        Ok(key.sign(&public_key, key.variant.pop_dst()).to_bytes())
+    }
+}
+
+/// Verifying key for [`crate::algorithms::Algorithm::BLS12381MinPk`] and
+/// [`crate::algorithms::Algorithm::BLS12381MinSig`]
+#[wasm_bindgen]
+pub struct BlsVerifyingKey {
+    #[cfg(not(feature = "wasm"))]
+    key: PublicKey,
+
+    #[cfg(feature = "wasm")]
+    key_bytes: Vec<u8>,
+}
+
+impl VerifyFromKey for BlsVerifyingKey {
+    fn check_key_policy(&self, policy: &KeyPolicy) -> Result<(), Error> {
+        match self.get_key() {
+            Ok(val) => policy.check_curve(val.variant().policy_curve()),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn verify_bytes(
+        &self,
+        content: &[u8],
+        signature: &[u8],
+        alg: Algorithm,
+    ) -> Result<bool, Error> {
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if let Err(error) = check_algorithm(key.variant(), alg) {
+            return Err(error);
+        }
+
+        verify_with(&key, content, signature)
+    }
+}
+
+fn verify_with(key: &PublicKey, message: &[u8], signature: &[u8]) -> Result<bool, Error> {
+    let signature = match get_signature_from_bytes(signature) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    if let Err(error) = check_signature_variant(key.variant(), &signature) {
+        return Err(error);
+    }
+
+    Ok(core_aggregate_verify(
+        &[*key],
+        &[message],
+        &signature,
+        key.variant().signature_dst(),
+    ))
+}
+
+#[cfg(not(feature = "wasm"))]
+impl BlsVerifyingKey {
+    /// Create verifying key from the compressed public key. The variant follows the key length.
+    pub fn from_bytes(bytes: &[u8]) -> Result<BlsVerifyingKey, Error> {
+        match get_public_key_from_bytes(bytes) {
+            Ok(val) => Ok(BlsVerifyingKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn from_key(key: PublicKey) -> BlsVerifyingKey {
+        BlsVerifyingKey { key }
+    }
+
+    /// Variant of the key
+    pub fn variant(&self) -> BlsVariant {
+        self.key.variant()
+    }
+
+    fn get_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.key)
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl BlsVerifyingKey {
+    /// Create verifying key from the compressed public key. The variant follows the key length.
+    #[wasm_bindgen(js_name = "fromBytes")]
+    pub fn from_bytes(bytes: &[u8]) -> BlsVerifyingKey {
+        BlsVerifyingKey {
+            key_bytes: bytes.to_vec(),
+        }
+    }
+
+    fn from_key(key: PublicKey) -> BlsVerifyingKey {
+        BlsVerifyingKey::from_bytes(key.to_bytes().as_slice())
+    }
+
+    /// Variant of the key
+    pub fn variant(&self) -> Result<BlsVariant, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.variant()),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn get_key(&self) -> Result<PublicKey, Error> {
+        get_public_key_from_bytes(self.key_bytes.as_slice())
+    }
+
+    pub fn from_js_object(value: Object) -> Result<BlsVerifyingKey, Error> {
+        if value.is_array() {
+            Ok(BlsVerifyingKey::from_bytes(
+                Uint8Array::new(&value).to_vec().as_slice(),
+            ))
+        } else {
+            Err(Error::new(crate::errors::MISSING_FIELD))
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl BlsVerifyingKey {
+    /// Compressed public key
+    #[wasm_bindgen(js_name = "toBytes")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.to_bytes()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Verify a raw signature of the message
+    #[wasm_bindgen(js_name = "verifyMessage")]
+    pub fn verify_message(&self, message: &[u8], signature: &[u8]) -> Result<bool, Error> {
+        match self.get_key() {
+            Ok(val) => verify_with(&val, message, signature),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Verify a proof of possession created with [`BlsSigningKey::prove_possession`] (PopVerify)
+    #[wasm_bindgen(js_name = "verifyPossession")]
+    pub fn verify_possession(&self, proof: &[u8]) -> Result<bool, Error> {
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let proof = match get_signature_from_bytes(proof) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if let Err(error) = check_signature_variant(key.variant(), &proof) {
+            return Err(error);
+        }
+
+        let public_key = key.to_bytes();
+        Ok(core_aggregate_verify(
+            &[key],
+            &[public_key.as_slice()],
+            &proof,
+            key.variant().pop_dst(),
+        ))
+    }
+}
+
+fn get_public_keys(public_keys: &[BlsVerifyingKey]) -> Result<Vec<PublicKey>, Error> {
+    let mut keys: Vec<PublicKey> = Vec::with_capacity(public_keys.len());
+
+    for public_key in public_keys {
+        let key = match public_key.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if let Some(first) = keys.first() {
+            if first.variant() != key.variant() {
+                return Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH));
+            }
+        }
+
+        keys.push(key);
+    }
+
+    match keys.is_empty() {
+        true => Err(Error::new(crate::errors::BLS_AGGREGATION_INPUT)),
+        false => Ok(keys),
+    }
+}
+
+/// Aggregate raw signatures of the same variant into one signature
+pub fn aggregate_signatures(signatures: &[impl AsRef<[u8]>]) -> Result<Vec<u8>, Error> {
+    let mut aggregate: Option<Signature> = None;
+
+    for signature in signatures {
+        let signature = match get_signature_from_bytes(signature.as_ref()) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        aggregate = match (aggregate, signature) {
+            (None, signature) => Some(signature),
+            (Some(Signature::MinPk(sum)), Signature::MinPk(point)) => Some(Signature::MinPk(
+                G2Affine::from(G2Projective::from(sum) + point),
+            )),
+            (Some(Signature::MinSig(sum)), Signature::MinSig(point)) => Some(Signature::MinSig(
+                G1Affine::from(G1Projective::from(sum) + point),
+            )),
+            _ => return Err(Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED)),
+        };
+    }
+
+    match aggregate {
+        Some(val) => Ok(val.to_bytes()),
+        None => Err(Error::new(crate::errors::BLS_AGGREGATION_INPUT)),
+    }
+}
+
+/// Aggregate public keys of the same variant into one key. Signatures of the same message
+/// verify against the aggregated key once aggregated with [`aggregate_signatures`].
+pub fn aggregate_public_keys(public_keys: &[BlsVerifyingKey]) -> Result<BlsVerifyingKey, Error> {
+    let keys = match get_public_keys(public_keys) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    let mut aggregate = keys[0];
+    for key in &keys[1..] {
+        aggregate = match (aggregate, key) {
+            (PublicKey::MinPk(sum), PublicKey::MinPk(point)) => {
+                PublicKey::MinPk(G1Affine::from(G1Projective::from(sum) + point))
+            }
+            (PublicKey::MinSig(sum), PublicKey::MinSig(point)) => {
+                PublicKey::MinSig(G2Affine::from(G2Projective::from(sum) + point))
+            }
+            _ => return Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH)),
+        };
+    }
+
+    match aggregate {
+        PublicKey::MinPk(point) if bool::from(point.is_identity()) => {
+            Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR))
+        }
+        PublicKey::MinSig(point) if bool::from(point.is_identity()) => {
+            Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR))
+        }
+        _ => Ok(BlsVerifyingKey::from_key(aggregate)),
+    }
+}
+
+/// Verify an aggregated signature over one message per public key (AggregateVerify). The
+/// messages may be distinct or repeat.
+pub fn aggregate_verify(
+    public_keys: &[BlsVerifyingKey],
+    messages: &[impl AsRef<[u8]>],
+    signature: &[u8],
+) -> Result<bool, Error> {
+    if public_keys.len() != messages.len() {
+        return Err(Error::new(crate::errors::BLS_AGGREGATION_INPUT));
+    }
+
+    let keys = match get_public_keys(public_keys) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    let signature = match get_signature_from_bytes(signature) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    if let Err(error) = check_signature_variant(keys[0].variant(), &signature) {
+        return Err(error);
+    }
+
+    let messages: Vec<&[u8]> = messages.iter().map(|message| message.as_ref()).collect();
+    Ok(core_aggregate_verify(
+        &keys,
+        &messages,
+        &signature,
+        keys[0].variant().signature_dst(),
+    ))
+}
+
+/// Verify an aggregated signature of the same message by all public keys
+/// (FastAggregateVerify). Only secure when every public key came with a valid proof of
+/// possession, see [`BlsVerifyingKey::verify_possession`].
+pub fn fast_aggregate_verify(
+    public_keys: &[BlsVerifyingKey],
+    message: &[u8],
+    signature: &[u8],
+) -> Result<bool, Error> {
+    match aggregate_public_keys(public_keys) {
+        Ok(val) => val.verify_message(message, signature),
+        Err(error) => Err(error),
+    }
+}
+
+/// Sign content with [`crate::algorithms::Algorithm::BLS12381MinPk`] or
+/// [`crate::algorithms::Algorithm::BLS12381MinSig`]
+#[cfg(not(feature = "wasm"))]
+pub fn sign_bls(message: String, key: impl SignFromKey, alg: Algorithm) -> Result<String, Error> {
+    key.sign(message, alg)
+}
+
+#[cfg(feature = "wasm")]
+pub fn sign_bls(message: String, key: Object, alg: Algorithm) -> Result<String, Error> {
+    match BlsSigningKey::from_js_object(key, alg) {
+        Ok(val) => val.sign(message, alg),
+        Err(error) => Err(error),
+    }
+}
+
+/// Verify signature using [`crate::algorithms::Algorithm::BLS12381MinPk`] or
+/// [`crate::algorithms::Algorithm::BLS12381MinSig`]
+#[cfg(not(feature = "wasm"))]
+pub fn verify_bls(
+    message: String,
+    signature: String,
+    key: impl VerifyFromKey,
+    alg: Algorithm,
+) -> Result<bool, Error> {
+    key.verify(message, signature, alg)
+}
+
+#[cfg(feature = "wasm")]
+pub fn verify_bls(
+    message: String,
+    signature: String,
+    key: Object,
+    alg: Algorithm,
+) -> Result<bool, Error> {
+    match BlsVerifyingKey::from_js_object(key) {
+        Ok(val) => val.verify(message, signature, alg),
+        Err(error) => Err(error),
+    }
+}
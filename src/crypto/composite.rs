@@ -0,0 +1,939 @@
+use crate::{
+    algorithms::Algorithm,
+    crypto::{
+        ecdsa::{
+            _256::{P256SigningKey, P256VerifyingKey},
+            _384::{P384SigningKey, P384VerifyingKey},
+        },
+        ed448::{Ed448SigningKey, Ed448VerifyingKey},
+        eddsa::{EDDSASigningKey, EDDSAVerifyingKey},
+        mldsa::{MlDsaParameterSet, MlDsaSigningKey, MlDsaVerifyingKey, SEED_LENGTH},
+        policy::KeyPolicy,
+        SignFromKey, VerifyFromKey,
+    },
+};
+use fi_common::error::Error;
+#[cfg(feature = "wasm")]
+use js_sys::{Object, Uint8Array};
+use p256::ecdsa::signature::{Signer, Verifier};
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Digest, Sha256, Sha512};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+use wasm_bindgen::prelude::wasm_bindgen;
+use zeroize::Zeroizing;
+
+/// Prefix of the composite message representative
+pub const COMPOSITE_PREFIX: &[u8] = b"CompositeAlgorithmSignatures2025";
+
+/// Maximum length of the composite context string
+pub const MAX_CONTEXT_LENGTH: usize = 255;
+
+const ED25519_KEY_LENGTH: usize = 32;
+const ED448_KEY_LENGTH: usize = 57;
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum TraditionalAlgorithm {
+    P256,
+    P384,
+    Ed25519,
+    Ed448,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum PreHash {
+    Sha256,
+    Sha512,
+    Shake256,
+}
+
+/// Components, label and pre-hash of a composite algorithm of the IETF LAMPS composite
+/// ML-DSA draft
+#[derive(Clone, Copy)]
+struct CompositeParameters {
+    parameter_set: MlDsaParameterSet,
+    traditional: TraditionalAlgorithm,
+    label: &'static str,
+    pre_hash: PreHash,
+}
+
+fn get_parameters(alg: Algorithm) -> Result<CompositeParameters, Error> {
+    let (parameter_set, traditional, label, pre_hash) = match alg {
+        Algorithm::MLDSA44ES256 => (
+            MlDsaParameterSet::MlDsa44,
+            TraditionalAlgorithm::P256,
+            "COMPSIG-MLDSA44-ECDSA-P256-SHA256",
+            PreHash::Sha256,
+        ),
+        Algorithm::MLDSA65ES256 => (
+            MlDsaParameterSet::MlDsa65,
+            TraditionalAlgorithm::P256,
+            "COMPSIG-MLDSA65-ECDSA-P256-SHA512",
+            PreHash::Sha512,
+        ),
+        Algorithm::MLDSA87ES384 => (
+            MlDsaParameterSet::MlDsa87,
+            TraditionalAlgorithm::P384,
+            "COMPSIG-MLDSA87-ECDSA-P384-SHA512",
+            PreHash::Sha512,
+        ),
+        Algorithm::MLDSA44Ed25519 => (
+            MlDsaParameterSet::MlDsa44,
+            TraditionalAlgorithm::Ed25519,
+            "COMPSIG-MLDSA44-Ed25519-SHA512",
+            PreHash::Sha512,
+        ),
+        Algorithm::MLDSA65Ed25519 => (
+            MlDsaParameterSet::MlDsa65,
+            TraditionalAlgorithm::Ed25519,
+            "COMPSIG-MLDSA65-Ed25519-SHA512",
+            PreHash::Sha512,
+        ),
+        Algorithm::MLDSA87Ed448 => (
+            MlDsaParameterSet::MlDsa87,
+            TraditionalAlgorithm::Ed448,
+            "COMPSIG-MLDSA87-Ed448-SHAKE256",
+            PreHash::Shake256,
+        ),
+        _ => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+    };
+
+    Ok(CompositeParameters {
+        parameter_set,
+        traditional,
+        label,
+        pre_hash,
+    })
+}
+
+/// Label of a composite algorithm, also used as the ML-DSA context of the component signature
+pub fn composite_label(alg: Algorithm) -> Result<&'static str, Error> {
+    match get_parameters(alg) {
+        Ok(val) => Ok(val.label),
+        Err(error) => Err(error),
+    }
+}
+
+/// Message representative <b>M'</b> signed by both components:
+/// <b>Prefix || Label || len(ctx) || ctx || PH(M)</b>
+pub fn composite_message(alg: Algorithm, message: &[u8], context: &[u8]) -> Result<Vec<u8>, Error> {
+    let parameters = match get_parameters(alg) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    if context.len() > MAX_CONTEXT_LENGTH {
+        return Err(Error::new(crate::errors::COMPOSITE_CONTEXT_TOO_LONG));
+    }
+
+    let mut representative = COMPOSITE_PREFIX.to_vec();
+    representative.extend_from_slice(parameters.label.as_bytes());
+    representative.push(context.len() as u8);
+    representative.extend_from_slice(context);
+
+    match parameters.pre_hash {
+        PreHash::Sha256 => representative.extend_from_slice(&Sha256::digest(message)),
+        PreHash::Sha512 => representative.extend_from_slice(&Sha512::digest(message)),
+        PreHash::Shake256 => {
+            let mut hasher = Shake256::default();
+            hasher.update(message);
+            let mut digest = [0u8; 64];
+            hasher.finalize_xof().read(&mut digest);
+            representative.extend_from_slice(&digest);
+        }
+    }
+
+    Ok(representative)
+}
+
+/// Traditional component of a [`CompositeSigningKey`]
+pub enum TraditionalSigningKey {
+    /// NistP256 private key
+    P256(P256SigningKey),
+    /// NistP384 private key
+    P384(P384SigningKey),
+    /// Ed25519 private key
+    Ed25519(EDDSASigningKey),
+    /// Ed448 private key
+    Ed448(Box<Ed448SigningKey>),
+}
+
+/// Traditional component of a [`CompositeVerifyingKey`]
+pub enum TraditionalVerifyingKey {
+    /// NistP256 public key
+    P256(P256VerifyingKey),
+    /// NistP384 public key
+    P384(P384VerifyingKey),
+    /// Ed25519 public key
+    Ed25519(EDDSAVerifyingKey),
+    /// Ed448 public key
+    Ed448(Ed448VerifyingKey),
+}
+
+fn signing_failed(error: impl ToString) -> Error {
+    fi_common::logger::error(error.to_string().as_str());
+    Error::new(crate::errors::SIGNING_FAILED)
+}
+
+fn private_key_error(error: impl ToString) -> Error {
+    fi_common::logger::error(error.to_string().as_str());
+    Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)
+}
+
+impl TraditionalSigningKey {
+    fn algorithm(&self) -> TraditionalAlgorithm {
+        match self {
+            TraditionalSigningKey::P256(_) => TraditionalAlgorithm::P256,
+            TraditionalSigningKey::P384(_) => TraditionalAlgorithm::P384,
+            TraditionalSigningKey::Ed25519(_) => TraditionalAlgorithm::Ed25519,
+            TraditionalSigningKey::Ed448(_) => TraditionalAlgorithm::Ed448,
+        }
+    }
+
+    /// Signature of the message, DER encoded for ECDSA
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        match self {
+            TraditionalSigningKey::P256(key) => {
+                let signature: Result<p256::ecdsa::Signature, _> = match key.get_key() {
+                    Ok(val) => val.try_sign(message),
+                    Err(error) => return Err(error),
+                };
+
+                match signature {
+                    Ok(val) => Ok(val.to_der().as_bytes().to_vec()),
+                    Err(error) => Err(signing_failed(error)),
+                }
+            }
+            TraditionalSigningKey::P384(key) => {
+                let signature: Result<p384::ecdsa::Signature, _> = match key.get_key() {
+                    Ok(val) => val.try_sign(message),
+                    Err(error) => return Err(error),
+                };
+
+                match signature {
+                    Ok(val) => Ok(val.to_der().as_bytes().to_vec()),
+                    Err(error) => Err(signing_failed(error)),
+                }
+            }
+            TraditionalSigningKey::Ed25519(key) => match key.get_key() {
+                Ok(val) => Ok(val.sign(message).to_bytes().to_vec()),
+                Err(error) => Err(error),
+            },
+            TraditionalSigningKey::Ed448(key) => match key.get_key() {
+                Ok(val) => Ok(val.sign_raw(message).to_bytes().to_vec()),
+                Err(error) => Err(error),
+            },
+        }
+    }
+
+    /// Private key in the composite encoding, <b>ECPrivateKey</b> DER for ECDSA and the raw key
+    /// for EdDSA
+    fn to_bytes(&self) -> Result<Zeroizing<Vec<u8>>, Error> {
+        match self {
+            TraditionalSigningKey::P256(key) => match key.get_key() {
+                Ok(val) => match p256::SecretKey::from(val.as_nonzero_scalar()).to_sec1_der() {
+                    Ok(der) => Ok(der),
+                    Err(error) => Err(private_key_error(error)),
+                },
+                Err(error) => Err(error),
+            },
+            TraditionalSigningKey::P384(key) => match key.get_key() {
+                Ok(val) => match p384::SecretKey::from(val.as_nonzero_scalar()).to_sec1_der() {
+                    Ok(der) => Ok(der),
+                    Err(error) => Err(private_key_error(error)),
+                },
+                Err(error) => Err(error),
+            },
+            TraditionalSigningKey::Ed25519(key) => match key.get_key() {
+                Ok(val) => Ok(Zeroizing::new(val.to_bytes().to_vec())),
+                Err(error) => Err(error),
+            },
+            TraditionalSigningKey::Ed448(key) => match key.get_key() {
+                Ok(val) => Ok(Zeroizing::new(val.to_bytes().to_vec())),
+                Err(error) => Err(error),
+            },
+        }
+    }
+
+    fn from_bytes(
+        traditional: TraditionalAlgorithm,
+        bytes: &[u8],
+    ) -> Result<TraditionalSigningKey, Error> {
+        match traditional {
+            TraditionalAlgorithm::P256 => {
+                let secret = match p256::SecretKey::from_sec1_der(bytes) {
+                    Ok(val) => Zeroizing::new(val.to_bytes().to_vec()),
+                    Err(error) => return Err(private_key_error(error)),
+                };
+
+                #[cfg(not(feature = "wasm"))]
+                return P256SigningKey::from_bytes(&secret).map(TraditionalSigningKey::P256);
+
+                #[cfg(feature = "wasm")]
+                return Ok(TraditionalSigningKey::P256(P256SigningKey::from_bytes(
+                    &secret,
+                )));
+            }
+            TraditionalAlgorithm::P384 => {
+                let secret = match p384::SecretKey::from_sec1_der(bytes) {
+                    Ok(val) => Zeroizing::new(val.to_bytes().to_vec()),
+                    Err(error) => return Err(private_key_error(error)),
+                };
+
+                #[cfg(not(feature = "wasm"))]
+                return P384SigningKey::from_bytes(&secret).map(TraditionalSigningKey::P384);
+
+                #[cfg(feature = "wasm")]
+                return Ok(TraditionalSigningKey::P384(P384SigningKey::from_bytes(
+                    &secret,
+                )));
+            }
+            TraditionalAlgorithm::Ed25519 => {
+                if bytes.len() != ED25519_KEY_LENGTH {
+                    return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+                }
+                let mut secret = Zeroizing::new(bytes.to_vec());
+
+                #[cfg(not(feature = "wasm"))]
+                return EDDSASigningKey::from_bytes(&mut secret)
+                    .map(TraditionalSigningKey::Ed25519);
+
+                #[cfg(feature = "wasm")]
+                return Ok(TraditionalSigningKey::Ed25519(EDDSASigningKey::from_bytes(
+                    &mut secret,
+                )));
+            }
+            TraditionalAlgorithm::Ed448 => {
+                if bytes.len() != ED448_KEY_LENGTH {
+                    return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+                }
+                let mut secret = Zeroizing::new(bytes.to_vec());
+
+                #[cfg(not(feature = "wasm"))]
+                return Ed448SigningKey::from_bytes(&mut secret)
+                    .map(|val| TraditionalSigningKey::Ed448(Box::new(val)));
+
+                #[cfg(feature = "wasm")]
+                return Ok(TraditionalSigningKey::Ed448(Box::new(
+                    Ed448SigningKey::from_bytes(&mut secret),
+                )));
+            }
+        }
+    }
+
+    fn generate(traditional: TraditionalAlgorithm) -> Result<Zeroizing<Vec<u8>>, Error> {
+        match traditional {
+            TraditionalAlgorithm::P256 => match p256::SecretKey::random(&mut OsRng).to_sec1_der() {
+                Ok(val) => Ok(val),
+                Err(error) => {
+                    fi_common::logger::error(error.to_string().as_str());
+                    Err(Error::new(crate::errors::KEY_GENERATION_FAILED))
+                }
+            },
+            TraditionalAlgorithm::P384 => match p384::SecretKey::random(&mut OsRng).to_sec1_der() {
+                Ok(val) => Ok(val),
+                Err(error) => {
+                    fi_common::logger::error(error.to_string().as_str());
+                    Err(Error::new(crate::errors::KEY_GENERATION_FAILED))
+                }
+            },
+            TraditionalAlgorithm::Ed25519 => {
+                let mut secret = Zeroizing::new(vec![0u8; ED25519_KEY_LENGTH]);
+                OsRng.fill_bytes(secret.as_mut_slice());
+                Ok(secret)
+            }
+            TraditionalAlgorithm::Ed448 => {
+                let mut secret = Zeroizing::new(vec![0u8; ED448_KEY_LENGTH]);
+                OsRng.fill_bytes(secret.as_mut_slice());
+                Ok(secret)
+            }
+        }
+    }
+
+    fn verifying_key(&self) -> Result<TraditionalVerifyingKey, Error> {
+        match self {
+            TraditionalSigningKey::P256(key) => match key.get_key() {
+                Ok(val) => Ok(TraditionalVerifyingKey::P256(P256VerifyingKey::from_key(
+                    *val.verifying_key(),
+                ))),
+                Err(error) => Err(error),
+            },
+            TraditionalSigningKey::P384(key) => match key.get_key() {
+                Ok(val) => Ok(TraditionalVerifyingKey::P384(P384VerifyingKey::from_key(
+                    *val.verifying_key(),
+                ))),
+                Err(error) => Err(error),
+            },
+            TraditionalSigningKey::Ed25519(key) => match key.get_key() {
+                Ok(val) => Ok(TraditionalVerifyingKey::Ed25519(
+                    EDDSAVerifyingKey::from_key(val.verifying_key()),
+                )),
+                Err(error) => Err(error),
+            },
+            TraditionalSigningKey::Ed448(key) => match key.get_key() {
+                Ok(val) => Ok(TraditionalVerifyingKey::Ed448(Ed448VerifyingKey::from_key(
+                    val.verifying_key(),
+                ))),
+                Err(error) => Err(error),
+            },
+        }
+    }
+}
+
+fn signature_error(error: impl ToString) -> Error {
+    fi_common::logger::error(error.to_string().as_str());
+    Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED)
+}
+
+impl TraditionalVerifyingKey {
+    fn algorithm(&self) -> TraditionalAlgorithm {
+        match self {
+            TraditionalVerifyingKey::P256(_) => TraditionalAlgorithm::P256,
+            TraditionalVerifyingKey::P384(_) => TraditionalAlgorithm::P384,
+            TraditionalVerifyingKey::Ed25519(_) => TraditionalAlgorithm::Ed25519,
+            TraditionalVerifyingKey::Ed448(_) => TraditionalAlgorithm::Ed448,
+        }
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<bool, Error> {
+        match self {
+            TraditionalVerifyingKey::P256(key) => {
+                let signature = match p256::ecdsa::Signature::from_der(signature) {
+                    Ok(val) => val,
+                    Err(error) => return Err(signature_error(error)),
+                };
+
+                match key.get_key() {
+                    Ok(val) => Ok(val.verify(message, &signature).is_ok()),
+                    Err(error) => Err(error),
+                }
+            }
+            TraditionalVerifyingKey::P384(key) => {
+                let signature = match p384::ecdsa::Signature::from_der(signature) {
+                    Ok(val) => val,
+                    Err(error) => return Err(signature_error(error)),
+                };
+
+                match key.get_key() {
+                    Ok(val) => Ok(val.verify(message, &signature).is_ok()),
+                    Err(error) => Err(error),
+                }
+            }
+            TraditionalVerifyingKey::Ed25519(key) => {
+                let signature = match ed25519_dalek::Signature::from_slice(signature) {
+                    Ok(val) => val,
+                    Err(error) => return Err(signature_error(error)),
+                };
+
+                match key.get_key() {
+                    Ok(val) => Ok(val.verify(message, &signature).is_ok()),
+                    Err(error) => Err(error),
+                }
+            }
+            TraditionalVerifyingKey::Ed448(key) => {
+                let signature = match ed448_goldilocks_plus::Signature::try_from(signature) {
+                    Ok(val) => val,
+                    Err(error) => return Err(signature_error(error)),
+                };
+
+                match key.get_key() {
+                    Ok(val) => Ok(val.verify_raw(&signature, message).is_ok()),
+                    Err(error) => Err(error),
+                }
+            }
+        }
+    }
+
+    /// Public key in the composite encoding, the uncompressed point for ECDSA and the raw key
+    /// for EdDSA
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        match self {
+            TraditionalVerifyingKey::P256(key) => match key.get_key() {
+                Ok(val) => Ok(val.to_encoded_point(false).as_bytes().to_vec()),
+                Err(error) => Err(error),
+            },
+            TraditionalVerifyingKey::P384(key) => match key.get_key() {
+                Ok(val) => Ok(val.to_encoded_point(false).as_bytes().to_vec()),
+                Err(error) => Err(error),
+            },
+            TraditionalVerifyingKey::Ed25519(key) => match key.get_key() {
+                Ok(val) => Ok(val.to_bytes().to_vec()),
+                Err(error) => Err(error),
+            },
+            TraditionalVerifyingKey::Ed448(key) => match key.get_key() {
+                Ok(val) => Ok(val.to_bytes().to_vec()),
+                Err(error) => Err(error),
+            },
+        }
+    }
+
+    fn from_bytes(
+        traditional: TraditionalAlgorithm,
+        bytes: &[u8],
+    ) -> Result<TraditionalVerifyingKey, Error> {
+        let mut bytes = bytes.to_vec();
+
+        #[cfg(not(feature = "wasm"))]
+        return match traditional {
+            TraditionalAlgorithm::P256 => {
+                P256VerifyingKey::from_bytes(&bytes).map(TraditionalVerifyingKey::P256)
+            }
+            TraditionalAlgorithm::P384 => {
+                P384VerifyingKey::from_bytes(&bytes).map(TraditionalVerifyingKey::P384)
+            }
+            TraditionalAlgorithm::Ed25519 => {
+                EDDSAVerifyingKey::from_bytes(&mut bytes).map(TraditionalVerifyingKey::Ed25519)
+            }
+            TraditionalAlgorithm::Ed448 => {
+                Ed448VerifyingKey::from_bytes(&mut bytes).map(TraditionalVerifyingKey::Ed448)
+            }
+        };
+
+        #[cfg(feature = "wasm")]
+        return Ok(match traditional {
+            TraditionalAlgorithm::P256 => {
+                TraditionalVerifyingKey::P256(P256VerifyingKey::from_bytes(&bytes))
+            }
+            TraditionalAlgorithm::P384 => {
+                TraditionalVerifyingKey::P384(P384VerifyingKey::from_bytes(&bytes))
+            }
+            TraditionalAlgorithm::Ed25519 => {
+                TraditionalVerifyingKey::Ed25519(EDDSAVerifyingKey::from_bytes(&mut bytes))
+            }
+            TraditionalAlgorithm::Ed448 => {
+                TraditionalVerifyingKey::Ed448(Ed448VerifyingKey::from_bytes(&mut bytes))
+            }
+        });
+    }
+
+    fn check_key_policy(&self, policy: &KeyPolicy) -> Result<(), Error> {
+        match self {
+            TraditionalVerifyingKey::P256(key) => key.check_key_policy(policy),
+            TraditionalVerifyingKey::P384(key) => key.check_key_policy(policy),
+            TraditionalVerifyingKey::Ed25519(key) => key.check_key_policy(policy),
+            TraditionalVerifyingKey::Ed448(key) => key.check_key_policy(policy),
+        }
+    }
+}
+
+fn check_components(
+    alg: Algorithm,
+    parameter_set: Result<MlDsaParameterSet, Error>,
+    traditional: TraditionalAlgorithm,
+) -> Result<CompositeParameters, Error> {
+    let parameters = match get_parameters(alg) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    let parameter_set = match parameter_set {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    match parameters.parameter_set == parameter_set && parameters.traditional == traditional {
+        true => Ok(parameters),
+        false => Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH)),
+    }
+}
+
+/// Composite ML-DSA signing key of the IETF LAMPS composite signatures draft. Signatures are the
+/// ML-DSA signature followed by the traditional ECDSA (DER) or EdDSA signature, both over the
+/// same message representative, see [`composite_message`].
+#[wasm_bindgen]
+pub struct CompositeSigningKey {
+    alg: Algorithm,
+    mldsa: MlDsaSigningKey,
+    traditional: TraditionalSigningKey,
+}
+
+impl CompositeSigningKey {
+    /// Combine an ML-DSA key and a traditional key into a composite key of the algorithm
+    pub fn new(
+        alg: Algorithm,
+        mldsa: MlDsaSigningKey,
+        traditional: TraditionalSigningKey,
+    ) -> Result<CompositeSigningKey, Error> {
+        if let Err(error) =
+            check_components(alg, mldsa.key_parameter_set(), traditional.algorithm())
+        {
+            return Err(error);
+        }
+
+        Ok(CompositeSigningKey {
+            alg,
+            mldsa,
+            traditional,
+        })
+    }
+
+    /// ML-DSA component of the key
+    pub fn mldsa(&self) -> &MlDsaSigningKey {
+        &self.mldsa
+    }
+
+    /// Traditional component of the key
+    pub fn traditional(&self) -> &TraditionalSigningKey {
+        &self.traditional
+    }
+
+    fn composite_signature(&self, message: &[u8], context: &[u8]) -> Result<Vec<u8>, Error> {
+        let parameters = match get_parameters(self.alg) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let representative = match composite_message(self.alg, message, context) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let mut signature = match self
+            .mldsa
+            .sign_with_context(&representative, parameters.label.as_bytes())
+        {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match self.traditional.sign(&representative) {
+            Ok(val) => signature.extend_from_slice(&val),
+            Err(error) => return Err(error),
+        };
+
+        Ok(signature)
+    }
+
+    #[cfg(feature = "wasm")]
+    pub fn from_js_object(value: Object, alg: Algorithm) -> Result<CompositeSigningKey, Error> {
+        if value.is_array() {
+            let arr = Zeroizing::new(Uint8Array::new(&value).to_vec());
+            CompositeSigningKey::from_bytes(alg, arr.as_slice())
+        } else {
+            Err(Error::new(crate::errors::MISSING_FIELD))
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl CompositeSigningKey {
+    /// Create signing key from the composite private key, the 32 bytes ML-DSA seed followed by
+    /// the <b>ECPrivateKey</b> DER of ECDSA or the raw EdDSA private key
+    #[wasm_bindgen(js_name = "fromBytes")]
+    pub fn from_bytes(alg: Algorithm, bytes: &[u8]) -> Result<CompositeSigningKey, Error> {
+        let parameters = match get_parameters(alg) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if bytes.len() <= SEED_LENGTH {
+            return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+        }
+        let (seed, traditional) = bytes.split_at(SEED_LENGTH);
+
+        #[cfg(not(feature = "wasm"))]
+        let mldsa = match MlDsaSigningKey::from_bytes(parameters.parameter_set, seed) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        #[cfg(feature = "wasm")]
+        let mldsa = MlDsaSigningKey::from_bytes(parameters.parameter_set, seed);
+
+        match TraditionalSigningKey::from_bytes(parameters.traditional, traditional) {
+            Ok(val) => CompositeSigningKey::new(alg, mldsa, val),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create a signing key from new random component keys
+    pub fn generate(alg: Algorithm) -> Result<CompositeSigningKey, Error> {
+        let parameters = match get_parameters(alg) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let mut bytes = Zeroizing::new(vec![0u8; SEED_LENGTH]);
+        OsRng.fill_bytes(bytes.as_mut_slice());
+
+        match TraditionalSigningKey::generate(parameters.traditional) {
+            Ok(val) => bytes.extend_from_slice(val.as_slice()),
+            Err(error) => return Err(error),
+        };
+
+        CompositeSigningKey::from_bytes(alg, bytes.as_slice())
+    }
+
+    /// Composite algorithm of the key
+    pub fn algorithm(&self) -> Algorithm {
+        self.alg
+    }
+
+    /// Composite private key, see [`CompositeSigningKey::from_bytes`]. The ML-DSA component has
+    /// to be created from a seed.
+    #[wasm_bindgen(js_name = "toBytes")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = match self.mldsa.to_bytes() {
+            Ok(val) if val.len() == SEED_LENGTH => Zeroizing::new(val),
+            Ok(_) => return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+            Err(error) => return Err(error),
+        };
+
+        match self.traditional.to_bytes() {
+            Ok(val) => bytes.extend_from_slice(val.as_slice()),
+            Err(error) => return Err(error),
+        };
+
+        Ok(bytes.to_vec())
+    }
+
+    /// Verifying key of this key. The ML-DSA component has to be created from a seed.
+    #[wasm_bindgen(js_name = "verifyingKey")]
+    pub fn verifying_key(&self) -> Result<CompositeVerifyingKey, Error> {
+        let mldsa = match self.mldsa.verifying_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match self.traditional.verifying_key() {
+            Ok(val) => CompositeVerifyingKey::new(self.alg, mldsa, val),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Composite signature of the message with a context string of up to 255 bytes
+    #[wasm_bindgen(js_name = "signWithContext")]
+    pub fn sign_with_context(&self, message: &[u8], context: &[u8]) -> Result<Vec<u8>, Error> {
+        self.composite_signature(message, context)
+    }
+}
+
+impl SignFromKey for CompositeSigningKey {
+    fn sign_bytes(&self, content: &[u8], alg: Algorithm) -> Result<Vec<u8>, Error> {
+        if alg != self.alg {
+            return Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH));
+        }
+
+        self.composite_signature(content, &[])
+    }
+}
+
+/// Composite ML-DSA verifying key, see [`CompositeSigningKey`]. The signature is valid only if
+/// both the ML-DSA and the traditional signature verify.
+#[wasm_bindgen]
+pub struct CompositeVerifyingKey {
+    alg: Algorithm,
+    mldsa: MlDsaVerifyingKey,
+    traditional: TraditionalVerifyingKey,
+}
+
+impl CompositeVerifyingKey {
+    /// Combine an ML-DSA key and a traditional key into a composite key of the algorithm
+    pub fn new(
+        alg: Algorithm,
+        mldsa: MlDsaVerifyingKey,
+        traditional: TraditionalVerifyingKey,
+    ) -> Result<CompositeVerifyingKey, Error> {
+        if let Err(error) =
+            check_components(alg, mldsa.key_parameter_set(), traditional.algorithm())
+        {
+            return Err(error);
+        }
+
+        Ok(CompositeVerifyingKey {
+            alg,
+            mldsa,
+            traditional,
+        })
+    }
+
+    /// ML-DSA component of the key
+    pub fn mldsa(&self) -> &MlDsaVerifyingKey {
+        &self.mldsa
+    }
+
+    /// Traditional component of the key
+    pub fn traditional(&self) -> &TraditionalVerifyingKey {
+        &self.traditional
+    }
+
+    fn verify_composite_signature(
+        &self,
+        message: &[u8],
+        context: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, Error> {
+        let parameters = match get_parameters(self.alg) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let mldsa_length = parameters.parameter_set.signature_length();
+        if signature.len() <= mldsa_length {
+            return Err(Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED));
+        }
+        let (mldsa_signature, traditional_signature) = signature.split_at(mldsa_length);
+
+        let representative = match composite_message(self.alg, message, context) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let mldsa_verified = match self.mldsa.verify_with_context(
+            &representative,
+            parameters.label.as_bytes(),
+            mldsa_signature,
+        ) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let traditional_verified = match self
+            .traditional
+            .verify(&representative, traditional_signature)
+        {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        Ok(mldsa_verified && traditional_verified)
+    }
+
+    #[cfg(feature = "wasm")]
+    pub fn from_js_object(value: Object, alg: Algorithm) -> Result<CompositeVerifyingKey, Error> {
+        if value.is_array() {
+            CompositeVerifyingKey::from_bytes(alg, Uint8Array::new(&value).to_vec().as_slice())
+        } else {
+            Err(Error::new(crate::errors::MISSING_FIELD))
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl CompositeVerifyingKey {
+    /// Create verifying key from the composite public key, the ML-DSA public key followed by the
+    /// uncompressed ECDSA point or the raw EdDSA public key
+    #[wasm_bindgen(js_name = "fromBytes")]
+    pub fn from_bytes(alg: Algorithm, bytes: &[u8]) -> Result<CompositeVerifyingKey, Error> {
+        let parameters = match get_parameters(alg) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let mldsa_length = parameters.parameter_set.public_key_length();
+        if bytes.len() <= mldsa_length {
+            return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR));
+        }
+        let (mldsa, traditional) = bytes.split_at(mldsa_length);
+
+        #[cfg(not(feature = "wasm"))]
+        let mldsa = match MlDsaVerifyingKey::from_bytes(mldsa) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        #[cfg(feature = "wasm")]
+        let mldsa = MlDsaVerifyingKey::from_bytes(mldsa);
+
+        match TraditionalVerifyingKey::from_bytes(parameters.traditional, traditional) {
+            Ok(val) => CompositeVerifyingKey::new(alg, mldsa, val),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Composite algorithm of the key
+    pub fn algorithm(&self) -> Algorithm {
+        self.alg
+    }
+
+    /// Composite public key, see [`CompositeVerifyingKey::from_bytes`]
+    #[wasm_bindgen(js_name = "toBytes")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = match self.mldsa.to_bytes() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match self.traditional.to_bytes() {
+            Ok(val) => bytes.extend_from_slice(&val),
+            Err(error) => return Err(error),
+        };
+
+        Ok(bytes)
+    }
+
+    /// Verify a composite signature of the message created with the context string
+    #[wasm_bindgen(js_name = "verifyWithContext")]
+    pub fn verify_with_context(
+        &self,
+        message: &[u8],
+        context: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, Error> {
+        self.verify_composite_signature(message, context, signature)
+    }
+}
+
+impl VerifyFromKey for CompositeVerifyingKey {
+    fn verify_bytes(
+        &self,
+        content: &[u8],
+        signature: &[u8],
+        alg: Algorithm,
+    ) -> Result<bool, Error> {
+        if alg != self.alg {
+            return Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH));
+        }
+
+        self.verify_composite_signature(content, &[], signature)
+    }
+
+    fn check_key_policy(&self, policy: &KeyPolicy) -> Result<(), Error> {
+        self.traditional.check_key_policy(policy)
+    }
+}
+
+/// Sign content with a composite ML-DSA algorithm, [`crate::algorithms::AlgorithmFamily::Composite`]
+#[cfg(not(feature = "wasm"))]
+pub fn sign_composite(
+    message: String,
+    key: impl SignFromKey,
+    alg: Algorithm,
+) -> Result<String, Error> {
+    key.sign(message, alg)
+}
+
+#[cfg(feature = "wasm")]
+pub fn sign_composite(message: String, key: Object, alg: Algorithm) -> Result<String, Error> {
+    match CompositeSigningKey::from_js_object(key, alg) {
+        Ok(val) => val.sign(message, alg),
+        Err(error) => Err(error),
+    }
+}
+
+/// Verify signature using a composite ML-DSA algorithm,
+/// [`crate::algorithms::AlgorithmFamily::Composite`]
+#[cfg(not(feature = "wasm"))]
+pub fn verify_composite(
+    message: String,
+    signature: String,
+    key: impl VerifyFromKey,
+    alg: Algorithm,
+) -> Result<bool, Error> {
+    key.verify(message, signature, alg)
+}
+
+#[cfg(feature = "wasm")]
+pub fn verify_composite(
+    message: String,
+    signature: String,
+    key: Object,
+    alg: Algorithm,
+) -> Result<bool, Error> {
+    match CompositeVerifyingKey::from_js_object(key, alg) {
+        Ok(val) => val.verify(message, signature, alg),
+        Err(error) => Err(error),
+    }
+}
@@ -0,0 +1,1374 @@
+#[cfg(feature = "wasm")]
+use super::read_hedged;
+use super::{check_prehash, rejects_high_s, signs_low_s, stream_hasher};
+use crate::{
+    algorithms::Algorithm,
+    crypto::{
+        policy::{get_key_policy, Curve, KeyPolicy},
+        SignFromKey, SignPrehashed, SignStream, VerifyFromKey, VerifyPrehashed, VerifyStream,
+    },
+    stream::{sign_digest_stream, verify_digest_stream, StreamHasher},
+};
+use elliptic_curve::pkcs8::{
+    der::{asn1::BitStringRef, pem, AnyRef, Encode},
+    spki::{AlgorithmIdentifierRef, ObjectIdentifier, SubjectPublicKeyInfoRef},
+    Document, LineEnding, PrivateKeyInfo, SecretDocument,
+};
+use elliptic_curve::{
+    bigint::{
+        modular::runtime_mod::{DynResidue, DynResidueParams},
+        Uint, U256, U384, U512,
+    },
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess},
+};
+use fi_common::error::Error;
+use hmac::{digest::KeyInit, Hmac, Mac};
+#[cfg(feature = "wasm")]
+use js_sys::{Object, Uint8Array};
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use wasm_bindgen::prelude::wasm_bindgen;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::JsValue;
+use zeroize::Zeroizing;
+
+const OID_EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+const OID_BRAINPOOL_P256R1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.36.3.3.2.8.1.1.7");
+const OID_BRAINPOOL_P384R1: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.36.3.3.2.8.1.1.11");
+const OID_BRAINPOOL_P512R1: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.36.3.3.2.8.1.1.13");
+
+/// Brainpool curves of RFC 5639
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Serialize, Deserialize, Copy, Clone, Debug)]
+pub enum BrainpoolCurve {
+    /// brainpoolP256r1, used by [`crate::algorithms::Algorithm::BP256R1`]
+    #[serde(rename = "brainpoolP256r1")]
+    P256r1,
+    /// brainpoolP384r1, used by [`crate::algorithms::Algorithm::BP384R1`]
+    #[serde(rename = "brainpoolP384r1")]
+    P384r1,
+    /// brainpoolP512r1, used by [`crate::algorithms::Algorithm::BP512R1`]
+    #[serde(rename = "brainpoolP512r1")]
+    P512r1,
+}
+
+impl BrainpoolCurve {
+    /// [`Algorithm`] that signs with this curve
+    pub fn algorithm(&self) -> Algorithm {
+        match self {
+            BrainpoolCurve::P256r1 => Algorithm::BP256R1,
+            BrainpoolCurve::P384r1 => Algorithm::BP384R1,
+            BrainpoolCurve::P512r1 => Algorithm::BP512R1,
+        }
+    }
+
+    /// Curve used by the algorithm
+    pub fn from_algorithm(alg: Algorithm) -> Option<BrainpoolCurve> {
+        match alg {
+            Algorithm::BP256R1 => Some(BrainpoolCurve::P256r1),
+            Algorithm::BP384R1 => Some(BrainpoolCurve::P384r1),
+            Algorithm::BP512R1 => Some(BrainpoolCurve::P512r1),
+            _ => None,
+        }
+    }
+
+    /// Length of field elements and scalars in bytes
+    pub fn field_size(&self) -> usize {
+        match self {
+            BrainpoolCurve::P256r1 => 32,
+            BrainpoolCurve::P384r1 => 48,
+            BrainpoolCurve::P512r1 => 64,
+        }
+    }
+
+    pub(crate) fn oid(&self) -> ObjectIdentifier {
+        match self {
+            BrainpoolCurve::P256r1 => OID_BRAINPOOL_P256R1,
+            BrainpoolCurve::P384r1 => OID_BRAINPOOL_P384R1,
+            BrainpoolCurve::P512r1 => OID_BRAINPOOL_P512R1,
+        }
+    }
+
+    pub(crate) fn from_oid(oid: ObjectIdentifier) -> Option<BrainpoolCurve> {
+        if oid == OID_BRAINPOOL_P256R1 {
+            Some(BrainpoolCurve::P256r1)
+        } else if oid == OID_BRAINPOOL_P384R1 {
+            Some(BrainpoolCurve::P384r1)
+        } else if oid == OID_BRAINPOOL_P512R1 {
+            Some(BrainpoolCurve::P512r1)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn policy_curve(&self) -> Curve {
+        match self {
+            BrainpoolCurve::P256r1 => Curve::BrainpoolP256r1,
+            BrainpoolCurve::P384r1 => Curve::BrainpoolP384r1,
+            BrainpoolCurve::P512r1 => Curve::BrainpoolP512r1,
+        }
+    }
+
+    fn arithmetic(&self) -> Box<dyn CurveArithmetic> {
+        match self {
+            BrainpoolCurve::P256r1 => {
+                Box::new(PrimeCurve::<{ U256::LIMBS }>::new(*self, &BRAINPOOL_P256R1))
+            }
+            BrainpoolCurve::P384r1 => {
+                Box::new(PrimeCurve::<{ U384::LIMBS }>::new(*self, &BRAINPOOL_P384R1))
+            }
+            BrainpoolCurve::P512r1 => {
+                Box::new(PrimeCurve::<{ U512::LIMBS }>::new(*self, &BRAINPOOL_P512R1))
+            }
+        }
+    }
+
+    /// SHA-2 digest matching the curve size
+    fn digest(&self, message: &[u8]) -> Vec<u8> {
+        match self {
+            BrainpoolCurve::P256r1 => Sha256::digest(message).to_vec(),
+            BrainpoolCurve::P384r1 => Sha384::digest(message).to_vec(),
+            BrainpoolCurve::P512r1 => Sha512::digest(message).to_vec(),
+        }
+    }
+
+    fn hmac(&self, key: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+        match self {
+            BrainpoolCurve::P256r1 => hmac_parts::<Hmac<Sha256>>(key, parts),
+            BrainpoolCurve::P384r1 => hmac_parts::<Hmac<Sha384>>(key, parts),
+            BrainpoolCurve::P512r1 => hmac_parts::<Hmac<Sha512>>(key, parts),
+        }
+    }
+}
+
+/// Domain parameters of RFC 5639 section 3, hex encoded
+struct DomainParameters {
+    p: &'static str,
+    a: &'static str,
+    b: &'static str,
+    x: &'static str,
+    y: &'static str,
+    n: &'static str,
+}
+
+const BRAINPOOL_P256R1: DomainParameters = DomainParameters {
+    p: "A9FB57DBA1EEA9BC3E660A909D838D726E3BF623D52620282013481D1F6E5377",
+    a: "7D5A0975FC2C3057EEF67530417AFFE7FB8055C126DC5C6CE94A4B44F330B5D9",
+    b: "26DC5C6CE94A4B44F330B5D9BBD77CBF958416295CF7E1CE6BCCDC18FF8C07B6",
+    x: "8BD2AEB9CB7E57CB2C4B482FFC81B7AFB9DE27E1E3BD23C23A4453BD9ACE3262",
+    y: "547EF835C3DAC4FD97F8461A14611DC9C27745132DED8E545C1D54C72F046997",
+    n: "A9FB57DBA1EEA9BC3E660A909D838D718C397AA3B561A6F7901E0E82974856A7",
+};
+
+const BRAINPOOL_P384R1: DomainParameters = DomainParameters {
+    p: "8CB91E82A3386D280F5D6F7E50E641DF152F7109ED5456B412B1DA197FB71123\
+        ACD3A729901D1A71874700133107EC53",
+    a: "7BC382C63D8C150C3C72080ACE05AFA0C2BEA28E4FB22787139165EFBA91F90F\
+        8AA5814A503AD4EB04A8C7DD22CE2826",
+    b: "04A8C7DD22CE28268B39B55416F0447C2FB77DE107DCD2A62E880EA53EEB62D5\
+        7CB4390295DBC9943AB78696FA504C11",
+    x: "1D1C64F068CF45FFA2A63A81B7C13F6B8847A3E77EF14FE3DB7FCAFE0CBD10E8\
+        E826E03436D646AAEF87B2E247D4AF1E",
+    y: "8ABE1D7520F9C2A45CB1EB8E95CFD55262B70B29FEEC5864E19C054FF9912928\
+        0E4646217791811142820341263C5315",
+    n: "8CB91E82A3386D280F5D6F7E50E641DF152F7109ED5456B31F166E6CAC0425A7\
+        CF3AB6AF6B7FC3103B883202E9046565",
+};
+
+const BRAINPOOL_P512R1: DomainParameters = DomainParameters {
+    p: "AADD9DB8DBE9C48B3FD4E6AE33C9FC07CB308DB3B3C9D20ED6639CCA70330871\
+        7D4D9B009BC66842AECDA12AE6A380E62881FF2F2D82C68528AA6056583A48F3",
+    a: "7830A3318B603B89E2327145AC234CC594CBDD8D3DF91610A83441CAEA9863BC\
+        2DED5D5AA8253AA10A2EF1C98B9AC8B57F1117A72BF2C7B9E7C1AC4D77FC94CA",
+    b: "3DF91610A83441CAEA9863BC2DED5D5AA8253AA10A2EF1C98B9AC8B57F1117A7\
+        2BF2C7B9E7C1AC4D77FC94CADC083E67984050B75EBAE5DD2809BD638016F723",
+    x: "81AEE4BDD82ED9645A21322E9C4C6A9385ED9F70B5D916C1B43B62EEF4D0098E\
+        FF3B1F78E2D0D48D50D1687B93B97D5F7C6D5047406A5E688B352209BCB9F822",
+    y: "7DDE385D566332ECC0EABFA9CF7822FDF209F70024A57B1AA000C55B881F8111\
+        B2DCDE494A5F485E5BCA4BD88A2763AED1CA2B2FA8F0540678CD1E0F3AD80892",
+    n: "AADD9DB8DBE9C48B3FD4E6AE33C9FC07CB308DB3B3C9D20ED6639CCA70330870\
+        553E5C414CA92619418661197FAC10471DB1D381085DDADDB58796829CA90069",
+};
+
+/// Point in projective coordinates, (0 : 1 : 0) is the point at infinity
+#[derive(Clone, Copy)]
+struct Point<const LIMBS: usize> {
+    x: DynResidue<LIMBS>,
+    y: DynResidue<LIMBS>,
+    z: DynResidue<LIMBS>,
+}
+
+impl<const LIMBS: usize> ConditionallySelectable for Point<LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Point {
+            x: DynResidue::conditional_select(&a.x, &b.x, choice),
+            y: DynResidue::conditional_select(&a.y, &b.y, choice),
+            z: DynResidue::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+/// Byte level ECDSA operations of a Brainpool curve, independent of the integer width of the
+/// curve. Points are SEC1 encoded, scalars and signature components are big endian of the
+/// field size.
+trait CurveArithmetic {
+    /// Whether the bytes are a scalar in <b>[1, n - 1]</b>
+    fn is_valid_scalar(&self, bytes: &[u8]) -> bool;
+
+    /// Uncompressed public point of the secret scalar
+    fn public_point(&self, secret: &[u8]) -> Option<Vec<u8>>;
+
+    /// Uncompressed form of a SEC1 uncompressed or compressed point of the curve
+    fn decode_point(&self, bytes: &[u8]) -> Option<Vec<u8>>;
+
+    /// Fixed width <b>r || s</b> signature of the digest
+    fn sign_digest(&self, secret: &[u8], digest: &[u8], extra_entropy: &[u8]) -> Option<Vec<u8>>;
+
+    fn verify_digest(&self, point: &[u8], digest: &[u8], signature: &[u8]) -> bool;
+}
+
+/// Short Weierstrass arithmetic over the prime field of a Brainpool curve. The RustCrypto
+/// curve crates don't cover the Brainpool curves, so the group law is implemented here on the
+/// Montgomery form residues of crypto-bigint, whose field operations and inversion run in
+/// constant time. Points add with the complete formulas of Renes, Costello and Batina, which
+/// have no exceptional cases, and scalar multiplication doubles and adds on every bit and
+/// selects the result without branching. Secret scalars and nonces never reach a branch or a
+/// memory index; only public inputs (points, signatures) are checked in variable time.
+struct PrimeCurve<const LIMBS: usize> {
+    curve: BrainpoolCurve,
+    field: DynResidueParams<LIMBS>,
+    order: DynResidueParams<LIMBS>,
+    a: DynResidue<LIMBS>,
+    b: DynResidue<LIMBS>,
+    b3: DynResidue<LIMBS>,
+    g: Point<LIMBS>,
+}
+
+impl<const LIMBS: usize> PrimeCurve<LIMBS> {
+    /// Every Brainpool order has its top bit set, so scalars, field elements and digests of
+    /// the curve all have this length
+    const SIZE: usize = Uint::<LIMBS>::BYTES;
+
+    fn new(curve: BrainpoolCurve, params: &DomainParameters) -> PrimeCurve<LIMBS> {
+        let field = DynResidueParams::new(&Uint::from_be_hex(params.p));
+        let order = DynResidueParams::new(&Uint::from_be_hex(params.n));
+        let element = |hex: &str| DynResidue::new(&Uint::from_be_hex(hex), field);
+        let b = element(params.b);
+
+        PrimeCurve {
+            curve,
+            field,
+            order,
+            a: element(params.a),
+            b,
+            b3: b.add(&b).add(&b),
+            g: Point {
+                x: element(params.x),
+                y: element(params.y),
+                z: DynResidue::one(field),
+            },
+        }
+    }
+
+    fn identity(&self) -> Point<LIMBS> {
+        Point {
+            x: DynResidue::zero(self.field),
+            y: DynResidue::one(self.field),
+            z: DynResidue::zero(self.field),
+        }
+    }
+
+    fn scalar(&self, value: &Uint<LIMBS>) -> DynResidue<LIMBS> {
+        DynResidue::new(value, self.order)
+    }
+
+    fn to_bytes(value: &Uint<LIMBS>) -> Vec<u8> {
+        value
+            .as_words()
+            .iter()
+            .rev()
+            .flat_map(|word| word.to_be_bytes())
+            .collect()
+    }
+
+    /// Leftmost bits of the digest as an integer (RFC 6979 section 2.3.2). The order fills
+    /// its byte length, so truncating to whole bytes keeps exactly the order's bit length.
+    fn bits_to_int(bytes: &[u8]) -> Uint<LIMBS> {
+        let len = bytes.len().min(Self::SIZE);
+        let mut fixed = Zeroizing::new(vec![0u8; Self::SIZE]);
+        fixed[Self::SIZE - len..].copy_from_slice(&bytes[..len]);
+        Uint::from_be_slice(&fixed)
+    }
+
+    fn is_valid(&self, value: &Uint<LIMBS>) -> bool {
+        bool::from(!value.ct_eq(&Uint::ZERO) & value.ct_lt(self.order.modulus()))
+    }
+
+    /// Complete addition of Renes, Costello and Batina (eprint 2015/1060, algorithm 1). It
+    /// also doubles and handles the point at infinity, as the curve has prime order.
+    fn add(&self, left: &Point<LIMBS>, right: &Point<LIMBS>) -> Point<LIMBS> {
+        let t0 = left.x.mul(&right.x);
+        let t1 = left.y.mul(&right.y);
+        let t2 = left.z.mul(&right.z);
+        let t3 = left
+            .x
+            .add(&left.y)
+            .mul(&right.x.add(&right.y))
+            .sub(&t0.add(&t1));
+        let t4 = left
+            .x
+            .add(&left.z)
+            .mul(&right.x.add(&right.z))
+            .sub(&t0.add(&t2));
+        let t5 = left
+            .y
+            .add(&left.z)
+            .mul(&right.y.add(&right.z))
+            .sub(&t1.add(&t2));
+
+        let z3 = self.a.mul(&t4).add(&self.b3.mul(&t2));
+        let x3 = t1.sub(&z3);
+        let z3 = t1.add(&z3);
+        let y3 = x3.mul(&z3);
+
+        let t2 = self.a.mul(&t2);
+        let t1 = t0.add(&t0).add(&t0).add(&t2);
+        let t4 = self.b3.mul(&t4).add(&self.a.mul(&t0.sub(&t2)));
+
+        Point {
+            x: t3.mul(&x3).sub(&t5.mul(&t4)),
+            y: y3.add(&t1.mul(&t4)),
+            z: t5.mul(&z3).add(&t3.mul(&t1)),
+        }
+    }
+
+    /// Double and add on every bit of the scalar, keeping the sum by a constant time select
+    fn mul(&self, scalar: &Uint<LIMBS>, point: &Point<LIMBS>) -> Point<LIMBS> {
+        let mut result = self.identity();
+
+        for index in (0..Uint::<LIMBS>::BITS).rev() {
+            result = self.add(&result, &result);
+            let sum = self.add(&result, point);
+            result = Point::conditional_select(&result, &sum, Choice::from(scalar.bit(index)));
+        }
+
+        result
+    }
+
+    fn to_affine(&self, point: &Point<LIMBS>) -> Option<(Uint<LIMBS>, Uint<LIMBS>)> {
+        let (z_inv, is_some) = point.z.invert();
+        match bool::from(is_some) {
+            true => Some((
+                point.x.mul(&z_inv).retrieve(),
+                point.y.mul(&z_inv).retrieve(),
+            )),
+            false => None,
+        }
+    }
+
+    /// x^3 + ax + b
+    fn curve_rhs(&self, x: &DynResidue<LIMBS>) -> DynResidue<LIMBS> {
+        x.square().mul(x).add(&self.a.mul(x)).add(&self.b)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Option<Point<LIMBS>> {
+        let p = self.field.modulus();
+        let (tag, body) = bytes.split_first()?;
+
+        let (x, y) = match (*tag, body.len()) {
+            (0x04, len) if len == 2 * Self::SIZE => {
+                let x = Uint::from_be_slice(&body[..Self::SIZE]);
+                let y = Uint::from_be_slice(&body[Self::SIZE..]);
+                if &x >= p || &y >= p {
+                    return None;
+                }
+
+                (
+                    DynResidue::new(&x, self.field),
+                    DynResidue::new(&y, self.field),
+                )
+            }
+            (0x02 | 0x03, len) if len == Self::SIZE => {
+                let x = Uint::from_be_slice(body);
+                if &x >= p {
+                    return None;
+                }
+
+                // p = 3 mod 4 for every Brainpool curve
+                let x = DynResidue::new(&x, self.field);
+                let y = self
+                    .curve_rhs(&x)
+                    .pow(&p.wrapping_add(&Uint::ONE).shr_vartime(2));
+                match y.retrieve().bit_vartime(0) == (tag & 1 == 1) {
+                    true => (x, y),
+                    false => (x, y.neg()),
+                }
+            }
+            _ => return None,
+        };
+
+        match y.square().retrieve() == self.curve_rhs(&x).retrieve() {
+            true => Some(Point {
+                x,
+                y,
+                z: DynResidue::one(self.field),
+            }),
+            false => None,
+        }
+    }
+
+    fn encode(x: &Uint<LIMBS>, y: &Uint<LIMBS>) -> Vec<u8> {
+        let mut encoded = vec![0x04];
+        encoded.extend_from_slice(&Self::to_bytes(x));
+        encoded.extend_from_slice(&Self::to_bytes(y));
+        encoded
+    }
+
+    /// Deterministic nonce of RFC 6979 section 3.2, with the additional data of section 3.6
+    /// mixed into the seeding of K when hedged
+    fn rfc6979_nonce(
+        &self,
+        secret: &[u8],
+        digest: &[u8],
+        extra_entropy: &[u8],
+    ) -> Zeroizing<Uint<LIMBS>> {
+        let h1 = Self::to_bytes(&self.scalar(&Self::bits_to_int(digest)).retrieve());
+
+        let mut v = vec![0x01u8; digest.len()];
+        let mut k = Zeroizing::new(vec![0x00u8; digest.len()]);
+
+        *k = self
+            .curve
+            .hmac(&k, &[&v, &[0x00], secret, &h1, extra_entropy]);
+        v = self.curve.hmac(&k, &[&v]);
+        *k = self
+            .curve
+            .hmac(&k, &[&v, &[0x01], secret, &h1, extra_entropy]);
+        v = self.curve.hmac(&k, &[&v]);
+
+        loop {
+            let mut t = Zeroizing::new(Vec::new());
+            while t.len() < Self::SIZE {
+                v = self.curve.hmac(&k, &[&v]);
+                t.extend_from_slice(&v);
+            }
+
+            let nonce = Zeroizing::new(Self::bits_to_int(&t[..Self::SIZE]));
+            if self.is_valid(&nonce) {
+                return nonce;
+            }
+
+            *k = self.curve.hmac(&k, &[&v, &[0x00]]);
+            v = self.curve.hmac(&k, &[&v]);
+        }
+    }
+}
+
+impl<const LIMBS: usize> CurveArithmetic for PrimeCurve<LIMBS> {
+    fn is_valid_scalar(&self, bytes: &[u8]) -> bool {
+        bytes.len() == Self::SIZE && self.is_valid(&Zeroizing::new(Uint::from_be_slice(bytes)))
+    }
+
+    fn public_point(&self, secret: &[u8]) -> Option<Vec<u8>> {
+        if !self.is_valid_scalar(secret) {
+            return None;
+        }
+
+        let secret = Zeroizing::new(Uint::from_be_slice(secret));
+        let (x, y) = self.to_affine(&self.mul(&secret, &self.g))?;
+        Some(Self::encode(&x, &y))
+    }
+
+    fn decode_point(&self, bytes: &[u8]) -> Option<Vec<u8>> {
+        let (x, y) = self.to_affine(&self.decode(bytes)?)?;
+        Some(Self::encode(&x, &y))
+    }
+
+    fn sign_digest(&self, secret: &[u8], digest: &[u8], extra_entropy: &[u8]) -> Option<Vec<u8>> {
+        if !self.is_valid_scalar(secret) {
+            return None;
+        }
+
+        let d = Zeroizing::new(self.scalar(&Uint::from_be_slice(secret)));
+        let k = self.rfc6979_nonce(secret, digest, extra_entropy);
+        let (x, _) = self.to_affine(&self.mul(&k, &self.g))?;
+
+        let r = self.scalar(&x);
+        let k_inv = Zeroizing::new(self.scalar(&k).invert().0);
+        let e = self.scalar(&Self::bits_to_int(digest));
+        let r = r.retrieve();
+        let s = k_inv.mul(&e.add(&self.scalar(&r).mul(&d))).retrieve();
+
+        let n = self.order.modulus();
+        let s = match signs_low_s() && s > n.shr_vartime(1) {
+            true => n.wrapping_sub(&s),
+            false => s,
+        };
+
+        if r == Uint::ZERO || s == Uint::ZERO {
+            return None;
+        }
+
+        let mut signature = Self::to_bytes(&r);
+        signature.extend_from_slice(&Self::to_bytes(&s));
+        Some(signature)
+    }
+
+    fn verify_digest(&self, point: &[u8], digest: &[u8], signature: &[u8]) -> bool {
+        let public = match self.decode(point) {
+            Some(val) => val,
+            None => return false,
+        };
+        if signature.len() != 2 * Self::SIZE {
+            return false;
+        }
+
+        let r = Uint::from_be_slice(&signature[..Self::SIZE]);
+        let s = Uint::from_be_slice(&signature[Self::SIZE..]);
+        if !self.is_valid(&r) || !self.is_valid(&s) {
+            return false;
+        }
+        if rejects_high_s(s > self.order.modulus().shr_vartime(1)) {
+            return false;
+        }
+
+        let (w, _) = self.scalar(&s).invert();
+        let u1 = self.scalar(&Self::bits_to_int(digest)).mul(&w).retrieve();
+        let u2 = self.scalar(&r).mul(&w).retrieve();
+
+        let sum = self.add(&self.mul(&u1, &self.g), &self.mul(&u2, &public));
+        match self.to_affine(&sum) {
+            Some((x, _)) => self.scalar(&x).retrieve() == r,
+            None => false,
+        }
+    }
+}
+
+fn hmac_parts<M: Mac + KeyInit>(key: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+    // HMAC accepts keys of any length
+    let mut mac = match <M as KeyInit>::new_from_slice(key) {
+        Ok(val) => val,
+        Err(_) => return Vec::new(),
+    };
+
+    for part in parts {
+        mac.update(part);
+    }
+
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[derive(Clone)]
+struct PrivateKey {
+    curve: BrainpoolCurve,
+    secret: Zeroizing<Vec<u8>>,
+}
+
+#[derive(Clone)]
+struct PublicKey {
+    curve: BrainpoolCurve,
+    /// Uncompressed SEC1 point
+    point: Vec<u8>,
+}
+
+impl PrivateKey {
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        match self.curve.arithmetic().public_point(&self.secret) {
+            Some(point) => Ok(PublicKey {
+                curve: self.curve,
+                point,
+            }),
+            None => Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+        }
+    }
+
+    /// Fixed width <b>r || s</b> ECDSA signature of the digest. Extra entropy hedges the
+    /// deterministic nonce, an empty slice signs deterministically.
+    fn sign_digest(&self, digest: &[u8], extra_entropy: &[u8]) -> Result<Vec<u8>, Error> {
+        match self
+            .curve
+            .arithmetic()
+            .sign_digest(&self.secret, digest, extra_entropy)
+        {
+            Some(val) => Ok(val),
+            None => {
+                fi_common::logger::error("Brainpool ECDSA produced a zero signature component");
+                Err(Error::new(crate::errors::SIGNING_FAILED))
+            }
+        }
+    }
+}
+
+impl PublicKey {
+    fn to_sec1_bytes(&self) -> Vec<u8> {
+        self.point.clone()
+    }
+
+    fn verify_digest(&self, digest: &[u8], signature: &[u8]) -> Result<bool, Error> {
+        let size = self.curve.field_size();
+        if signature.len() != 2 * size {
+            fi_common::logger::error(
+                format!(
+                    "Brainpool signature is {} bytes, expected {} bytes",
+                    signature.len(),
+                    2 * size
+                )
+                .as_str(),
+            );
+            return Err(Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED));
+        }
+
+        Ok(self
+            .curve
+            .arithmetic()
+            .verify_digest(&self.point, digest, signature))
+    }
+}
+
+fn check_algorithm(curve: BrainpoolCurve, alg: Algorithm) -> Result<(), Error> {
+    match curve.algorithm() == alg {
+        true => Ok(()),
+        false => Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH)),
+    }
+}
+
+fn get_private_key_from_bytes(curve: BrainpoolCurve, bytes: &[u8]) -> Result<PrivateKey, Error> {
+    if let Err(error) = get_key_policy().check_curve(curve.policy_curve()) {
+        return Err(error);
+    }
+
+    if !curve.arithmetic().is_valid_scalar(bytes) {
+        fi_common::logger::error("Brainpool private key isn't a valid scalar of the curve");
+        return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+    }
+
+    Ok(PrivateKey {
+        curve,
+        secret: Zeroizing::new(bytes.to_vec()),
+    })
+}
+
+/// Private key of a <b>sec1</b> ECPrivateKey structure. The curve comes from the structure
+/// or, inside <b>pkcs8</b>, from the algorithm parameters.
+fn get_private_key_from_sec1_der(
+    der: &[u8],
+    curve: Option<BrainpoolCurve>,
+) -> Result<PrivateKey, Error> {
+    let ec_key = match sec1::EcPrivateKey::try_from(der) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::EC_PEM_ERROR));
+        }
+    };
+
+    let named_curve = ec_key
+        .parameters
+        .and_then(|params| params.named_curve())
+        .and_then(BrainpoolCurve::from_oid);
+    let curve = match (curve, named_curve) {
+        (Some(outer), Some(inner)) if outer != inner => {
+            return Err(Error::new(crate::errors::EC_PEM_ERROR))
+        }
+        (Some(val), _) | (None, Some(val)) => val,
+        (None, None) => return Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE)),
+    };
+
+    get_private_key_from_bytes(curve, ec_key.private_key)
+}
+
+fn get_private_key_from_der(der: &[u8]) -> Result<PrivateKey, Error> {
+    let key_info = match PrivateKeyInfo::try_from(der) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+        }
+    };
+
+    let curve = match key_info.algorithm.oids() {
+        Ok((algorithm, Some(curve))) if algorithm == OID_EC_PUBLIC_KEY => {
+            BrainpoolCurve::from_oid(curve)
+        }
+        Ok(_) => None,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            None
+        }
+    };
+
+    match curve {
+        Some(val) => get_private_key_from_sec1_der(key_info.private_key, Some(val)),
+        None => Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE)),
+    }
+}
+
+fn get_private_key_from_pem(key_str: &str) -> Result<PrivateKey, Error> {
+    let (label, document) = match SecretDocument::from_pem(key_str) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::EC_PEM_ERROR));
+        }
+    };
+
+    match label {
+        "EC PRIVATE KEY" => get_private_key_from_sec1_der(document.as_bytes(), None),
+        _ => get_private_key_from_der(document.as_bytes()),
+    }
+}
+
+fn private_key_to_der(key: &PrivateKey) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let public_key = match key.public_key() {
+        Ok(val) => val.to_sec1_bytes(),
+        Err(error) => return Err(error),
+    };
+
+    let ec_key = sec1::EcPrivateKey {
+        private_key: key.secret.as_slice(),
+        parameters: None,
+        public_key: Some(public_key.as_slice()),
+    };
+    let ec_der = match ec_key.to_der() {
+        Ok(val) => Zeroizing::new(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::ENCODING_ERROR));
+        }
+    };
+
+    let curve_oid = key.curve.oid();
+    let key_info = PrivateKeyInfo::new(
+        AlgorithmIdentifierRef {
+            oid: OID_EC_PUBLIC_KEY,
+            parameters: Some(AnyRef::from(&curve_oid)),
+        },
+        ec_der.as_slice(),
+    );
+
+    match key_info.to_der() {
+        Ok(val) => Ok(Zeroizing::new(val)),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::ENCODING_ERROR))
+        }
+    }
+}
+
+fn get_public_key_from_bytes(curve: BrainpoolCurve, bytes: &[u8]) -> Result<PublicKey, Error> {
+    if let Err(error) = get_key_policy().check_curve(curve.policy_curve()) {
+        return Err(error);
+    }
+
+    match curve.arithmetic().decode_point(bytes) {
+        Some(point) => Ok(PublicKey { curve, point }),
+        None => {
+            fi_common::logger::error("Brainpool public key isn't a point of the curve");
+            Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR))
+        }
+    }
+}
+
+fn get_public_key_from_der(der: &[u8]) -> Result<PublicKey, Error> {
+    let key_info = match SubjectPublicKeyInfoRef::try_from(der) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR));
+        }
+    };
+
+    let curve = match key_info.algorithm.oids() {
+        Ok((algorithm, Some(curve))) if algorithm == OID_EC_PUBLIC_KEY => {
+            BrainpoolCurve::from_oid(curve)
+        }
+        Ok(_) => None,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            None
+        }
+    };
+
+    match (curve, key_info.subject_public_key.as_bytes()) {
+        (Some(curve), Some(bytes)) => get_public_key_from_bytes(curve, bytes),
+        (None, _) => Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE)),
+        (_, None) => Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+    }
+}
+
+fn get_public_key_from_pem(key_str: &str) -> Result<PublicKey, Error> {
+    match Document::from_pem(key_str) {
+        Ok((_, document)) => get_public_key_from_der(document.as_bytes()),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::EC_PEM_ERROR))
+        }
+    }
+}
+
+fn public_key_to_der(key: &PublicKey) -> Result<Vec<u8>, Error> {
+    let encoded = key.to_sec1_bytes();
+    let subject_public_key = match BitStringRef::from_bytes(encoded.as_slice()) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::ENCODING_ERROR));
+        }
+    };
+
+    let curve_oid = key.curve.oid();
+    let key_info = SubjectPublicKeyInfoRef {
+        algorithm: AlgorithmIdentifierRef {
+            oid: OID_EC_PUBLIC_KEY,
+            parameters: Some(AnyRef::from(&curve_oid)),
+        },
+        subject_public_key,
+    };
+
+    match key_info.to_der() {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::ENCODING_ERROR))
+        }
+    }
+}
+
+fn to_pem(label: &str, der: &[u8]) -> Result<String, Error> {
+    match pem::encode_string(label, LineEnding::LF, der) {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::ENCODING_ERROR))
+        }
+    }
+}
+
+/// Signing key for [`crate::algorithms::Algorithm::BP256R1`],
+/// [`crate::algorithms::Algorithm::BP384R1`] and [`crate::algorithms::Algorithm::BP512R1`]
+#[wasm_bindgen]
+pub struct BrainpoolSigningKey {
+    #[cfg(not(feature = "wasm"))]
+    key: PrivateKey,
+
+    #[cfg(feature = "wasm")]
+    key_str: Option<Zeroizing<String>>,
+    #[cfg(feature = "wasm")]
+    key_bytes: Option<Zeroizing<Vec<u8>>>,
+    #[cfg(feature = "wasm")]
+    curve: BrainpoolCurve,
+
+    hedged: bool,
+}
+
+impl SignFromKey for BrainpoolSigningKey {
+    fn sign_bytes(&self, content: &[u8], alg: Algorithm) -> Result<Vec<u8>, Error> {
+        #[cfg(not(feature = "wasm"))]
+        let key = &self.key;
+
+        #[cfg(feature = "wasm")]
+        let key = &match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if let Err(error) = check_algorithm(key.curve, alg) {
+            return Err(error);
+        }
+
+        self.sign_hashed(key, &key.curve.digest(content))
+    }
+}
+
+impl SignPrehashed for BrainpoolSigningKey {
+    fn sign_prehashed(&self, digest: &[u8], alg: Algorithm) -> Result<String, Error> {
+        #[cfg(not(feature = "wasm"))]
+        let key = &self.key;
+
+        #[cfg(feature = "wasm")]
+        let key = &match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if let Err(error) = check_prehash(key.curve.algorithm(), digest, alg) {
+            return Err(error);
+        }
+
+        match self.sign_hashed(key, digest) {
+            Ok(val) => Ok(base64_url::encode(&val)),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl SignStream for BrainpoolSigningKey {
+    fn sign_stream_hasher(&self, alg: Algorithm) -> Result<StreamHasher, Error> {
+        match self.get_key() {
+            Ok(val) => stream_hasher(val.curve.algorithm(), alg),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn sign_stream(&self, hasher: StreamHasher, alg: Algorithm) -> Result<String, Error> {
+        sign_digest_stream(self, hasher, alg)
+    }
+}
+
+impl BrainpoolSigningKey {
+    fn sign_hashed(&self, key: &PrivateKey, digest: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut extra_entropy = Zeroizing::new(Vec::new());
+        if self.hedged {
+            extra_entropy.resize(key.curve.field_size(), 0);
+            OsRng.fill_bytes(extra_entropy.as_mut_slice());
+        }
+
+        key.sign_digest(digest, &extra_entropy)
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+impl BrainpoolSigningKey {
+    /// Create signing key from pem formatted private key. <b>pkcs8</b> and <b>sec1</b>.
+    pub fn from_pem(key_str: &str) -> Result<BrainpoolSigningKey, Error> {
+        match get_private_key_from_pem(key_str) {
+            Ok(val) => Ok(BrainpoolSigningKey {
+                key: val,
+                hedged: false,
+            }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create signing key from a <b>pkcs8</b> DER encoded private key.
+    pub fn from_der(bytes: &[u8]) -> Result<BrainpoolSigningKey, Error> {
+        match get_private_key_from_der(bytes) {
+            Ok(val) => Ok(BrainpoolSigningKey {
+                key: val,
+                hedged: false,
+            }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create signing key from the raw private scalar.
+    pub fn from_bytes(curve: BrainpoolCurve, bytes: &[u8]) -> Result<BrainpoolSigningKey, Error> {
+        match get_private_key_from_bytes(curve, bytes) {
+            Ok(val) => Ok(BrainpoolSigningKey {
+                key: val,
+                hedged: false,
+            }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Curve of the key
+    pub fn curve(&self) -> BrainpoolCurve {
+        self.key.curve
+    }
+
+    fn get_key(&self) -> Result<&PrivateKey, Error> {
+        Ok(&self.key)
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl BrainpoolSigningKey {
+    /// Create signing key from pem formatted private key. <b>pkcs8</b> and <b>sec1</b>.
+    #[wasm_bindgen(js_name = "fromPem")]
+    pub fn from_pem(key_str: &str) -> BrainpoolSigningKey {
+        BrainpoolSigningKey {
+            key_str: Some(Zeroizing::new(String::from(key_str))),
+            key_bytes: None,
+            curve: BrainpoolCurve::P256r1,
+            hedged: false,
+        }
+    }
+
+    /// Create signing key from the raw private scalar.
+    #[wasm_bindgen(js_name = "fromBytes")]
+    pub fn from_bytes(curve: BrainpoolCurve, bytes: &[u8]) -> BrainpoolSigningKey {
+        BrainpoolSigningKey {
+            key_str: None,
+            key_bytes: Some(Zeroizing::new(bytes.to_vec())),
+            curve,
+            hedged: false,
+        }
+    }
+
+    /// Curve of the key
+    pub fn curve(&self) -> Result<BrainpoolCurve, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.curve),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn get_key(&self) -> Result<PrivateKey, Error> {
+        match (&self.key_str, &self.key_bytes) {
+            (Some(key_str), _) => get_private_key_from_pem(key_str.as_str()),
+            (None, Some(key_bytes)) => get_private_key_from_bytes(self.curve, key_bytes.as_slice()),
+            (None, None) => Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+        }
+    }
+
+    /// Key from a <b>{pem: ...}</b> object or the raw private scalar of the curve of the
+    /// algorithm
+    pub fn from_js_object(value: Object, alg: Algorithm) -> Result<BrainpoolSigningKey, Error> {
+        let pem_field = JsValue::from_str("pem");
+
+        if value.has_own_property(&pem_field) {
+            let pem = match js_sys::Reflect::get(&value, &pem_field) {
+                Ok(val) => match val.as_string() {
+                    Some(v) => Zeroizing::new(v),
+                    None => return Err(Error::new(crate::errors::MISSING_FIELD)),
+                },
+                Err(error) => {
+                    fi_common::logger::error(error.as_string().unwrap().as_str());
+                    return Err(Error::new(crate::errors::MISSING_FIELD));
+                }
+            };
+
+            let mut key = BrainpoolSigningKey::from_pem(pem.as_str());
+            key.hedged = match read_hedged(&value) {
+                Ok(val) => val,
+                Err(error) => return Err(error),
+            };
+
+            Ok(key)
+        } else if value.is_array() {
+            let curve = match BrainpoolCurve::from_algorithm(alg) {
+                Some(val) => val,
+                None => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+            };
+            let arr = Zeroizing::new(Uint8Array::new(&value).to_vec());
+
+            Ok(BrainpoolSigningKey::from_bytes(curve, arr.as_slice()))
+        } else {
+            Err(Error::new(crate::errors::MISSING_FIELD))
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl BrainpoolSigningKey {
+    /// Create a signing key from a new random scalar
+    pub fn generate(curve: BrainpoolCurve) -> Result<BrainpoolSigningKey, Error> {
+        let arithmetic = curve.arithmetic();
+        let mut bytes = Zeroizing::new(vec![0u8; curve.field_size()]);
+
+        loop {
+            OsRng.fill_bytes(bytes.as_mut_slice());
+            if arithmetic.is_valid_scalar(&bytes) {
+                break;
+            }
+        }
+
+        #[cfg(not(feature = "wasm"))]
+        return BrainpoolSigningKey::from_bytes(curve, bytes.as_slice());
+
+        #[cfg(feature = "wasm")]
+        return Ok(BrainpoolSigningKey::from_bytes(curve, bytes.as_slice()));
+    }
+
+    /// Raw private scalar
+    #[wasm_bindgen(js_name = "toBytes")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.secret.to_vec()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// <b>pkcs8</b> pem formatted private key
+    #[wasm_bindgen(js_name = "toPem")]
+    pub fn to_pem(&self) -> Result<String, Error> {
+        #[cfg(not(feature = "wasm"))]
+        let key = &self.key;
+
+        #[cfg(feature = "wasm")]
+        let key = &match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match private_key_to_der(key) {
+            Ok(val) => to_pem("PRIVATE KEY", val.as_slice()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Verifying key of this key
+    #[wasm_bindgen(js_name = "verifyingKey")]
+    pub fn verifying_key(&self) -> Result<BrainpoolVerifyingKey, Error> {
+        match self.get_key().and_then(|key| key.public_key()) {
+            Ok(val) => Ok(BrainpoolVerifyingKey::from_key(val)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Enable or disable hedged signing. Hedged signatures mix fresh randomness into the
+    /// RFC 6979 nonce, signing is deterministic by default.
+    #[wasm_bindgen(js_name = "setHedged")]
+    pub fn set_hedged(&mut self, hedged: bool) {
+        self.hedged = hedged;
+    }
+
+    /// Check if hedged signing is enabled
+    #[wasm_bindgen(js_name = "isHedged")]
+    pub fn is_hedged(&self) -> bool {
+        self.hedged
+    }
+}
+
+/// Verifying key for [`crate::algorithms::Algorithm::BP256R1`],
+/// [`crate::algorithms::Algorithm::BP384R1`] and [`crate::algorithms::Algorithm::BP512R1`]
+#[wasm_bindgen]
+pub struct BrainpoolVerifyingKey {
+    #[cfg(not(feature = "wasm"))]
+    key: PublicKey,
+
+    #[cfg(feature = "wasm")]
+    key_str: Option<String>,
+    #[cfg(feature = "wasm")]
+    key_bytes: Option<Vec<u8>>,
+    #[cfg(feature = "wasm")]
+    curve: BrainpoolCurve,
+}
+
+impl VerifyFromKey for BrainpoolVerifyingKey {
+    fn check_key_policy(&self, policy: &KeyPolicy) -> Result<(), Error> {
+        match self.get_key() {
+            Ok(val) => policy.check_curve(val.curve.policy_curve()),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn verify_bytes(
+        &self,
+        content: &[u8],
+        signature: &[u8],
+        alg: Algorithm,
+    ) -> Result<bool, Error> {
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if let Err(error) = check_algorithm(key.curve, alg) {
+            return Err(error);
+        }
+
+        key.verify_digest(&key.curve.digest(content), signature)
+    }
+}
+
+impl VerifyPrehashed for BrainpoolVerifyingKey {
+    fn verify_prehashed(
+        &self,
+        digest: &[u8],
+        signature: String,
+        alg: Algorithm,
+    ) -> Result<bool, Error> {
+        let decoded_sig = match base64_url::decode(signature.as_bytes()) {
+            Ok(val) => val,
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                return Err(Error::new(crate::errors::DECODING_ERROR));
+            }
+        };
+
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if let Err(error) = check_prehash(key.curve.algorithm(), digest, alg) {
+            return Err(error);
+        }
+
+        key.verify_digest(digest, &decoded_sig)
+    }
+}
+
+impl VerifyStream for BrainpoolVerifyingKey {
+    fn verify_stream_hasher(&self, alg: Algorithm) -> Result<StreamHasher, Error> {
+        match self.get_key() {
+            Ok(val) => stream_hasher(val.curve.algorithm(), alg),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn verify_stream(
+        &self,
+        hasher: StreamHasher,
+        signature: String,
+        alg: Algorithm,
+    ) -> Result<bool, Error> {
+        verify_digest_stream(self, hasher, signature, alg)
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+impl BrainpoolVerifyingKey {
+    /// Create verifying key from pem formatted public key. <b>spki</b> only.
+    pub fn from_pem(key_str: &str) -> Result<BrainpoolVerifyingKey, Error> {
+        match get_public_key_from_pem(key_str) {
+            Ok(val) => Ok(BrainpoolVerifyingKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create verifying key from a <b>spki</b> DER encoded public key.
+    pub fn from_der(bytes: &[u8]) -> Result<BrainpoolVerifyingKey, Error> {
+        match get_public_key_from_der(bytes) {
+            Ok(val) => Ok(BrainpoolVerifyingKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create verifying key from a SEC1 encoded point, compressed or uncompressed.
+    pub fn from_bytes(curve: BrainpoolCurve, bytes: &[u8]) -> Result<BrainpoolVerifyingKey, Error> {
+        match get_public_key_from_bytes(curve, bytes) {
+            Ok(val) => Ok(BrainpoolVerifyingKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn from_key(key: PublicKey) -> BrainpoolVerifyingKey {
+        BrainpoolVerifyingKey { key }
+    }
+
+    /// Curve of the key
+    pub fn curve(&self) -> BrainpoolCurve {
+        self.key.curve
+    }
+
+    fn get_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.key.clone())
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl BrainpoolVerifyingKey {
+    /// Create verifying key from pem formatted public key. <b>spki</b> only.
+    #[wasm_bindgen(js_name = "fromPem")]
+    pub fn from_pem(key_str: &str) -> BrainpoolVerifyingKey {
+        BrainpoolVerifyingKey {
+            key_str: Some(String::from(key_str)),
+            key_bytes: None,
+            curve: BrainpoolCurve::P256r1,
+        }
+    }
+
+    /// Create verifying key from a SEC1 encoded point, compressed or uncompressed.
+    #[wasm_bindgen(js_name = "fromBytes")]
+    pub fn from_bytes(curve: BrainpoolCurve, bytes: &[u8]) -> BrainpoolVerifyingKey {
+        BrainpoolVerifyingKey {
+            key_str: None,
+            key_bytes: Some(bytes.to_vec()),
+            curve,
+        }
+    }
+
+    fn from_key(key: PublicKey) -> BrainpoolVerifyingKey {
+        BrainpoolVerifyingKey::from_bytes(key.curve, key.to_sec1_bytes().as_slice())
+    }
+
+    /// Curve of the key
+    pub fn curve(&self) -> Result<BrainpoolCurve, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.curve),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn get_key(&self) -> Result<PublicKey, Error> {
+        match (&self.key_str, &self.key_bytes) {
+            (Some(key_str), _) => get_public_key_from_pem(key_str.as_str()),
+            (None, Some(key_bytes)) => get_public_key_from_bytes(self.curve, key_bytes.as_slice()),
+            (None, None) => Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+        }
+    }
+
+    /// Key from a <b>{pem: ...}</b> object or the SEC1 encoded point of the curve of the
+    /// algorithm
+    pub fn from_js_object(value: Object, alg: Algorithm) -> Result<BrainpoolVerifyingKey, Error> {
+        let pem_field = JsValue::from_str("pem");
+
+        if value.has_own_property(&pem_field) {
+            let pem = match js_sys::Reflect::get(&value, &pem_field) {
+                Ok(val) => match val.as_string() {
+                    Some(v) => v,
+                    None => return Err(Error::new(crate::errors::MISSING_FIELD)),
+                },
+                Err(error) => {
+                    fi_common::logger::error(error.as_string().unwrap().as_str());
+                    return Err(Error::new(crate::errors::MISSING_FIELD));
+                }
+            };
+
+            Ok(BrainpoolVerifyingKey::from_pem(pem.as_str()))
+        } else if value.is_array() {
+            let curve = match BrainpoolCurve::from_algorithm(alg) {
+                Some(val) => val,
+                None => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+            };
+
+            Ok(BrainpoolVerifyingKey::from_bytes(
+                curve,
+                Uint8Array::new(&value).to_vec().as_slice(),
+            ))
+        } else {
+            Err(Error::new(crate::errors::MISSING_FIELD))
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl BrainpoolVerifyingKey {
+    /// Uncompressed SEC1 encoded point
+    #[wasm_bindgen(js_name = "toBytes")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.to_sec1_bytes()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// <b>spki</b> pem formatted public key
+    #[wasm_bindgen(js_name = "toPem")]
+    pub fn to_pem(&self) -> Result<String, Error> {
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match public_key_to_der(&key) {
+            Ok(val) => to_pem("PUBLIC KEY", val.as_slice()),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Sign content using [`crate::algorithms::Algorithm::BP256R1`],
+/// [`crate::algorithms::Algorithm::BP384R1`] or [`crate::algorithms::Algorithm::BP512R1`]
+pub fn bp_sign(message: String, key: impl SignFromKey, alg: Algorithm) -> Result<String, Error> {
+    key.sign(message, alg)
+}
+
+/// Verify signature using [`crate::algorithms::Algorithm::BP256R1`],
+/// [`crate::algorithms::Algorithm::BP384R1`] or [`crate::algorithms::Algorithm::BP512R1`]
+pub fn bp_verify(
+    message: String,
+    sig: String,
+    key: impl VerifyFromKey,
+    alg: Algorithm,
+) -> Result<bool, Error> {
+    key.verify(message, sig, alg)
+}
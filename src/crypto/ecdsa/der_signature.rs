@@ -0,0 +1,235 @@
+use crate::algorithms::Algorithm;
+#[cfg(not(feature = "wasm"))]
+use crate::crypto::{SignFromKey, VerifyFromKey};
+use fi_common::error::Error;
+#[cfg(feature = "wasm")]
+use js_sys::Object;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+const SEQUENCE_TAG: u8 = 0x30;
+const INTEGER_TAG: u8 = 0x02;
+
+/// Length of r and s in the raw signature of the algorithm
+fn scalar_length(alg: Algorithm) -> Result<usize, Error> {
+    match alg {
+        Algorithm::ES256 | Algorithm::ES256K => Ok(32),
+        Algorithm::ES384 => Ok(48),
+        Algorithm::ES512 => Ok(66),
+        _ => Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+    }
+}
+
+fn push_length(length: usize, output: &mut Vec<u8>) {
+    // r and s of P-521 make the sequence longer than 127 bytes, but never longer than 255
+    if length >= 0x80 {
+        output.push(0x81);
+    }
+    output.push(length as u8);
+}
+
+fn push_integer(bytes: &[u8], output: &mut Vec<u8>) {
+    let start = bytes
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(bytes.len() - 1);
+    let value = &bytes[start..];
+    let padded = value[0] & 0x80 != 0;
+
+    output.push(INTEGER_TAG);
+    push_length(value.len() + padded as usize, output);
+    if padded {
+        output.push(0);
+    }
+    output.extend_from_slice(value);
+}
+
+/// Length of a DER element at the start of the bytes and the number of bytes it takes.
+/// Only the minimal short and one byte long forms are accepted.
+fn read_length(bytes: &[u8]) -> Option<(usize, usize)> {
+    match bytes {
+        [length, ..] if *length < 0x80 => Some((*length as usize, 1)),
+        [0x81, length, ..] if *length >= 0x80 => Some((*length as usize, 2)),
+        _ => None,
+    }
+}
+
+/// Reads a positive, minimally encoded DER integer into a fixed width big endian value.
+/// Returns the value and the remaining bytes.
+fn read_integer(bytes: &[u8], width: usize) -> Option<(Vec<u8>, &[u8])> {
+    if bytes.first() != Some(&INTEGER_TAG) {
+        return None;
+    }
+
+    let (length, length_size) = read_length(&bytes[1..])?;
+    let start = 1 + length_size;
+    if length == 0 || bytes.len() < start + length {
+        return None;
+    }
+
+    let value = &bytes[start..start + length];
+    let negative = value[0] & 0x80 != 0;
+    let non_minimal = value.len() > 1 && value[0] == 0 && value[1] & 0x80 == 0;
+    if negative || non_minimal {
+        return None;
+    }
+
+    let value = match value[0] {
+        0 => &value[1..],
+        _ => value,
+    };
+    if value.len() > width {
+        return None;
+    }
+
+    let mut fixed = vec![0u8; width - value.len()];
+    fixed.extend_from_slice(value);
+    Some((fixed, &bytes[start + length..]))
+}
+
+/// Convert a raw fixed width <b>r || s</b> ECDSA signature of
+/// [`crate::algorithms::Algorithm::ES256`], [`crate::algorithms::Algorithm::ES384`],
+/// [`crate::algorithms::Algorithm::ES512`] or [`crate::algorithms::Algorithm::ES256K`] into
+/// an ASN.1 DER <b>ECDSA-Sig-Value</b>
+#[wasm_bindgen(js_name = "ecdsaRawToDer")]
+pub fn raw_to_der(alg: Algorithm, signature: &[u8]) -> Result<Vec<u8>, Error> {
+    let width = match scalar_length(alg) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    if signature.len() != 2 * width {
+        fi_common::logger::error(
+            format!(
+                "ECDSA signature is {} bytes, expected {} bytes",
+                signature.len(),
+                2 * width
+            )
+            .as_str(),
+        );
+        return Err(Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED));
+    }
+
+    let mut integers = Vec::with_capacity(2 * width + 6);
+    push_integer(&signature[..width], &mut integers);
+    push_integer(&signature[width..], &mut integers);
+
+    let mut der = vec![SEQUENCE_TAG];
+    push_length(integers.len(), &mut der);
+    der.extend_from_slice(&integers);
+    Ok(der)
+}
+
+/// Convert an ASN.1 DER <b>ECDSA-Sig-Value</b> into the raw fixed width <b>r || s</b>
+/// signature of the algorithm. Only strict DER is accepted.
+#[wasm_bindgen(js_name = "ecdsaDerToRaw")]
+pub fn der_to_raw(alg: Algorithm, signature: &[u8]) -> Result<Vec<u8>, Error> {
+    let width = match scalar_length(alg) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    let raw =
+        match signature.split_first() {
+            Some((&SEQUENCE_TAG, rest)) => read_length(rest).and_then(|(length, length_size)| {
+                match rest.len() == length_size + length {
+                    true => Some(&rest[length_size..]),
+                    false => None,
+                }
+            }),
+            _ => None,
+        }
+        .and_then(|integers| read_integer(integers, width))
+        .and_then(|(r, rest)| read_integer(rest, width).map(|(s, rest)| (r, s, rest)))
+        .and_then(|(mut r, s, rest)| match rest.is_empty() {
+            true => {
+                r.extend_from_slice(&s);
+                Some(r)
+            }
+            false => None,
+        });
+
+    match raw {
+        Some(val) => Ok(val),
+        None => {
+            fi_common::logger::error("Malformed DER encoded ECDSA signature");
+            Err(Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED))
+        }
+    }
+}
+
+/// Convert a base64url encoded JOSE signature, as returned by [`crate::signer::sign`], into an
+/// ASN.1 DER <b>ECDSA-Sig-Value</b>
+#[wasm_bindgen(js_name = "ecdsaJoseToDer")]
+pub fn jose_to_der(alg: Algorithm, signature: &str) -> Result<Vec<u8>, Error> {
+    match base64_url::decode(signature.as_bytes()) {
+        Ok(val) => raw_to_der(alg, &val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::DECODING_ERROR))
+        }
+    }
+}
+
+/// Convert an ASN.1 DER <b>ECDSA-Sig-Value</b> into a base64url encoded JOSE signature, as
+/// accepted by [`crate::verifier::verify`]
+#[wasm_bindgen(js_name = "ecdsaDerToJose")]
+pub fn der_to_jose(alg: Algorithm, signature: &[u8]) -> Result<String, Error> {
+    match der_to_raw(alg, signature) {
+        Ok(val) => Ok(base64_url::encode(&val)),
+        Err(error) => Err(error),
+    }
+}
+
+/// Sign content with an ECDSA algorithm and return the ASN.1 DER encoded signature
+#[cfg(not(feature = "wasm"))]
+pub fn sign_der(message: String, key: impl SignFromKey, alg: Algorithm) -> Result<Vec<u8>, Error> {
+    if let Err(error) = scalar_length(alg) {
+        return Err(error);
+    }
+
+    match crate::signer::sign(message, key, alg) {
+        Ok(val) => jose_to_der(alg, val.as_str()),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen(js_name = "signEcdsaDer")]
+pub fn sign_der(message: String, key: Object, alg: Algorithm) -> Result<Vec<u8>, Error> {
+    if let Err(error) = scalar_length(alg) {
+        return Err(error);
+    }
+
+    match crate::signer::sign(message, key, alg) {
+        Ok(val) => jose_to_der(alg, val.as_str()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Verify an ASN.1 DER encoded ECDSA signature of the content
+#[cfg(not(feature = "wasm"))]
+pub fn verify_der(
+    message: String,
+    signature: &[u8],
+    key: impl VerifyFromKey,
+    alg: Algorithm,
+) -> Result<bool, Error> {
+    match der_to_jose(alg, signature) {
+        Ok(val) => crate::verifier::verify(message, val, key, alg),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen(js_name = "verifyEcdsaDer")]
+pub fn verify_der(
+    message: String,
+    signature: &[u8],
+    key: Object,
+    alg: Algorithm,
+) -> Result<bool, Error> {
+    match der_to_jose(alg, signature) {
+        Ok(val) => crate::verifier::verify(message, val, key, alg),
+        Err(error) => Err(error),
+    }
+}
@@ -0,0 +1,481 @@
+use std::collections::HashMap;
+
+use super::_256k::{
+    decode_address, get_address, recover_from_prehash, sign_prehash_recoverable, P256kSigningKey,
+    P256kVerifyingKey, RECOVERABLE_SIGNATURE_LENGTH,
+};
+use fi_common::error::Error;
+use rsa::BigUint;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use sha3::{Digest, Keccak256};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+const PERSONAL_MESSAGE_PREFIX: &str = "\x19Ethereum Signed Message:\n";
+const DOMAIN_TYPE: &str = "EIP712Domain";
+
+/// Field of an EIP-712 struct type
+#[derive(Deserialize, Clone)]
+pub struct TypedDataField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: String,
+}
+
+/// EIP-712 typed data in the JSON format of <b>eth_signTypedData_v4</b>
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedData {
+    pub types: HashMap<String, Vec<TypedDataField>>,
+    pub primary_type: String,
+    pub domain: Map<String, Value>,
+    pub message: Map<String, Value>,
+}
+
+impl TypedData {
+    /// Parse typed data from its JSON description
+    pub fn from_json(json: &str) -> Result<TypedData, Error> {
+        let mut typed_data: TypedData = match serde_json::from_str(json) {
+            Ok(val) => val,
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                return Err(Error::new(crate::errors::JSON_DESERIALIZATION_FAILED));
+            }
+        };
+
+        if !typed_data.types.contains_key(DOMAIN_TYPE) {
+            let domain_type = typed_data.default_domain_type();
+            typed_data
+                .types
+                .insert(String::from(DOMAIN_TYPE), domain_type);
+        }
+
+        Ok(typed_data)
+    }
+
+    /// <b>EIP712Domain</b> fields of the domain in the order of the EIP-712 specification
+    fn default_domain_type(&self) -> Vec<TypedDataField> {
+        [
+            ("name", "string"),
+            ("version", "string"),
+            ("chainId", "uint256"),
+            ("verifyingContract", "address"),
+            ("salt", "bytes32"),
+        ]
+        .iter()
+        .filter(|(name, _)| self.domain.contains_key(*name))
+        .map(|(name, field_type)| TypedDataField {
+            name: String::from(*name),
+            field_type: String::from(*field_type),
+        })
+        .collect()
+    }
+
+    /// Domain separator, the struct hash of the domain
+    pub fn domain_separator(&self) -> Result<[u8; 32], Error> {
+        self.hash_struct(DOMAIN_TYPE, &self.domain)
+    }
+
+    /// EIP-712 hash to sign, <b>keccak256(0x19 0x01 || domainSeparator || hashStruct(message))</b>
+    pub fn signing_hash(&self) -> Result<[u8; 32], Error> {
+        let domain_separator = match self.domain_separator() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let mut hasher = Keccak256::new()
+            .chain_update([0x19, 0x01])
+            .chain_update(domain_separator);
+
+        if self.primary_type != DOMAIN_TYPE {
+            match self.hash_struct(self.primary_type.as_str(), &self.message) {
+                Ok(val) => hasher.update(val),
+                Err(error) => return Err(error),
+            };
+        }
+
+        Ok(hasher.finalize().into())
+    }
+
+    /// Encoded type of a struct, <b>Name(type name,...)</b> followed by its referenced struct types
+    /// sorted by name
+    pub fn encode_type(&self, type_name: &str) -> Result<String, Error> {
+        let mut dependencies = Vec::new();
+        if let Err(error) = self.find_dependencies(type_name, &mut dependencies) {
+            return Err(error);
+        }
+
+        dependencies.retain(|dependency| dependency != type_name);
+        dependencies.sort();
+        dependencies.insert(0, String::from(type_name));
+
+        let mut encoded = String::new();
+        for dependency in dependencies {
+            let fields = match self.types.get(&dependency) {
+                Some(val) => val,
+                None => return Err(Error::new(crate::errors::TYPED_DATA_ERROR)),
+            };
+
+            let fields = fields
+                .iter()
+                .map(|field| format!("{} {}", field.field_type, field.name))
+                .collect::<Vec<String>>()
+                .join(",");
+            encoded.push_str(format!("{}({})", dependency, fields).as_str());
+        }
+
+        Ok(encoded)
+    }
+
+    fn find_dependencies(&self, type_name: &str, found: &mut Vec<String>) -> Result<(), Error> {
+        let base_type = strip_array(type_name);
+        if found.iter().any(|val| val == base_type) {
+            return Ok(());
+        }
+
+        let fields = match self.types.get(base_type) {
+            Some(val) => val,
+            None => return Ok(()),
+        };
+
+        found.push(String::from(base_type));
+        for field in fields {
+            if let Err(error) = self.find_dependencies(field.field_type.as_str(), found) {
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Keccak-256 hash of the encoded type
+    pub fn type_hash(&self, type_name: &str) -> Result<[u8; 32], Error> {
+        match self.encode_type(type_name) {
+            Ok(val) => Ok(Keccak256::digest(val.as_bytes()).into()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// <b>keccak256(typeHash || encodeData(data))</b>
+    pub fn hash_struct(
+        &self,
+        type_name: &str,
+        data: &Map<String, Value>,
+    ) -> Result<[u8; 32], Error> {
+        let fields = match self.types.get(type_name) {
+            Some(val) => val,
+            None => {
+                fi_common::logger::error(format!("Unknown EIP-712 type {}", type_name).as_str());
+                return Err(Error::new(crate::errors::TYPED_DATA_ERROR));
+            }
+        };
+
+        let mut hasher = match self.type_hash(type_name) {
+            Ok(val) => Keccak256::new().chain_update(val),
+            Err(error) => return Err(error),
+        };
+
+        for field in fields {
+            let value = data.get(&field.name).unwrap_or(&Value::Null);
+            match self.encode_value(field.field_type.as_str(), value) {
+                Ok(val) => hasher.update(val),
+                Err(error) => return Err(error),
+            };
+        }
+
+        Ok(hasher.finalize().into())
+    }
+
+    fn encode_value(&self, field_type: &str, value: &Value) -> Result<[u8; 32], Error> {
+        if field_type.ends_with(']') {
+            let items = match value.as_array() {
+                Some(val) => val,
+                None => return Err(Error::new(crate::errors::TYPED_DATA_ERROR)),
+            };
+
+            let mut hasher = Keccak256::new();
+            for item in items {
+                match self.encode_value(strip_array(field_type), item) {
+                    Ok(val) => hasher.update(val),
+                    Err(error) => return Err(error),
+                };
+            }
+
+            return Ok(hasher.finalize().into());
+        }
+
+        if self.types.contains_key(field_type) {
+            return match value.as_object() {
+                Some(val) => self.hash_struct(field_type, val),
+                None => Err(Error::new(crate::errors::TYPED_DATA_ERROR)),
+            };
+        }
+
+        encode_atomic_value(field_type, value)
+    }
+}
+
+/// Type of the array items, <b>Type</b> for <b>Type[]</b> and <b>Type[n]</b>
+fn strip_array(type_name: &str) -> &str {
+    match type_name.rfind('[') {
+        Some(index) if type_name.ends_with(']') => &type_name[..index],
+        _ => type_name,
+    }
+}
+
+fn encode_atomic_value(field_type: &str, value: &Value) -> Result<[u8; 32], Error> {
+    let mut encoded = [0u8; 32];
+
+    match field_type {
+        "string" => match value.as_str() {
+            Some(val) => Ok(Keccak256::digest(val.as_bytes()).into()),
+            None => Err(Error::new(crate::errors::TYPED_DATA_ERROR)),
+        },
+        "bytes" => match decode_hex_value(value) {
+            Ok(val) => Ok(Keccak256::digest(val).into()),
+            Err(error) => Err(error),
+        },
+        "bool" => match value.as_bool() {
+            Some(val) => {
+                encoded[31] = val as u8;
+                Ok(encoded)
+            }
+            None => Err(Error::new(crate::errors::TYPED_DATA_ERROR)),
+        },
+        "address" => match value.as_str() {
+            Some(val) => match decode_address(val) {
+                Ok(address) => {
+                    encoded[12..].copy_from_slice(&address);
+                    Ok(encoded)
+                }
+                Err(error) => Err(error),
+            },
+            None => Err(Error::new(crate::errors::TYPED_DATA_ERROR)),
+        },
+        _ if field_type.starts_with("bytes") => match decode_hex_value(value) {
+            Ok(val) if val.len() <= 32 => {
+                encoded[..val.len()].copy_from_slice(&val);
+                Ok(encoded)
+            }
+            Ok(_) => Err(Error::new(crate::errors::TYPED_DATA_ERROR)),
+            Err(error) => Err(error),
+        },
+        _ if field_type.starts_with("uint") => encode_integer(value, false),
+        _ if field_type.starts_with("int") => encode_integer(value, true),
+        _ => {
+            fi_common::logger::error(format!("Unknown EIP-712 type {}", field_type).as_str());
+            Err(Error::new(crate::errors::TYPED_DATA_ERROR))
+        }
+    }
+}
+
+fn decode_hex_value(value: &Value) -> Result<Vec<u8>, Error> {
+    let hex_str = match value.as_str() {
+        Some(val) => val.strip_prefix("0x").unwrap_or(val),
+        None => return Err(Error::new(crate::errors::TYPED_DATA_ERROR)),
+    };
+
+    match hex::decode(hex_str) {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::TYPED_DATA_ERROR))
+        }
+    }
+}
+
+/// 256 bits big endian two's complement of a JSON number, decimal string or <b>0x</b> hex string
+fn encode_integer(value: &Value, signed: bool) -> Result<[u8; 32], Error> {
+    let number = match value {
+        Value::Number(val) => val.to_string(),
+        Value::String(val) => val.clone(),
+        _ => return Err(Error::new(crate::errors::TYPED_DATA_ERROR)),
+    };
+
+    let (negative, digits) = match number.strip_prefix('-') {
+        Some(val) if signed => (true, val),
+        Some(_) => return Err(Error::new(crate::errors::TYPED_DATA_ERROR)),
+        None => (false, number.as_str()),
+    };
+
+    let magnitude = match digits.strip_prefix("0x") {
+        Some(val) => BigUint::parse_bytes(val.as_bytes(), 16),
+        None => BigUint::parse_bytes(digits.as_bytes(), 10),
+    };
+    let magnitude = match magnitude {
+        Some(val) => val,
+        None => return Err(Error::new(crate::errors::TYPED_DATA_ERROR)),
+    };
+
+    let modulus = BigUint::from(1u8) << 256;
+    if magnitude >= modulus {
+        return Err(Error::new(crate::errors::TYPED_DATA_ERROR));
+    }
+
+    let value = match negative {
+        true if magnitude > BigUint::from(0u8) => modulus - magnitude,
+        _ => magnitude,
+    };
+
+    let bytes = value.to_bytes_be();
+    let mut encoded = [0u8; 32];
+    encoded[32 - bytes.len()..].copy_from_slice(&bytes);
+    Ok(encoded)
+}
+
+/// EIP-191 <b>personal_sign</b> hash,
+/// <b>keccak256("\x19Ethereum Signed Message:\n" || len(message) || message)</b>
+pub fn hash_personal_message(message: &[u8]) -> [u8; 32] {
+    Keccak256::new()
+        .chain_update(PERSONAL_MESSAGE_PREFIX.as_bytes())
+        .chain_update(message.len().to_string().as_bytes())
+        .chain_update(message)
+        .finalize()
+        .into()
+}
+
+/// EIP-712 hash of typed data in the JSON format of <b>eth_signTypedData_v4</b>
+pub fn hash_typed_data(typed_data_json: &str) -> Result<[u8; 32], Error> {
+    match TypedData::from_json(typed_data_json) {
+        Ok(val) => val.signing_hash(),
+        Err(error) => Err(error),
+    }
+}
+
+fn sign_hash(key: &P256kSigningKey, hash: &[u8; 32]) -> Result<String, Error> {
+    match sign_prehash_recoverable(key, hash) {
+        Ok(mut val) => {
+            val[RECOVERABLE_SIGNATURE_LENGTH - 1] += 27;
+            Ok(format!("0x{}", hex::encode(val)))
+        }
+        Err(error) => Err(error),
+    }
+}
+
+fn recover_hash_signer(hash: &[u8; 32], signature: &str) -> Result<[u8; 20], Error> {
+    let signature_bytes = match hex::decode(signature.strip_prefix("0x").unwrap_or(signature)) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::DECODING_ERROR));
+        }
+    };
+
+    match recover_from_prehash(hash, &signature_bytes) {
+        Ok(val) => Ok(get_address(&val)),
+        Err(error) => Err(error),
+    }
+}
+
+fn verify_hash(hash: &[u8; 32], signature: &str, expected_address: &str) -> Result<bool, Error> {
+    let expected = match decode_address(expected_address) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    match recover_hash_signer(hash, signature) {
+        Ok(val) => Ok(val == expected),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Ok(false)
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl P256kSigningKey {
+    /// EIP-191 <b>personal_sign</b>, returns the <b>0x</b> prefixed hex of the 65 bytes
+    /// <b>r||s||v</b> signature with <b>v</b> 27 or 28
+    #[wasm_bindgen(js_name = "signPersonalMessage")]
+    pub fn sign_personal_message(&self, message: &[u8]) -> Result<String, Error> {
+        sign_hash(self, &hash_personal_message(message))
+    }
+
+    /// EIP-712 <b>eth_signTypedData_v4</b>, returns the <b>0x</b> prefixed hex of the 65 bytes
+    /// <b>r||s||v</b> signature with <b>v</b> 27 or 28
+    #[wasm_bindgen(js_name = "signTypedData")]
+    pub fn sign_typed_data(&self, typed_data_json: &str) -> Result<String, Error> {
+        match hash_typed_data(typed_data_json) {
+            Ok(val) => sign_hash(self, &val),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl P256kVerifyingKey {
+    /// EIP-55 mixed-case checksum encoding of the Ethereum address of the key
+    #[wasm_bindgen(js_name = "checksumAddress")]
+    pub fn checksum_address(&self) -> Result<String, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(to_checksum_address(&get_address(&val))),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// EIP-55 mixed-case checksum encoding of an address
+pub fn to_checksum_address(address: &[u8; 20]) -> String {
+    let address_hex = hex::encode(address);
+    let hash = Keccak256::digest(address_hex.as_bytes());
+
+    let checksummed: String = address_hex
+        .chars()
+        .enumerate()
+        .map(|(index, character)| {
+            let nibble = (hash[index / 2] >> (4 * (1 - index % 2))) & 0x0f;
+            match nibble >= 8 {
+                true => character.to_ascii_uppercase(),
+                false => character,
+            }
+        })
+        .collect();
+
+    format!("0x{}", checksummed)
+}
+
+/// Verify an EIP-191 <b>personal_sign</b> signature against the expected Ethereum address
+#[wasm_bindgen(js_name = "verifyPersonalMessage")]
+pub fn verify_personal_message(
+    message: &[u8],
+    signature: &str,
+    expected_address: &str,
+) -> Result<bool, Error> {
+    verify_hash(&hash_personal_message(message), signature, expected_address)
+}
+
+/// Verify an EIP-712 typed data signature against the expected Ethereum address
+#[wasm_bindgen(js_name = "verifyTypedData")]
+pub fn verify_typed_data(
+    typed_data_json: &str,
+    signature: &str,
+    expected_address: &str,
+) -> Result<bool, Error> {
+    match hash_typed_data(typed_data_json) {
+        Ok(val) => verify_hash(&val, signature, expected_address),
+        Err(error) => Err(error),
+    }
+}
+
+/// Recover the Ethereum address that created an EIP-191 <b>personal_sign</b> signature
+#[wasm_bindgen(js_name = "recoverPersonalMessageSigner")]
+pub fn recover_personal_message_signer(message: &[u8], signature: &str) -> Result<String, Error> {
+    match recover_hash_signer(&hash_personal_message(message), signature) {
+        Ok(val) => Ok(to_checksum_address(&val)),
+        Err(error) => Err(error),
+    }
+}
+
+/// Recover the Ethereum address that created an EIP-712 typed data signature
+#[wasm_bindgen(js_name = "recoverTypedDataSigner")]
+pub fn recover_typed_data_signer(typed_data_json: &str, signature: &str) -> Result<String, Error> {
+    let hash = match hash_typed_data(typed_data_json) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    match recover_hash_signer(&hash, signature) {
+        Ok(val) => Ok(to_checksum_address(&val)),
+        Err(error) => Err(error),
+    }
+}
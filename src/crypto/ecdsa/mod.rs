@@ -1,22 +1,29 @@
 #[cfg(feature = "wasm")]
 use self::{
+    brainpool::{BrainpoolSigningKey, BrainpoolVerifyingKey},
     _256k::P256kSigningKey,
     _256::{P256SigningKey, P256VerifyingKey},
     _384::{P384SigningKey, P384VerifyingKey},
     _512::{P512SigningKey, P512VerifyingKey},
 };
 use self::{
+    brainpool::{bp_sign, bp_verify},
     _256k::{ec_256k_sign, ec_256k_verify},
     _256::{ec_256_sign, ec_256_verify},
     _384::{ec_384_sign, ec_384_verify},
     _512::{ec_512_sign, ec_512_verify},
 };
 use crate::algorithms::Algorithm;
+use crate::crypto::check_digest_length;
+use crate::crypto::policy::{get_verification_mode, VerificationMode};
+use crate::stream::StreamHasher;
 #[cfg(not(feature = "wasm"))]
 use crate::crypto::{SignFromKey, VerifyFromKey};
 use fi_common::error::Error;
 #[cfg(feature = "wasm")]
 use js_sys::Object;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::JsValue;
 
 /// EC signing & verifying with NistP256 curve
 pub mod _256;
@@ -26,6 +33,76 @@ pub mod _256k;
 pub mod _384;
 /// EC signing & verifying with NistP521 curve
 pub mod _512;
+/// EC signing & verifying with the Brainpool curves of RFC 5639
+pub mod brainpool;
+/// Conversion between raw JOSE and ASN.1 DER ECDSA signatures
+pub mod der_signature;
+/// Ethereum EIP-191 and EIP-712 signing with Secp256k1 keys
+pub mod ethereum;
+/// BIP-340 Schnorr signing & verifying with Secp256k1 keys
+pub mod schnorr;
+
+/// Whether the verification mode rejects the ECDSA signature because of its high S value
+pub(crate) fn rejects_high_s(is_high: bool) -> bool {
+    if is_high && get_verification_mode() != VerificationMode::Lenient {
+        fi_common::logger::error("High-S ECDSA signature rejected by the verification mode");
+        return true;
+    }
+
+    false
+}
+
+/// ECDSA signing keys normalize signatures to low S when the verification mode rejects high S
+pub(crate) fn signs_low_s() -> bool {
+    get_verification_mode() != VerificationMode::Lenient
+}
+
+fn check_key_algorithm(key_alg: Algorithm, alg: Algorithm) -> Result<(), Error> {
+    if key_alg != alg {
+        fi_common::logger::error(
+            format!("{} key can't be used with {}", key_alg.to_str(), alg.to_str()).as_str(),
+        );
+        return Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH));
+    }
+
+    Ok(())
+}
+
+/// Check that a digest signed or verified with the key of `key_alg` was created for it
+pub(crate) fn check_prehash(
+    key_alg: Algorithm,
+    digest: &[u8],
+    alg: Algorithm,
+) -> Result<(), Error> {
+    if let Err(error) = check_key_algorithm(key_alg, alg) {
+        return Err(error);
+    }
+
+    check_digest_length(digest, alg)
+}
+
+/// Hasher of content streamed to the key of `key_alg`
+pub(crate) fn stream_hasher(key_alg: Algorithm, alg: Algorithm) -> Result<StreamHasher, Error> {
+    if let Err(error) = check_key_algorithm(key_alg, alg) {
+        return Err(error);
+    }
+
+    StreamHasher::digest(alg)
+}
+
+/// Optional <b>hedged</b> flag of a signing key JS object
+#[cfg(feature = "wasm")]
+pub(crate) fn read_hedged(value: &Object) -> Result<bool, Error> {
+    let hedged_field = JsValue::from_str("hedged");
+    if !value.has_own_property(&hedged_field) {
+        return Ok(false);
+    }
+
+    match js_sys::Reflect::get(value, &hedged_field).map(|val| val.as_bool()) {
+        Ok(Some(val)) => Ok(val),
+        _ => Err(Error::new(crate::errors::MISSING_FIELD)),
+    }
+}
 
 /// Sign content with EC based algorithms
 #[cfg(not(feature = "wasm"))]
@@ -35,6 +112,9 @@ pub fn sign_ec(message: String, key: impl SignFromKey, alg: Algorithm) -> Result
         Algorithm::ES384 => ec_384_sign(message, key),
         Algorithm::ES512 => ec_512_sign(message, key),
         Algorithm::ES256K => ec_256k_sign(message, key),
+        Algorithm::BP256R1 | Algorithm::BP384R1 | Algorithm::BP512R1 => {
+            bp_sign(message, key, alg)
+        }
         _ => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
     }
 }
@@ -70,6 +150,14 @@ pub fn sign_ec(message: String, key: Object, alg: Algorithm) -> Result<String, E
                 Err(error) => return Err(error),
             },
         ),
+        Algorithm::BP256R1 | Algorithm::BP384R1 | Algorithm::BP512R1 => bp_sign(
+            message,
+            match BrainpoolSigningKey::from_js_object(key, alg) {
+                Ok(val) => val,
+                Err(error) => return Err(error),
+            },
+            alg,
+        ),
         _ => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
     }
 }
@@ -87,6 +175,9 @@ pub fn verify_ec(
         Algorithm::ES384 => ec_384_verify(message, signature, key),
         Algorithm::ES512 => ec_512_verify(message, signature, key),
         Algorithm::ES256K => ec_256k_verify(message, signature, key),
+        Algorithm::BP256R1 | Algorithm::BP384R1 | Algorithm::BP512R1 => {
+            bp_verify(message, signature, key, alg)
+        }
         _ => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
     }
 }
@@ -131,6 +222,15 @@ pub fn verify_ec(
                 Err(error) => return Err(error),
             },
         ),
+        Algorithm::BP256R1 | Algorithm::BP384R1 | Algorithm::BP512R1 => bp_verify(
+            message,
+            signature,
+            match BrainpoolVerifyingKey::from_js_object(key, alg) {
+                Ok(val) => val,
+                Err(error) => return Err(error),
+            },
+            alg,
+        ),
         _ => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
     }
 }
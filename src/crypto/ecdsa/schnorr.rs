@@ -0,0 +1,403 @@
+use super::_256k::{get_private_key_from_bytes, get_private_key_from_pem, get_public_key_from_pem};
+use crate::crypto::{
+    decode_base64_signature,
+    policy::{get_key_policy, Curve},
+};
+use fi_common::error::Error;
+#[cfg(feature = "wasm")]
+use js_sys::{Object, Uint8Array};
+use k256::schnorr::{
+    signature::{hazmat::PrehashVerifier, RandomizedSigner, Verifier},
+    Signature, SigningKey, VerifyingKey,
+};
+use rand::rngs::OsRng;
+use wasm_bindgen::prelude::wasm_bindgen;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::JsValue;
+#[cfg(feature = "wasm")]
+use zeroize::Zeroizing;
+
+/// Length of the BIP-340 x-only public key
+pub const SCHNORR_PUBLIC_KEY_LENGTH: usize = 32;
+
+/// Length of the BIP-340 signature
+pub const SCHNORR_SIGNATURE_LENGTH: usize = 64;
+
+/// BIP-340 Schnorr signing key on the Secp256k1 curve
+#[wasm_bindgen]
+pub struct P256kSchnorrSigningKey {
+    #[cfg(not(feature = "wasm"))]
+    key: SigningKey,
+
+    #[cfg(feature = "wasm")]
+    key_str: Option<Zeroizing<String>>,
+    #[cfg(feature = "wasm")]
+    key_bytes: Option<Zeroizing<Vec<u8>>>,
+}
+
+fn get_schnorr_private_key_from_pem(key_str: &str) -> Result<SigningKey, Error> {
+    match get_private_key_from_pem(key_str) {
+        Ok(val) => Ok(SigningKey::from(*val.as_nonzero_scalar())),
+        Err(error) => Err(error),
+    }
+}
+
+fn get_schnorr_private_key_from_bytes(bytes: &[u8]) -> Result<SigningKey, Error> {
+    match get_private_key_from_bytes(bytes) {
+        Ok(val) => Ok(SigningKey::from(*val.as_nonzero_scalar())),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+impl P256kSchnorrSigningKey {
+    /// Create Signing key from pem formatted secp256k1 private key. <b>pkcs8</b> and <b>sec1</b>.
+    pub fn from_pem(key_str: &str) -> Result<P256kSchnorrSigningKey, Error> {
+        match get_schnorr_private_key_from_pem(key_str) {
+            Ok(val) => Ok(P256kSchnorrSigningKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create Signing key from the 32 bytes secret key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<P256kSchnorrSigningKey, Error> {
+        match get_schnorr_private_key_from_bytes(bytes) {
+            Ok(val) => Ok(P256kSchnorrSigningKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl P256kSchnorrSigningKey {
+    /// Create Signing key from pem formatted secp256k1 private key. <b>pkcs8</b> and <b>sec1</b>.
+    #[wasm_bindgen(js_name = "fromPem")]
+    pub fn from_pem(key_str: &str) -> P256kSchnorrSigningKey {
+        P256kSchnorrSigningKey {
+            key_str: Some(Zeroizing::new(String::from(key_str))),
+            key_bytes: None,
+        }
+    }
+
+    /// Create Signing key from the 32 bytes secret key.
+    #[wasm_bindgen(js_name = "fromBytes")]
+    pub fn from_bytes(bytes: &[u8]) -> P256kSchnorrSigningKey {
+        P256kSchnorrSigningKey {
+            key_str: None,
+            key_bytes: Some(Zeroizing::new(bytes.to_vec())),
+        }
+    }
+
+    fn get_key(&self) -> Result<SigningKey, Error> {
+        match (&self.key_str, &self.key_bytes) {
+            (Some(key_str), _) => get_schnorr_private_key_from_pem(key_str.as_str()),
+            (None, Some(key_bytes)) => get_schnorr_private_key_from_bytes(key_bytes.as_slice()),
+            (None, None) => Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+        }
+    }
+
+    pub fn from_js_object(value: Object) -> Result<P256kSchnorrSigningKey, Error> {
+        let pem_field = JsValue::from_str("pem");
+
+        if value.has_own_property(&pem_field) {
+            let pem = match js_sys::Reflect::get(&value, &pem_field) {
+                Ok(val) => match val.as_string() {
+                    Some(v) => Zeroizing::new(v),
+                    None => return Err(Error::new(crate::errors::MISSING_FIELD)),
+                },
+                Err(error) => {
+                    fi_common::logger::error(error.as_string().unwrap().as_str());
+                    return Err(Error::new(crate::errors::MISSING_FIELD));
+                }
+            };
+
+            Ok(P256kSchnorrSigningKey::from_pem(pem.as_str()))
+        } else if value.is_array() {
+            let arr = Zeroizing::new(Uint8Array::new(&value).to_vec());
+
+            Ok(P256kSchnorrSigningKey::from_bytes(arr.as_slice()))
+        } else {
+            Err(Error::new(crate::errors::MISSING_FIELD))
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl P256kSchnorrSigningKey {
+    /// Sign the SHA-256 hash of the content with fresh auxiliary randomness, returns the base64 url
+    /// encoded 64 bytes signature
+    pub fn sign(&self, content: String) -> Result<String, Error> {
+        match self.sign_bytes(content.as_bytes()) {
+            Ok(val) => Ok(base64_url::encode(&val)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Sign the SHA-256 hash of the content with fresh auxiliary randomness, returns the 64 bytes
+    /// signature
+    #[wasm_bindgen(js_name = "signBytes")]
+    pub fn sign_bytes(&self, content: &[u8]) -> Result<Vec<u8>, Error> {
+        #[cfg(not(feature = "wasm"))]
+        let key = &self.key;
+
+        #[cfg(feature = "wasm")]
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match key.try_sign_with_rng(&mut OsRng, content) {
+            Ok(val) => Ok(val.to_bytes().to_vec()),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::SIGNING_FAILED))
+            }
+        }
+    }
+
+    /// BIP-340 signing of a 32 bytes message with the 32 bytes auxiliary randomness
+    #[wasm_bindgen(js_name = "signWithAuxRand")]
+    pub fn sign_with_aux_rand(&self, message: &[u8], aux_rand: &[u8]) -> Result<Vec<u8>, Error> {
+        let (message, aux_rand): (&[u8; 32], &[u8; 32]) =
+            match (message.try_into(), aux_rand.try_into()) {
+                (Ok(message), Ok(aux_rand)) => (message, aux_rand),
+                _ => return Err(Error::new(crate::errors::SCHNORR_INPUT_LENGTH)),
+            };
+
+        #[cfg(not(feature = "wasm"))]
+        let key = &self.key;
+
+        #[cfg(feature = "wasm")]
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match key.sign_prehash_with_aux_rand(message, aux_rand) {
+            Ok(val) => Ok(val.to_bytes().to_vec()),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::SIGNING_FAILED))
+            }
+        }
+    }
+
+    /// Verifying key with the x-only public key of this key
+    #[wasm_bindgen(js_name = "verifyingKey")]
+    pub fn verifying_key(&self) -> Result<P256kSchnorrVerifyingKey, Error> {
+        #[cfg(not(feature = "wasm"))]
+        return Ok(P256kSchnorrVerifyingKey::from_key(
+            *self.key.verifying_key(),
+        ));
+
+        #[cfg(feature = "wasm")]
+        match self.get_key() {
+            Ok(val) => Ok(P256kSchnorrVerifyingKey::from_key(*val.verifying_key())),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// BIP-340 Schnorr verifying key, the x-only public key on the Secp256k1 curve
+#[wasm_bindgen]
+pub struct P256kSchnorrVerifyingKey {
+    #[cfg(not(feature = "wasm"))]
+    key: VerifyingKey,
+
+    #[cfg(feature = "wasm")]
+    key_str: Option<String>,
+    #[cfg(feature = "wasm")]
+    key_bytes: Option<Vec<u8>>,
+}
+
+fn get_schnorr_public_key_from_pem(key_str: &str) -> Result<VerifyingKey, Error> {
+    let key = match get_public_key_from_pem(key_str) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    get_schnorr_public_key_from_bytes(&key.to_encoded_point(true).as_bytes()[1..])
+}
+
+fn get_schnorr_public_key_from_bytes(bytes: &[u8]) -> Result<VerifyingKey, Error> {
+    if let Err(error) = get_key_policy().check_curve(Curve::Secp256k1) {
+        return Err(error);
+    }
+
+    if bytes.len() != SCHNORR_PUBLIC_KEY_LENGTH {
+        return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR));
+    }
+
+    match VerifyingKey::from_bytes(bytes) {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR))
+        }
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+impl P256kSchnorrVerifyingKey {
+    /// Create verifying key from pem formated secp256k1 public key. <b>pkcs8</b> only.
+    pub fn from_pem(key_str: &str) -> Result<P256kSchnorrVerifyingKey, Error> {
+        match get_schnorr_public_key_from_pem(key_str) {
+            Ok(val) => Ok(P256kSchnorrVerifyingKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create verifying key from the 32 bytes x-only public key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<P256kSchnorrVerifyingKey, Error> {
+        match get_schnorr_public_key_from_bytes(bytes) {
+            Ok(val) => Ok(P256kSchnorrVerifyingKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn from_key(key: VerifyingKey) -> P256kSchnorrVerifyingKey {
+        P256kSchnorrVerifyingKey { key }
+    }
+
+    fn get_key(&self) -> Result<VerifyingKey, Error> {
+        Ok(self.key)
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl P256kSchnorrVerifyingKey {
+    /// Create verifying key from pem formated secp256k1 public key. <b>pkcs8</b> only.
+    #[wasm_bindgen(js_name = "fromPem")]
+    pub fn from_pem(key_str: &str) -> P256kSchnorrVerifyingKey {
+        P256kSchnorrVerifyingKey {
+            key_str: Some(String::from(key_str)),
+            key_bytes: None,
+        }
+    }
+
+    /// Create verifying key from the 32 bytes x-only public key.
+    #[wasm_bindgen(js_name = "fromBytes")]
+    pub fn from_bytes(bytes: &[u8]) -> P256kSchnorrVerifyingKey {
+        P256kSchnorrVerifyingKey {
+            key_str: None,
+            key_bytes: Some(bytes.to_vec()),
+        }
+    }
+
+    fn from_key(key: VerifyingKey) -> P256kSchnorrVerifyingKey {
+        P256kSchnorrVerifyingKey::from_bytes(key.to_bytes().as_slice())
+    }
+
+    fn get_key(&self) -> Result<VerifyingKey, Error> {
+        match (&self.key_str, &self.key_bytes) {
+            (Some(key_str), _) => get_schnorr_public_key_from_pem(key_str.as_str()),
+            (None, Some(key_bytes)) => get_schnorr_public_key_from_bytes(key_bytes.as_slice()),
+            (None, None) => Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+        }
+    }
+
+    pub fn from_js_object(value: Object) -> Result<P256kSchnorrVerifyingKey, Error> {
+        let pem_field = JsValue::from_str("pem");
+
+        if value.has_own_property(&pem_field) {
+            let pem = match js_sys::Reflect::get(&value, &pem_field) {
+                Ok(val) => match val.as_string() {
+                    Some(v) => v,
+                    None => return Err(Error::new(crate::errors::MISSING_FIELD)),
+                },
+                Err(error) => {
+                    fi_common::logger::error(error.as_string().unwrap().as_str());
+                    return Err(Error::new(crate::errors::MISSING_FIELD));
+                }
+            };
+
+            Ok(P256kSchnorrVerifyingKey::from_pem(pem.as_str()))
+        } else if value.is_array() {
+            Ok(P256kSchnorrVerifyingKey::from_bytes(
+                Uint8Array::new(&value).to_vec().as_slice(),
+            ))
+        } else {
+            Err(Error::new(crate::errors::MISSING_FIELD))
+        }
+    }
+}
+
+fn decode_signature(signature: &[u8]) -> Result<Signature, Error> {
+    match Signature::try_from(signature) {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED))
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl P256kSchnorrVerifyingKey {
+    /// The 32 bytes x-only public key
+    #[wasm_bindgen(js_name = "toBytes")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.to_bytes().to_vec()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Verify a base64 url encoded signature of [`P256kSchnorrSigningKey::sign`]
+    pub fn verify(&self, content: String, signature: String) -> Result<bool, Error> {
+        match decode_base64_signature(signature.as_str()) {
+            Ok(val) => self.verify_bytes(content.as_bytes(), &val),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Verify a 64 bytes signature of [`P256kSchnorrSigningKey::sign_bytes`]
+    #[wasm_bindgen(js_name = "verifyBytes")]
+    pub fn verify_bytes(&self, content: &[u8], signature: &[u8]) -> Result<bool, Error> {
+        let sig = match decode_signature(signature) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match key.verify(content, &sig) {
+            Ok(_) => Ok(true),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Ok(false)
+            }
+        }
+    }
+
+    /// BIP-340 verification of a 64 bytes signature of a 32 bytes message
+    #[wasm_bindgen(js_name = "verifyRaw")]
+    pub fn verify_raw(&self, message: &[u8], signature: &[u8]) -> Result<bool, Error> {
+        if message.len() != 32 {
+            return Err(Error::new(crate::errors::SCHNORR_INPUT_LENGTH));
+        }
+
+        let sig = match decode_signature(signature) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match key.verify_prehash(message, &sig) {
+            Ok(_) => Ok(true),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Ok(false)
+            }
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use ed448_goldilocks_plus::{
+    Signature, SigningKey, VerifyingKey, ALGORITHM_OID, PUBLIC_KEY_LENGTH,
+};
+use elliptic_curve::pkcs8::{
+    der::{asn1::OctetStringRef, Decode},
+    DecodePublicKey, PrivateKeyInfo, SecretDocument,
+};
+#[cfg(feature = "wasm")]
+use js_sys::{Object, Uint8Array};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::JsValue;
+#[cfg(feature = "wasm")]
+use zeroize::Zeroizing;
+
+use crate::algorithms::Algorithm;
+use fi_common::error::Error;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use super::{
+    policy::{get_key_policy, Curve, KeyPolicy},
+    SignFromKey, VerifyFromKey,
+};
+
+/// Signing key for ED448 curve of [`crate::algorithms::Algorithm::EdDSA`] algorithm
+#[wasm_bindgen]
+pub struct Ed448SigningKey {
+    #[cfg(not(feature = "wasm"))]
+    key: SigningKey,
+
+    #[cfg(feature = "wasm")]
+    key_str: Option<Zeroizing<String>>,
+    #[cfg(feature = "wasm")]
+    key_bytes: Option<Zeroizing<Vec<u8>>>,
+}
+
+impl SignFromKey for Ed448SigningKey {
+    fn sign_bytes(&self, content: &[u8], _alg: Algorithm) -> Result<Vec<u8>, Error> {
+        #[cfg(not(feature = "wasm"))]
+        let key = &self.key;
+
+        #[cfg(feature = "wasm")]
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        Ok(key.sign_raw(content).to_bytes().to_vec())
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+impl Ed448SigningKey {
+    /// Create signing key from pem formatted private key. <b>pksc8</b> only.
+    pub fn from_pem(key_str: &str) -> Result<Ed448SigningKey, Error> {
+        let pkc8_key = match get_private_key_from_pem(key_str) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        Ok(Ed448SigningKey { key: pkc8_key })
+    }
+
+    /// Create signing key from the 57 bytes private key.
+    pub fn from_bytes(bytes: &mut [u8]) -> Result<Ed448SigningKey, Error> {
+        let ec_key = match get_private_key_from_bytes(bytes) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        Ok(Ed448SigningKey { key: ec_key })
+    }
+
+    pub(crate) fn get_key(&self) -> Result<&SigningKey, Error> {
+        Ok(&self.key)
+    }
+}
+
+fn get_private_key_from_pem(key_str: &str) -> Result<SigningKey, Error> {
+    if let Err(error) = get_key_policy().check_curve(Curve::Ed448) {
+        return Err(error);
+    }
+
+    let (_, document) = match SecretDocument::from_pem(key_str) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+        }
+    };
+
+    let key_info = match PrivateKeyInfo::try_from(document.as_bytes()) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+        }
+    };
+
+    if key_info.algorithm.oid != ALGORITHM_OID {
+        return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+    }
+
+    // RFC 8410 wraps the private key into another OCTET STRING (CurvePrivateKey)
+    match OctetStringRef::from_der(key_info.private_key) {
+        Ok(val) => get_private_key_from_bytes(val.as_bytes()),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+        }
+    }
+}
+
+fn get_private_key_from_bytes(bytes: &[u8]) -> Result<SigningKey, Error> {
+    if let Err(error) = get_key_policy().check_curve(Curve::Ed448) {
+        return Err(error);
+    }
+
+    match SigningKey::try_from(bytes) {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error);
+            return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl Ed448SigningKey {
+    /// Create signing key from pem formatted private key. <b>pksc8</b> only.
+    #[wasm_bindgen]
+    pub fn from_pem(key_str: &str) -> Ed448SigningKey {
+        Ed448SigningKey {
+            key_str: Some(Zeroizing::new(String::from(key_str))),
+            key_bytes: None,
+        }
+    }
+
+    /// Create signing key from the 57 bytes private key.
+    #[wasm_bindgen]
+    pub fn from_bytes(bytes: &mut [u8]) -> Ed448SigningKey {
+        Ed448SigningKey {
+            key_str: None,
+            key_bytes: Some(Zeroizing::new(bytes.to_vec())),
+        }
+    }
+
+    pub(crate) fn get_key(&self) -> Result<SigningKey, Error> {
+        match (&self.key_str, &self.key_bytes) {
+            (Some(key_str), _) => get_private_key_from_pem(key_str.as_str()),
+            (None, Some(key_bytes)) => get_private_key_from_bytes(key_bytes.as_slice()),
+            (None, None) => Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+        }
+    }
+
+    pub fn from_js_object(value: Object) -> Result<Ed448SigningKey, Error> {
+        let pem_field = JsValue::from_str("pem");
+
+        if value.has_own_property(&pem_field) {
+            let pem = match js_sys::Reflect::get(&value, &pem_field) {
+                Ok(val) => {
+                    let string_value = match val.as_string() {
+                        Some(v) => Zeroizing::new(v),
+                        None => return Err(Error::new(crate::errors::MISSING_FIELD)),
+                    };
+                    string_value
+                }
+                Err(error) => {
+                    fi_common::logger::error(error.as_string().unwrap().as_str());
+                    return Err(Error::new(crate::errors::MISSING_FIELD));
+                }
+            };
+
+            return Ok(Ed448SigningKey::from_pem(pem.as_str()));
+        } else if value.is_array() {
+            let mut arr = Zeroizing::new(Uint8Array::new(&value).to_vec());
+            let bytes = arr.as_mut_slice();
+
+            return Ok(Ed448SigningKey::from_bytes(bytes));
+        } else {
+            Err(Error::new(crate::errors::MISSING_FIELD))
+        }
+    }
+}
+
+/// Verifying key for ED448 curve of [`crate::algorithms::Algorithm::EdDSA`] algorithm
+#[wasm_bindgen]
+pub struct Ed448VerifyingKey {
+    #[cfg(not(feature = "wasm"))]
+    key: VerifyingKey,
+    #[cfg(feature = "wasm")]
+    key_bytes: Option<Vec<u8>>,
+    #[cfg(feature = "wasm")]
+    key_str: Option<String>,
+}
+
+impl VerifyFromKey for Ed448VerifyingKey {
+    fn check_key_policy(&self, policy: &KeyPolicy) -> Result<(), Error> {
+        policy.check_curve(Curve::Ed448)
+    }
+
+    fn verify_bytes(
+        &self,
+        content: &[u8],
+        signature: &[u8],
+        _alg: Algorithm,
+    ) -> Result<bool, Error> {
+        let sig = match Signature::try_from(signature) {
+            Ok(val) => val,
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                return Err(Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED));
+            }
+        };
+
+        #[cfg(not(feature = "wasm"))]
+        let key = &self.key;
+
+        #[cfg(feature = "wasm")]
+        let key = &match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match key.verify_raw(&sig, content) {
+            Ok(_) => Ok(true),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Ok(false)
+            }
+        }
+    }
+}
+
+fn get_public_key_from_pem(key_str: &str) -> Result<VerifyingKey, Error> {
+    if let Err(error) = get_key_policy().check_curve(Curve::Ed448) {
+        return Err(error);
+    }
+
+    match VerifyingKey::from_public_key_pem(key_str) {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR));
+        }
+    }
+}
+
+fn get_public_key_from_bytes(bytes: &[u8]) -> Result<VerifyingKey, Error> {
+    if let Err(error) = get_key_policy().check_curve(Curve::Ed448) {
+        return Err(error);
+    }
+
+    if bytes.len() != PUBLIC_KEY_LENGTH {
+        return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR));
+    }
+
+    let mut ec_bytes: [u8; PUBLIC_KEY_LENGTH] = [0; PUBLIC_KEY_LENGTH];
+    ec_bytes.copy_from_slice(bytes);
+    match VerifyingKey::from_bytes(&ec_bytes) {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR));
+        }
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+impl Ed448VerifyingKey {
+    /// Create verifying key from pem formatted public key. <b>spki</b> only.
+    pub fn from_pem(key_str: &str) -> Result<Ed448VerifyingKey, Error> {
+        let spki_key = match get_public_key_from_pem(key_str) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        Ok(Ed448VerifyingKey { key: spki_key })
+    }
+
+    /// Create verifying key from the 57 bytes public key.
+    pub fn from_bytes(bytes: &mut [u8]) -> Result<Ed448VerifyingKey, Error> {
+        let ec_key = match get_public_key_from_bytes(bytes) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+        Ok(Ed448VerifyingKey { key: ec_key })
+    }
+
+    pub(crate) fn get_key(&self) -> Result<VerifyingKey, Error> {
+        Ok(self.key)
+    }
+
+    pub(crate) fn from_key(key: VerifyingKey) -> Ed448VerifyingKey {
+        Ed448VerifyingKey { key }
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl Ed448VerifyingKey {
+    /// Create verifying key from pem formatted public key. <b>spki</b> only.
+    #[wasm_bindgen]
+    pub fn from_pem(key_str: &str) -> Ed448VerifyingKey {
+        Ed448VerifyingKey {
+            key_str: Some(String::from(key_str)),
+            key_bytes: None,
+        }
+    }
+
+    /// Create verifying key from the 57 bytes public key.
+    #[wasm_bindgen]
+    pub fn from_bytes(bytes: &mut [u8]) -> Ed448VerifyingKey {
+        Ed448VerifyingKey {
+            key_str: None,
+            key_bytes: Some(bytes.to_vec()),
+        }
+    }
+
+    pub(crate) fn from_key(key: VerifyingKey) -> Ed448VerifyingKey {
+        Ed448VerifyingKey::from_bytes(&mut key.to_bytes())
+    }
+
+    pub(crate) fn get_key(&self) -> Result<VerifyingKey, Error> {
+        match (&self.key_str, &self.key_bytes) {
+            (Some(key_str), _) => get_public_key_from_pem(key_str.as_str()),
+            (None, Some(key_bytes)) => get_public_key_from_bytes(key_bytes.as_slice()),
+            (None, None) => Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+        }
+    }
+
+    pub fn from_js_object(value: Object) -> Result<Ed448VerifyingKey, Error> {
+        let pem_field = JsValue::from_str("pem");
+
+        if value.has_own_property(&pem_field) {
+            let pem = match js_sys::Reflect::get(&value, &pem_field) {
+                Ok(val) => {
+                    let string_value = match val.as_string() {
+                        Some(v) => v,
+                        None => return Err(Error::new(crate::errors::MISSING_FIELD)),
+                    };
+                    string_value
+                }
+                Err(error) => {
+                    fi_common::logger::error(error.as_string().unwrap().as_str());
+                    return Err(Error::new(crate::errors::MISSING_FIELD));
+                }
+            };
+
+            return Ok(Ed448VerifyingKey::from_pem(pem.as_str()));
+        } else if value.is_array() {
+            let mut arr = Uint8Array::new(&value).to_vec();
+            let bytes = arr.as_mut_slice();
+
+            return Ok(Ed448VerifyingKey::from_bytes(bytes));
+        } else {
+            Err(Error::new(crate::errors::MISSING_FIELD))
+        }
+    }
+}
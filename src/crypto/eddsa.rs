@@ -1,18 +1,276 @@
+use curve25519_dalek::{
+    constants::ED25519_BASEPOINT_POINT,
+    edwards::CompressedEdwardsY,
+    traits::{IsIdentity, VartimeMultiscalarMul},
+    EdwardsPoint, Scalar,
+};
+use ed25519_dalek::{hazmat::ExpandedSecretKey, Signature, VerifyingKey};
 use ed25519_dalek::{
     pkcs8::DecodePrivateKey, pkcs8::DecodePublicKey, Signer, SigningKey, Verifier,
 };
-use ed25519_dalek::{Signature, VerifyingKey};
+#[cfg(feature = "wasm")]
+use elliptic_curve::pkcs8::{der::pem, spki::SubjectPublicKeyInfoRef, PrivateKeyInfo};
 #[cfg(feature = "wasm")]
 use js_sys::{Object, Uint8Array};
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
+use std::str::FromStr;
 #[cfg(feature = "wasm")]
 use wasm_bindgen::JsValue;
+#[cfg(feature = "wasm")]
+use zeroize::Zeroizing;
 
 use crate::algorithms::Algorithm;
 use fi_common::error::Error;
 
 use wasm_bindgen::prelude::wasm_bindgen;
 
-use super::{SignFromKey, VerifyFromKey};
+#[cfg(feature = "wasm")]
+use super::ed448::{Ed448SigningKey, Ed448VerifyingKey};
+use super::{
+    decode_base64_signature,
+    policy::{get_key_policy, get_verification_mode, Curve, KeyPolicy, VerificationMode},
+    SignFromKey, SignStream, VerifyFromKey, VerifyStream,
+};
+use crate::stream::StreamHasher;
+
+/// Ed25519 variants of RFC 8032 section 5.1
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Serialize, Deserialize, Copy, Clone, Debug)]
+pub enum Ed25519Variant {
+    /// Ed25519 over the whole message
+    #[serde(rename = "Ed25519")]
+    Pure,
+    /// Ed25519ctx, Ed25519 with a non-empty context string
+    #[serde(rename = "Ed25519ctx")]
+    Ctx,
+    /// Ed25519ph, Ed25519 over the SHA-512 hash of the message with an optional context string
+    #[serde(rename = "Ed25519ph")]
+    Ph,
+}
+
+impl Ed25519Variant {
+    pub fn to_str<'a>(&self) -> &'a str {
+        match self {
+            Ed25519Variant::Pure => "Ed25519",
+            Ed25519Variant::Ctx => "Ed25519ctx",
+            Ed25519Variant::Ph => "Ed25519ph",
+        }
+    }
+}
+
+impl FromStr for Ed25519Variant {
+    type Err = Error;
+
+    fn from_str(variant: &str) -> Result<Self, Error> {
+        match variant {
+            "Ed25519" => Ok(Ed25519Variant::Pure),
+            "Ed25519ctx" => Ok(Ed25519Variant::Ctx),
+            "Ed25519ph" => Ok(Ed25519Variant::Ph),
+            _ => Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+        }
+    }
+}
+
+/// Maximum length of the Ed25519ctx and Ed25519ph context string
+pub const MAX_CONTEXT_LENGTH: usize = 255;
+
+const DOM2_PREFIX: &[u8] = b"SigEd25519 no Ed25519 collisions";
+
+fn check_context_length(context: &[u8]) -> Result<(), Error> {
+    if context.len() > MAX_CONTEXT_LENGTH {
+        return Err(Error::new(crate::errors::EDDSA_CONTEXT_TOO_LONG));
+    }
+
+    Ok(())
+}
+
+/// SHA-512 with the dom2(phflag, context) prefix of Ed25519ctx (phflag 0) and Ed25519ph (phflag 1)
+fn dom2_hasher(phflag: u8, context: &[u8]) -> Sha512 {
+    Sha512::new()
+        .chain_update(DOM2_PREFIX)
+        .chain_update([phflag, context.len() as u8])
+        .chain_update(context)
+}
+
+fn sign_ctx(key: &SigningKey, context: &[u8], message: &[u8]) -> Signature {
+    let expanded_key = ExpandedSecretKey::from(key.as_bytes());
+    let public_key = key.verifying_key();
+
+    let r = Scalar::from_hash(
+        dom2_hasher(0, context)
+            .chain_update(expanded_key.hash_prefix)
+            .chain_update(message),
+    );
+    let big_r = EdwardsPoint::mul_base(&r).compress();
+
+    let k = Scalar::from_hash(
+        dom2_hasher(0, context)
+            .chain_update(big_r.as_bytes())
+            .chain_update(public_key.as_bytes())
+            .chain_update(message),
+    );
+    let s = k * expanded_key.scalar + r;
+
+    Signature::from_components(big_r.to_bytes(), s.to_bytes())
+}
+
+fn verify_ctx(
+    key: &VerifyingKey,
+    context: &[u8],
+    message: &[u8],
+    signature: &Signature,
+) -> Result<(), ed25519_dalek::ed25519::Error> {
+    let public_point = match CompressedEdwardsY(key.to_bytes()).decompress() {
+        Some(val) => val,
+        None => return Err(ed25519_dalek::ed25519::Error::new()),
+    };
+
+    let s = match Option::<Scalar>::from(Scalar::from_canonical_bytes(*signature.s_bytes())) {
+        Some(val) => val,
+        None => return Err(ed25519_dalek::ed25519::Error::new()),
+    };
+
+    let k = Scalar::from_hash(
+        dom2_hasher(0, context)
+            .chain_update(signature.r_bytes())
+            .chain_update(key.as_bytes())
+            .chain_update(message),
+    );
+    let big_r = EdwardsPoint::vartime_double_scalar_mul_basepoint(&k, &(-public_point), &s);
+
+    if big_r.compress().as_bytes() == signature.r_bytes() {
+        Ok(())
+    } else {
+        Err(ed25519_dalek::ed25519::Error::new())
+    }
+}
+
+/// Reject small order and non-canonically encoded points as the public key or the R of the signature
+fn check_strict_points(
+    key: &VerifyingKey,
+    signature: &Signature,
+) -> Result<(), ed25519_dalek::ed25519::Error> {
+    for bytes in [key.to_bytes(), *signature.r_bytes()] {
+        let strict = match CompressedEdwardsY(bytes).decompress() {
+            Some(point) => !point.is_small_order() && point.compress().to_bytes() == bytes,
+            None => false,
+        };
+
+        if !strict {
+            return Err(ed25519_dalek::ed25519::Error::new());
+        }
+    }
+
+    Ok(())
+}
+
+/// SHA-512 state of the message, the prehash of Ed25519ph
+fn prehash_of(message: &[u8]) -> Sha512 {
+    Sha512::new().chain_update(message)
+}
+
+/// Only Ed25519ph hashes the content before signing, so only it can sign streamed content
+fn check_streamed_variant(variant: Ed25519Variant) -> Result<(), Error> {
+    if variant != Ed25519Variant::Ph {
+        fi_common::logger::error(
+            format!(
+                "{} can't sign streamed content, use Ed25519ph",
+                variant.to_str()
+            )
+            .as_str(),
+        );
+        return Err(Error::new(crate::errors::STREAMING_NOT_SUPPORTED));
+    }
+
+    Ok(())
+}
+
+fn encode_signature(
+    sig_result: Result<Signature, ed25519_dalek::ed25519::Error>,
+) -> Result<Vec<u8>, Error> {
+    let signature = match sig_result {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::SIGNING_FAILED));
+        }
+    };
+
+    Ok(signature.to_bytes().to_vec())
+}
+
+/// Terms of the cofactored verification equation [8][S]B = [8]R + [8][k]A
+struct CofactoredTerms {
+    public_point: EdwardsPoint,
+    big_r: EdwardsPoint,
+    s: Scalar,
+    k: Scalar,
+}
+
+/// Decompress the public key and R, which may be any point encoding that decompresses, and
+/// compute the challenge of the variant. S has to be canonical. Ed25519ph uses the streamed
+/// prehash when there is one, the hash of the message otherwise.
+fn cofactored_terms(
+    key: &VerifyingKey,
+    variant: Ed25519Variant,
+    context: &[u8],
+    message: &[u8],
+    prehash: Option<Sha512>,
+    signature: &Signature,
+) -> Option<CofactoredTerms> {
+    let public_point = CompressedEdwardsY(key.to_bytes()).decompress()?;
+    let big_r = CompressedEdwardsY(*signature.r_bytes()).decompress()?;
+    let s = Option::<Scalar>::from(Scalar::from_canonical_bytes(*signature.s_bytes()))?;
+
+    let hasher = match variant {
+        Ed25519Variant::Pure => Sha512::new(),
+        Ed25519Variant::Ctx => dom2_hasher(0, context),
+        Ed25519Variant::Ph => dom2_hasher(1, context),
+    }
+    .chain_update(signature.r_bytes())
+    .chain_update(key.as_bytes());
+    let k = match variant {
+        Ed25519Variant::Ph => Scalar::from_hash(
+            hasher.chain_update(prehash.unwrap_or_else(|| prehash_of(message)).finalize()),
+        ),
+        _ => Scalar::from_hash(hasher.chain_update(message)),
+    };
+
+    Some(CofactoredTerms {
+        public_point,
+        big_r,
+        s,
+        k,
+    })
+}
+
+/// ZIP-215 verification with the cofactored equation, see [`cofactored_terms`]
+fn verify_zip215(
+    key: &VerifyingKey,
+    variant: Ed25519Variant,
+    context: &[u8],
+    message: &[u8],
+    prehash: Option<Sha512>,
+    signature: &Signature,
+) -> Result<(), ed25519_dalek::ed25519::Error> {
+    let terms = match cofactored_terms(key, variant, context, message, prehash, signature) {
+        Some(val) => val,
+        None => return Err(ed25519_dalek::ed25519::Error::new()),
+    };
+
+    let check = EdwardsPoint::vartime_double_scalar_mul_basepoint(
+        &terms.k,
+        &(-terms.public_point),
+        &terms.s,
+    ) - terms.big_r;
+    if check.mul_by_cofactor().is_identity() {
+        Ok(())
+    } else {
+        Err(ed25519_dalek::ed25519::Error::new())
+    }
+}
 
 /// Signing key for ED25519 algorithm [`crate::algorithms::Algorithm::EdDSA`]
 #[wasm_bindgen]
@@ -21,33 +279,102 @@ pub struct EDDSASigningKey {
     key: SigningKey,
 
     #[cfg(feature = "wasm")]
-    key_str: Option<String>,
+    key_str: Option<Zeroizing<String>>,
     #[cfg(feature = "wasm")]
-    key_bytes: Option<Vec<u8>>,
+    key_bytes: Option<Zeroizing<Vec<u8>>>,
+
+    variant: Ed25519Variant,
+    context: Vec<u8>,
 }
 
 impl SignFromKey for EDDSASigningKey {
-    fn sign(&self, content: String, _alg: Algorithm) -> Result<String, Error> {
+    fn sign_bytes(&self, content: &[u8], _alg: Algorithm) -> Result<Vec<u8>, Error> {
         #[cfg(not(feature = "wasm"))]
-        let key = self.key.clone();
+        let key = &self.key;
 
         #[cfg(feature = "wasm")]
-        let key = match self.get_key() {
+        let key = &match self.get_key() {
             Ok(val) => val,
             Err(error) => return Err(error),
         };
 
-        let sig_result: Result<Signature, ed25519_dalek::ed25519::Error> =
-            key.try_sign(content.as_bytes());
-        let signature = match sig_result {
-            Ok(val) => val,
-            Err(error) => {
-                fi_common::logger::error(error.to_string().as_str());
-                return Err(Error::new(crate::errors::SIGNING_FAILED));
+        encode_signature(match self.variant {
+            Ed25519Variant::Pure => key.try_sign(content),
+            Ed25519Variant::Ph => {
+                key.sign_prehashed(prehash_of(content), Some(self.context.as_slice()))
             }
+            Ed25519Variant::Ctx => {
+                if self.context.is_empty() {
+                    return Err(Error::new(crate::errors::EDDSA_CONTEXT_REQUIRED));
+                }
+
+                Ok(sign_ctx(key, self.context.as_slice(), content))
+            }
+        })
+    }
+}
+
+impl SignStream for EDDSASigningKey {
+    fn sign_stream_hasher(&self, alg: Algorithm) -> Result<StreamHasher, Error> {
+        if let Err(error) = check_streamed_variant(self.variant) {
+            return Err(error);
+        }
+
+        match alg {
+            Algorithm::EdDSA => StreamHasher::digest(alg),
+            _ => Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+        }
+    }
+
+    fn sign_stream(&self, hasher: StreamHasher, _alg: Algorithm) -> Result<String, Error> {
+        if let Err(error) = check_streamed_variant(self.variant) {
+            return Err(error);
+        }
+
+        let prehash = match hasher.into_prehash() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        #[cfg(not(feature = "wasm"))]
+        let key = &self.key;
+
+        #[cfg(feature = "wasm")]
+        let key = &match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
         };
 
-        Ok(base64_url::encode(signature.to_bytes().as_slice()))
+        match encode_signature(key.sign_prehashed(prehash, Some(self.context.as_slice()))) {
+            Ok(val) => Ok(base64_url::encode(&val)),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl EDDSASigningKey {
+    /// Use the Ed25519 variant for signing, [`Ed25519Variant::Pure`] by default
+    #[wasm_bindgen(js_name = "setVariant")]
+    pub fn set_variant(&mut self, variant: Ed25519Variant) {
+        self.variant = variant;
+    }
+
+    /// Ed25519 variant used for signing
+    #[wasm_bindgen(getter)]
+    pub fn variant(&self) -> Ed25519Variant {
+        self.variant
+    }
+
+    /// Set the context string of [`Ed25519Variant::Ctx`] and [`Ed25519Variant::Ph`], at most 255 bytes
+    #[wasm_bindgen(js_name = "setContext")]
+    pub fn set_context(&mut self, context: &[u8]) -> Result<(), Error> {
+        if let Err(error) = check_context_length(context) {
+            return Err(error);
+        }
+
+        self.context = context.to_vec();
+        Ok(())
     }
 }
 
@@ -60,7 +387,11 @@ impl EDDSASigningKey {
             Err(error) => return Err(error),
         };
 
-        Ok(EDDSASigningKey { key: pkc8_key })
+        Ok(EDDSASigningKey {
+            key: pkc8_key,
+            variant: Ed25519Variant::Pure,
+            context: Vec::new(),
+        })
     }
 
     /// Create signing key from private key bytes.
@@ -70,11 +401,23 @@ impl EDDSASigningKey {
             Err(error) => return Err(error),
         };
 
-        Ok(EDDSASigningKey { key: ec_key })
+        Ok(EDDSASigningKey {
+            key: ec_key,
+            variant: Ed25519Variant::Pure,
+            context: Vec::new(),
+        })
+    }
+
+    pub(crate) fn get_key(&self) -> Result<&SigningKey, Error> {
+        Ok(&self.key)
     }
 }
 
 fn get_private_key_from_pem(key_str: &str) -> Result<SigningKey, Error> {
+    if let Err(error) = get_key_policy().check_curve(Curve::Ed25519) {
+        return Err(error);
+    }
+
     match SigningKey::from_pkcs8_pem(key_str) {
         Ok(val) => Ok(val),
         Err(error) => {
@@ -84,14 +427,18 @@ fn get_private_key_from_pem(key_str: &str) -> Result<SigningKey, Error> {
     }
 }
 
-fn get_private_key_from_bytes(bytes: &mut [u8]) -> Result<SigningKey, Error> {
-    if bytes.len() != 32 {
-        return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+fn get_private_key_from_bytes(bytes: &[u8]) -> Result<SigningKey, Error> {
+    if let Err(error) = get_key_policy().check_curve(Curve::Ed25519) {
+        return Err(error);
     }
 
-    let mut ec_bytes: [u8; 32] = [0; 32];
-    ec_bytes.copy_from_slice(&bytes);
-    Ok(SigningKey::from_bytes(&ec_bytes))
+    match SigningKey::try_from(bytes) {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+        }
+    }
 }
 
 #[cfg(feature = "wasm")]
@@ -101,8 +448,10 @@ impl EDDSASigningKey {
     #[wasm_bindgen]
     pub fn from_pem(key_str: &str) -> EDDSASigningKey {
         EDDSASigningKey {
-            key_str: Some(String::from(key_str)),
+            key_str: Some(Zeroizing::new(String::from(key_str))),
             key_bytes: None,
+            variant: Ed25519Variant::Pure,
+            context: Vec::new(),
         }
     }
 
@@ -111,20 +460,27 @@ impl EDDSASigningKey {
     pub fn from_bytes(bytes: &mut [u8]) -> EDDSASigningKey {
         EDDSASigningKey {
             key_str: None,
-            key_bytes: Some(bytes.to_vec()),
+            key_bytes: Some(Zeroizing::new(bytes.to_vec())),
+            variant: Ed25519Variant::Pure,
+            context: Vec::new(),
         }
     }
 
-    fn get_key(&self) -> Result<SigningKey, Error> {
-        let key_bytes = self.key_bytes.clone();
-        let key_str = self.key_str.clone();
+    pub(crate) fn get_key(&self) -> Result<SigningKey, Error> {
+        match (&self.key_str, &self.key_bytes) {
+            (Some(key_str), _) => get_private_key_from_pem(key_str.as_str()),
+            (None, Some(key_bytes)) => get_private_key_from_bytes(key_bytes.as_slice()),
+            (None, None) => Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+        }
+    }
 
-        if key_str.is_some() {
-            get_private_key_from_pem(key_str.unwrap().as_str())
-        } else if key_bytes.is_some() {
-            get_private_key_from_bytes(key_bytes.unwrap().as_mut_slice())
-        } else {
-            Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR))
+    fn set_variant_from_js_object(&mut self, value: &Object) -> Result<(), Error> {
+        match get_variant_from_js_object(value) {
+            Ok((variant, context)) => {
+                self.variant = variant;
+                self.set_context(context.as_slice())
+            }
+            Err(error) => Err(error),
         }
     }
 
@@ -135,7 +491,7 @@ impl EDDSASigningKey {
             let pem = match js_sys::Reflect::get(&value, &pem_field) {
                 Ok(val) => {
                     let string_value = match val.as_string() {
-                        Some(v) => v,
+                        Some(v) => Zeroizing::new(v),
                         None => return Err(Error::new(crate::errors::MISSING_FIELD)),
                     };
                     string_value
@@ -146,9 +502,26 @@ impl EDDSASigningKey {
                 }
             };
 
-            return Ok(EDDSASigningKey::from_pem(pem.as_str()));
+            let mut key = EDDSASigningKey::from_pem(pem.as_str());
+            if let Err(error) = key.set_variant_from_js_object(&value) {
+                return Err(error);
+            }
+
+            return Ok(key);
+        } else if value.has_own_property(&JsValue::from_str("bytes")) {
+            let mut arr = match get_bytes_from_js_object(&value) {
+                Ok(val) => val,
+                Err(error) => return Err(error),
+            };
+
+            let mut key = EDDSASigningKey::from_bytes(arr.as_mut_slice());
+            if let Err(error) = key.set_variant_from_js_object(&value) {
+                return Err(error);
+            }
+
+            return Ok(key);
         } else if value.is_array() {
-            let mut arr = Uint8Array::new(&value).to_vec();
+            let mut arr = Zeroizing::new(Uint8Array::new(&value).to_vec());
             let bytes = arr.as_mut_slice();
 
             return Ok(EDDSASigningKey::from_bytes(bytes));
@@ -167,19 +540,70 @@ pub struct EDDSAVerifyingKey {
     key_bytes: Option<Vec<u8>>,
     #[cfg(feature = "wasm")]
     key_str: Option<String>,
+
+    variant: Ed25519Variant,
+    context: Vec<u8>,
 }
 
 impl VerifyFromKey for EDDSAVerifyingKey {
-    fn verify(&self, content: String, sig: String, _alg: Algorithm) -> Result<bool, Error> {
-        let decoded_sig = match base64_url::decode(sig.as_bytes()) {
+    fn check_key_policy(&self, policy: &KeyPolicy) -> Result<(), Error> {
+        policy.check_curve(Curve::Ed25519)
+    }
+
+    fn verify_bytes(
+        &self,
+        content: &[u8],
+        signature: &[u8],
+        _alg: Algorithm,
+    ) -> Result<bool, Error> {
+        self.verify_signature(content, None, signature)
+    }
+}
+
+impl VerifyStream for EDDSAVerifyingKey {
+    fn verify_stream_hasher(&self, alg: Algorithm) -> Result<StreamHasher, Error> {
+        if let Err(error) = check_streamed_variant(self.variant) {
+            return Err(error);
+        }
+
+        match alg {
+            Algorithm::EdDSA => StreamHasher::digest(alg),
+            _ => Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+        }
+    }
+
+    fn verify_stream(
+        &self,
+        hasher: StreamHasher,
+        signature: String,
+        _alg: Algorithm,
+    ) -> Result<bool, Error> {
+        if let Err(error) = check_streamed_variant(self.variant) {
+            return Err(error);
+        }
+
+        let prehash = match hasher.into_prehash() {
             Ok(val) => val,
-            Err(error) => {
-                fi_common::logger::error(error.to_string().as_str());
-                return Err(Error::new(crate::errors::DECODING_ERROR));
-            }
+            Err(error) => return Err(error),
         };
 
-        let signature = match Signature::from_slice(&decoded_sig) {
+        match decode_base64_signature(signature.as_str()) {
+            Ok(val) => self.verify_signature(&[], Some(prehash), &val),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl EDDSAVerifyingKey {
+    /// Verify the signature of the message. Ed25519ph uses the streamed prehash when there is
+    /// one, the hash of the message otherwise.
+    fn verify_signature(
+        &self,
+        message: &[u8],
+        prehash: Option<Sha512>,
+        sig: &[u8],
+    ) -> Result<bool, Error> {
+        let signature = match Signature::from_slice(sig) {
             Ok(val) => val,
             Err(error) => {
                 fi_common::logger::error(error.to_string().as_str());
@@ -196,8 +620,38 @@ impl VerifyFromKey for EDDSAVerifyingKey {
             Err(error) => return Err(error),
         };
 
-        let verify_result: Result<(), ed25519_dalek::ed25519::Error> =
-            key.verify(content.as_bytes(), &signature);
+        if self.variant == Ed25519Variant::Ctx && self.context.is_empty() {
+            return Err(Error::new(crate::errors::EDDSA_CONTEXT_REQUIRED));
+        }
+
+        let context = self.context.as_slice();
+        let verify_result: Result<(), ed25519_dalek::ed25519::Error> = match get_verification_mode()
+        {
+            VerificationMode::Lenient => match self.variant {
+                Ed25519Variant::Pure => key.verify(message, &signature),
+                Ed25519Variant::Ph => key.verify_prehashed(
+                    prehash.unwrap_or_else(|| prehash_of(message)),
+                    Some(context),
+                    &signature,
+                ),
+                Ed25519Variant::Ctx => verify_ctx(&key, context, message, &signature),
+            },
+            VerificationMode::Strict => match check_strict_points(&key, &signature) {
+                Ok(()) => match self.variant {
+                    Ed25519Variant::Pure => key.verify_strict(message, &signature),
+                    Ed25519Variant::Ph => key.verify_prehashed_strict(
+                        prehash.unwrap_or_else(|| prehash_of(message)),
+                        Some(context),
+                        &signature,
+                    ),
+                    Ed25519Variant::Ctx => verify_ctx(&key, context, message, &signature),
+                },
+                Err(error) => Err(error),
+            },
+            VerificationMode::Zip215 => {
+                verify_zip215(&key, self.variant, context, message, prehash, &signature)
+            }
+        };
         if verify_result.is_ok() {
             return Ok(true);
         } else {
@@ -213,6 +667,10 @@ impl VerifyFromKey for EDDSAVerifyingKey {
 }
 
 fn get_public_key_from_pem(key_str: &str) -> Result<VerifyingKey, Error> {
+    if let Err(error) = get_key_policy().check_curve(Curve::Ed25519) {
+        return Err(error);
+    }
+
     match VerifyingKey::from_public_key_pem(key_str) {
         Ok(val) => Ok(val),
         Err(error) => {
@@ -223,6 +681,10 @@ fn get_public_key_from_pem(key_str: &str) -> Result<VerifyingKey, Error> {
 }
 
 fn get_public_key_from_bytes(bytes: &mut [u8]) -> Result<VerifyingKey, Error> {
+    if let Err(error) = get_key_policy().check_curve(Curve::Ed25519) {
+        return Err(error);
+    }
+
     if bytes.len() != 32 {
         return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR));
     }
@@ -238,6 +700,32 @@ fn get_public_key_from_bytes(bytes: &mut [u8]) -> Result<VerifyingKey, Error> {
     }
 }
 
+#[wasm_bindgen]
+impl EDDSAVerifyingKey {
+    /// Use the Ed25519 variant the signature was created with, [`Ed25519Variant::Pure`] by default
+    #[wasm_bindgen(js_name = "setVariant")]
+    pub fn set_variant(&mut self, variant: Ed25519Variant) {
+        self.variant = variant;
+    }
+
+    /// Ed25519 variant used for verifying
+    #[wasm_bindgen(getter)]
+    pub fn variant(&self) -> Ed25519Variant {
+        self.variant
+    }
+
+    /// Set the context string of [`Ed25519Variant::Ctx`] and [`Ed25519Variant::Ph`], at most 255 bytes
+    #[wasm_bindgen(js_name = "setContext")]
+    pub fn set_context(&mut self, context: &[u8]) -> Result<(), Error> {
+        if let Err(error) = check_context_length(context) {
+            return Err(error);
+        }
+
+        self.context = context.to_vec();
+        Ok(())
+    }
+}
+
 #[cfg(not(feature = "wasm"))]
 impl EDDSAVerifyingKey {
     /// Create verifying key from pem formatted public key. <b>pksc8</b> only.
@@ -247,7 +735,11 @@ impl EDDSAVerifyingKey {
             Err(error) => return Err(error),
         };
 
-        Ok(EDDSAVerifyingKey { key: pkc8_key })
+        Ok(EDDSAVerifyingKey {
+            key: pkc8_key,
+            variant: Ed25519Variant::Pure,
+            context: Vec::new(),
+        })
     }
 
     /// Create verifying key from public key bytes. <b>pksc8</b> only.
@@ -256,7 +748,23 @@ impl EDDSAVerifyingKey {
             Ok(val) => val,
             Err(error) => return Err(error),
         };
-        Ok(EDDSAVerifyingKey { key: ec_key })
+        Ok(EDDSAVerifyingKey {
+            key: ec_key,
+            variant: Ed25519Variant::Pure,
+            context: Vec::new(),
+        })
+    }
+
+    pub(crate) fn get_key(&self) -> Result<VerifyingKey, Error> {
+        Ok(self.key)
+    }
+
+    pub(crate) fn from_key(key: VerifyingKey) -> EDDSAVerifyingKey {
+        EDDSAVerifyingKey {
+            key,
+            variant: Ed25519Variant::Pure,
+            context: Vec::new(),
+        }
     }
 }
 
@@ -269,6 +777,8 @@ impl EDDSAVerifyingKey {
         EDDSAVerifyingKey {
             key_str: Some(String::from(key_str)),
             key_bytes: None,
+            variant: Ed25519Variant::Pure,
+            context: Vec::new(),
         }
     }
 
@@ -278,10 +788,16 @@ impl EDDSAVerifyingKey {
         EDDSAVerifyingKey {
             key_str: None,
             key_bytes: Some(bytes.to_vec()),
+            variant: Ed25519Variant::Pure,
+            context: Vec::new(),
         }
     }
 
-    fn get_key(&self) -> Result<VerifyingKey, Error> {
+    pub(crate) fn from_key(key: VerifyingKey) -> EDDSAVerifyingKey {
+        EDDSAVerifyingKey::from_bytes(&mut key.to_bytes())
+    }
+
+    pub(crate) fn get_key(&self) -> Result<VerifyingKey, Error> {
         let key_bytes = self.key_bytes.clone();
         let key_str = self.key_str.clone();
 
@@ -294,6 +810,16 @@ impl EDDSAVerifyingKey {
         }
     }
 
+    fn set_variant_from_js_object(&mut self, value: &Object) -> Result<(), Error> {
+        match get_variant_from_js_object(value) {
+            Ok((variant, context)) => {
+                self.variant = variant;
+                self.set_context(context.as_slice())
+            }
+            Err(error) => Err(error),
+        }
+    }
+
     pub fn from_js_object(value: Object) -> Result<EDDSAVerifyingKey, Error> {
         let pem_field = JsValue::from_str("pem");
 
@@ -312,7 +838,24 @@ impl EDDSAVerifyingKey {
                 }
             };
 
-            return Ok(EDDSAVerifyingKey::from_pem(pem.as_str()));
+            let mut key = EDDSAVerifyingKey::from_pem(pem.as_str());
+            if let Err(error) = key.set_variant_from_js_object(&value) {
+                return Err(error);
+            }
+
+            return Ok(key);
+        } else if value.has_own_property(&JsValue::from_str("bytes")) {
+            let mut arr = match get_bytes_from_js_object(&value) {
+                Ok(val) => val,
+                Err(error) => return Err(error),
+            };
+
+            let mut key = EDDSAVerifyingKey::from_bytes(arr.as_mut_slice());
+            if let Err(error) = key.set_variant_from_js_object(&value) {
+                return Err(error);
+            }
+
+            return Ok(key);
         } else if value.is_array() {
             let mut arr = Uint8Array::new(&value).to_vec();
             let bytes = arr.as_mut_slice();
@@ -324,21 +867,155 @@ impl EDDSAVerifyingKey {
     }
 }
 
-/// Sign content using [`crate::algorithms::Algorithm::EdDSA`] algorithm
+/// How one entry of a batch is verified
+enum BatchEntry {
+    /// Part of the cofactored batch equation
+    Batched(Box<CofactoredTerms>),
+    /// Verified on its own, the batch equation doesn't decide it in this mode
+    Single,
+    /// Can't be valid
+    Invalid,
+}
+
+fn batch_entry(message: &str, signature: &str, key: &EDDSAVerifyingKey) -> BatchEntry {
+    let signature = match base64_url::decode(signature.as_bytes()) {
+        Ok(val) => match Signature::from_slice(&val) {
+            Ok(val) => val,
+            Err(_) => return BatchEntry::Invalid,
+        },
+        Err(_) => return BatchEntry::Invalid,
+    };
+    let public_key = match key.get_key() {
+        Ok(val) => val,
+        Err(_) => return BatchEntry::Invalid,
+    };
+
+    if key.variant == Ed25519Variant::Ctx && key.context.is_empty() {
+        return BatchEntry::Invalid;
+    }
+
+    let mode = get_verification_mode();
+    if mode == VerificationMode::Strict && check_strict_points(&public_key, &signature).is_err() {
+        return BatchEntry::Invalid;
+    }
+
+    let terms = match cofactored_terms(
+        &public_key,
+        key.variant,
+        key.context.as_slice(),
+        message.as_bytes(),
+        None,
+        &signature,
+    ) {
+        Some(val) => val,
+        None => return BatchEntry::Invalid,
+    };
+
+    // The cofactored equation ignores small order components and R is only compared by its
+    // encoding outside of ZIP-215, so only torsion free points with a canonical R give the
+    // same result as the cofactorless equation of the other modes
+    if mode != VerificationMode::Zip215
+        && !(terms.public_point.is_torsion_free()
+            && terms.big_r.is_torsion_free()
+            && terms.big_r.compress().as_bytes() == signature.r_bytes())
+    {
+        return BatchEntry::Single;
+    }
+
+    BatchEntry::Batched(Box::new(terms))
+}
+
+/// Verify Ed25519 signatures in one randomized batch, one message and base64url signature per
+/// key. Returns whether each entry is valid. When the batch fails every entry is verified on its
+/// own to find the invalid ones.
+///
+/// The batch checks the cofactored equation of ZIP-215. Outside of
+/// [`VerificationMode::Zip215`] entries with small order components or a non-canonical R are
+/// left out of the batch and verified on their own, so every entry gets the result of
+/// [`VerifyFromKey::verify`] in the current mode.
+pub fn verify_batch(
+    messages: &[impl AsRef<str>],
+    signatures: &[impl AsRef<str>],
+    keys: &[EDDSAVerifyingKey],
+) -> Result<Vec<bool>, Error> {
+    if messages.len() != keys.len() || signatures.len() != keys.len() {
+        return Err(Error::new(crate::errors::EDDSA_BATCH_INPUT));
+    }
+
+    let entries: Vec<BatchEntry> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| batch_entry(messages[i].as_ref(), signatures[i].as_ref(), key))
+        .collect();
+
+    // sum of z * ([k]A + R - [S]B) with random 128 bit z for every entry
+    let mut basepoint_scalar = Scalar::ZERO;
+    let mut scalars = Vec::with_capacity(2 * entries.len());
+    let mut points = Vec::with_capacity(2 * entries.len());
+    for entry in entries.iter() {
+        let terms = match entry {
+            BatchEntry::Batched(val) => val,
+            _ => continue,
+        };
+
+        let mut z = [0u8; 32];
+        OsRng.fill_bytes(&mut z[..16]);
+        let z = Scalar::from_bytes_mod_order(z);
+
+        basepoint_scalar -= z * terms.s;
+        scalars.push(z);
+        points.push(terms.big_r);
+        scalars.push(z * terms.k);
+        points.push(terms.public_point);
+    }
+    scalars.push(basepoint_scalar);
+    points.push(ED25519_BASEPOINT_POINT);
+
+    let batch = EdwardsPoint::vartime_multiscalar_mul(scalars, points);
+    let batch_valid = batch.mul_by_cofactor().is_identity();
+    if !batch_valid {
+        fi_common::logger::error("Ed25519 batch verification failed, verifying every signature");
+    }
+
+    Ok(keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| match &entries[i] {
+            BatchEntry::Batched(_) if batch_valid => true,
+            BatchEntry::Batched(_) | BatchEntry::Single => key
+                .verify(
+                    String::from(messages[i].as_ref()),
+                    String::from(signatures[i].as_ref()),
+                    Algorithm::EdDSA,
+                )
+                .unwrap_or(false),
+            BatchEntry::Invalid => false,
+        })
+        .collect())
+}
+
+/// Sign content using [`crate::algorithms::Algorithm::EdDSA`] algorithm. The curve, Ed25519 or Ed448, follows the key.
 #[cfg(not(feature = "wasm"))]
 pub fn sign_eddsa(message: String, key: impl SignFromKey, alg: Algorithm) -> Result<String, Error> {
     key.sign(message, alg)
 }
 
 #[cfg(feature = "wasm")]
-pub fn sign_eddsa(message: String, key: impl SignFromKey, alg: Algorithm) -> Result<String, Error> {
-    match key.sign(message, alg) {
-        Ok(val) => Ok(val),
+pub fn sign_eddsa(message: String, key: Object, alg: Algorithm) -> Result<String, Error> {
+    match get_okp_curve(&key) {
+        Ok(Curve::Ed448) => match Ed448SigningKey::from_js_object(key) {
+            Ok(val) => val.sign(message, alg),
+            Err(error) => Err(error),
+        },
+        Ok(_) => match EDDSASigningKey::from_js_object(key) {
+            Ok(val) => val.sign(message, alg),
+            Err(error) => Err(error),
+        },
         Err(error) => Err(error),
     }
 }
 
-/// Verify signature using [`crate::algorithms::Algorithm::EdDSA`] algorithm
+/// Verify signature using [`crate::algorithms::Algorithm::EdDSA`] algorithm. The curve, Ed25519 or Ed448, follows the key.
 #[cfg(not(feature = "wasm"))]
 pub fn verify_eddsa(
     message: String,
@@ -353,11 +1030,121 @@ pub fn verify_eddsa(
 pub fn verify_eddsa(
     message: String,
     sig: String,
-    key: impl VerifyFromKey,
+    key: Object,
     alg: Algorithm,
 ) -> Result<bool, Error> {
-    match key.verify(message, sig, alg) {
-        Ok(val) => Ok(val),
+    match get_okp_curve(&key) {
+        Ok(Curve::Ed448) => match Ed448VerifyingKey::from_js_object(key) {
+            Ok(val) => val.verify(message, sig, alg),
+            Err(error) => Err(error),
+        },
+        Ok(_) => match EDDSAVerifyingKey::from_js_object(key) {
+            Ok(val) => val.verify(message, sig, alg),
+            Err(error) => Err(error),
+        },
         Err(error) => Err(error),
     }
 }
+
+/// Ed25519 variant and context string of a <b>pem</b> or <b>bytes</b> key JS object, read from
+/// the optional <b>variant</b> (Ed25519, Ed25519ctx or Ed25519ph) and <b>context</b>
+/// (Uint8Array) fields.
+#[cfg(feature = "wasm")]
+fn get_variant_from_js_object(value: &Object) -> Result<(Ed25519Variant, Vec<u8>), Error> {
+    let variant = match js_sys::Reflect::get(value, &JsValue::from_str("variant")) {
+        Ok(val) if val.is_undefined() => Ed25519Variant::Pure,
+        Ok(val) => match val.as_string() {
+            Some(v) => match Ed25519Variant::from_str(v.as_str()) {
+                Ok(variant) => variant,
+                Err(error) => return Err(error),
+            },
+            None => return Err(Error::new(crate::errors::MISSING_FIELD)),
+        },
+        Err(_) => Ed25519Variant::Pure,
+    };
+
+    let context = match js_sys::Reflect::get(value, &JsValue::from_str("context")) {
+        Ok(val) if val.is_undefined() => Vec::new(),
+        Ok(val) => Uint8Array::new(&val).to_vec(),
+        Err(_) => Vec::new(),
+    };
+
+    Ok((variant, context))
+}
+
+/// Raw Ed25519 key of the <b>bytes</b> field (Uint8Array) of a key JS object, the object form
+/// of raw keys that also carries a variant and context
+#[cfg(feature = "wasm")]
+fn get_bytes_from_js_object(value: &Object) -> Result<Zeroizing<Vec<u8>>, Error> {
+    match js_sys::Reflect::get(value, &JsValue::from_str("bytes")) {
+        Ok(val) if val.is_undefined() => Err(Error::new(crate::errors::MISSING_FIELD)),
+        Ok(val) => Ok(Zeroizing::new(Uint8Array::new(&val).to_vec())),
+        Err(error) => {
+            fi_common::logger::error(error.as_string().unwrap().as_str());
+            Err(Error::new(crate::errors::MISSING_FIELD))
+        }
+    }
+}
+
+/// Curve of an OKP key JS object, detected from the <b>pkcs8</b>/<b>spki</b> algorithm OID
+/// of pem keys or from the length of raw keys. The <b>bytes</b> object form is Ed25519 only.
+#[cfg(feature = "wasm")]
+fn get_okp_curve(value: &Object) -> Result<Curve, Error> {
+    let pem_field = JsValue::from_str("pem");
+
+    if value.has_own_property(&pem_field) {
+        match js_sys::Reflect::get(value, &pem_field) {
+            Ok(val) => match val.as_string() {
+                Some(v) => get_okp_curve_from_pem(Zeroizing::new(v).as_str()),
+                None => Err(Error::new(crate::errors::MISSING_FIELD)),
+            },
+            Err(error) => {
+                fi_common::logger::error(error.as_string().unwrap().as_str());
+                Err(Error::new(crate::errors::MISSING_FIELD))
+            }
+        }
+    } else if value.has_own_property(&JsValue::from_str("bytes")) {
+        Ok(Curve::Ed25519)
+    } else if value.is_array() {
+        match Uint8Array::new(value).length() as usize {
+            ed448_goldilocks_plus::SECRET_KEY_LENGTH => Ok(Curve::Ed448),
+            _ => Ok(Curve::Ed25519),
+        }
+    } else {
+        Err(Error::new(crate::errors::MISSING_FIELD))
+    }
+}
+
+#[cfg(feature = "wasm")]
+fn get_okp_curve_from_pem(key_str: &str) -> Result<Curve, Error> {
+    let (label, der) = match pem::decode_vec(key_str.trim().as_bytes()) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::DECODING_ERROR));
+        }
+    };
+
+    let oid = if label == "PRIVATE KEY" {
+        PrivateKeyInfo::try_from(der.as_slice())
+            .map(|info| info.algorithm.oid)
+            .map_err(|error| error.to_string())
+    } else {
+        SubjectPublicKeyInfoRef::try_from(der.as_slice())
+            .map(|info| info.algorithm.oid)
+            .map_err(|error| error.to_string())
+    };
+
+    match oid {
+        Ok(val) if val == ed448_goldilocks_plus::ALGORITHM_OID => Ok(Curve::Ed448),
+        Ok(val) if val == ed25519_dalek::pkcs8::ALGORITHM_OID => Ok(Curve::Ed25519),
+        Ok(val) => {
+            fi_common::logger::error(format!("Unknown key algorithm OID {}", val).as_str());
+            Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE))
+        }
+        Err(error) => {
+            fi_common::logger::error(error.as_str());
+            Err(Error::new(crate::errors::UNSUPPORTED_KEY_TYPE))
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use crate::algorithms::Algorithm;
+use base64::{engine::general_purpose::STANDARD, Engine};
 use fi_common::error::Error;
 use generic_array::typenum::{IsLess, Le, NonZero, U256};
+use hkdf::Hkdf;
 use hmac::Hmac;
 use hmac::Mac;
 use js_sys::Object;
@@ -10,32 +12,232 @@ use sha2::{
     digest::{
         block_buffer::Eager,
         core_api::{BlockSizeUser, BufferKindUser, CoreProxy, FixedOutputCore, UpdateCore},
-        HashMarker,
+        HashMarker, OutputSizeUser,
     },
     Sha256,
 };
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsValue;
+use zeroize::Zeroizing;
 
+use super::decode_base64_signature;
+use super::policy::KeyPolicy;
 use super::SignFromKey;
+use super::SignStream;
 use super::VerifyFromKey;
+use super::VerifyStream;
+use crate::stream::StreamHasher;
 
-/// Signing key for HMAC algorithm
+/// Minimum HMAC key length in bytes for the algorithm, which is the size of the hash output
+/// (RFC 7518 section 3.2)
+pub fn min_key_length(alg: Algorithm) -> Result<usize, Error> {
+    match alg {
+        Algorithm::HS256 => Ok(32),
+        Algorithm::HS384 => Ok(48),
+        Algorithm::HS512 => Ok(64),
+        _ => Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+    }
+}
+
+fn hkdf_expand<T>(master: &[u8], salt: &[u8], info: &[u8], okm: &mut [u8]) -> Result<(), Error>
+where
+    T: CoreProxy + OutputSizeUser,
+    T::Core: HashMarker
+        + UpdateCore
+        + FixedOutputCore
+        + BufferKindUser<BufferKind = Eager>
+        + Default
+        + Clone,
+    <T::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<T::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    match Hkdf::<T>::new(Some(salt), master).expand(info, okm) {
+        Ok(()) => Ok(()),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::KEY_DERIVATION_FAILED))
+        }
+    }
+}
+
+/// Signing key for HMAC algorithm. The secret is zeroized on drop.
 #[derive(Clone)]
 #[wasm_bindgen]
 pub struct HMACKey {
-    key: String,
+    key: Zeroizing<Vec<u8>>,
+    strict: bool,
 }
 
 #[wasm_bindgen]
 impl HMACKey {
-    /// Create new <b>HMACKey</b> instance
+    /// Create new <b>HMACKey</b> instance from a pass phrase
     #[wasm_bindgen(constructor)]
     pub fn new(pass: String) -> HMACKey {
-        HMACKey { key: pass }
+        HMACKey {
+            key: Zeroizing::new(pass.into_bytes()),
+            strict: false,
+        }
+    }
+
+    /// Create <b>HMACKey</b> from raw secret bytes
+    #[wasm_bindgen(js_name = "fromBytes")]
+    pub fn from_bytes(bytes: &[u8]) -> HMACKey {
+        HMACKey {
+            key: Zeroizing::new(bytes.to_vec()),
+            strict: false,
+        }
+    }
+
+    /// Create <b>HMACKey</b> from a hex encoded secret
+    #[wasm_bindgen(js_name = "fromHex")]
+    pub fn from_hex(encoded: &str) -> Result<HMACKey, Error> {
+        match hex::decode(encoded.trim()) {
+            Ok(val) => Ok(HMACKey {
+                key: Zeroizing::new(val),
+                strict: false,
+            }),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::DECODING_ERROR))
+            }
+        }
+    }
+
+    /// Create <b>HMACKey</b> from a standard base64 encoded secret
+    #[wasm_bindgen(js_name = "fromBase64")]
+    pub fn from_base64(encoded: &str) -> Result<HMACKey, Error> {
+        match STANDARD.decode(encoded.trim()) {
+            Ok(val) => Ok(HMACKey {
+                key: Zeroizing::new(val),
+                strict: false,
+            }),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::DECODING_ERROR))
+            }
+        }
+    }
+
+    /// Create <b>HMACKey</b> from a base64url encoded secret, e.g. the <b>k</b> value of a JWK
+    /// <b>oct</b> key
+    #[wasm_bindgen(js_name = "fromBase64Url")]
+    pub fn from_base64_url(encoded: &str) -> Result<HMACKey, Error> {
+        match base64_url::decode(encoded.trim()) {
+            Ok(val) => Ok(HMACKey {
+                key: Zeroizing::new(val),
+                strict: false,
+            }),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::DECODING_ERROR))
+            }
+        }
+    }
+
+    /// Derive <b>HMACKey</b> from a master secret with HKDF (RFC 5869). The hash function and
+    /// the key length follow the algorithm, e.g. HKDF-SHA384 and 48 bytes for
+    /// [`crate::algorithms::Algorithm::HS384`].
+    #[wasm_bindgen(js_name = "deriveHkdf")]
+    pub fn derive_hkdf(
+        master: &[u8],
+        salt: &[u8],
+        info: &[u8],
+        alg: Algorithm,
+    ) -> Result<HMACKey, Error> {
+        let length = match min_key_length(alg) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let mut key = Zeroizing::new(vec![0u8; length]);
+        let result = match alg {
+            Algorithm::HS256 => hkdf_expand::<Sha256>(master, salt, info, key.as_mut_slice()),
+            Algorithm::HS384 => hkdf_expand::<Sha384>(master, salt, info, key.as_mut_slice()),
+            _ => hkdf_expand::<Sha512>(master, salt, info, key.as_mut_slice()),
+        };
+
+        match result {
+            Ok(()) => Ok(HMACKey { key, strict: false }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Derive <b>HMACKey</b> from a password with PBKDF2 (RFC 8018). HMAC with the hash
+    /// function of the algorithm is used as the PRF and the key is sized to the algorithm.
+    #[wasm_bindgen(js_name = "derivePbkdf2")]
+    pub fn derive_pbkdf2(
+        password: &[u8],
+        salt: &[u8],
+        iterations: u32,
+        alg: Algorithm,
+    ) -> Result<HMACKey, Error> {
+        let length = match min_key_length(alg) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if iterations == 0 {
+            return Err(Error::new(crate::errors::KEY_DERIVATION_FAILED));
+        }
+
+        let mut key = Zeroizing::new(vec![0u8; length]);
+        match alg {
+            Algorithm::HS256 => {
+                pbkdf2::pbkdf2_hmac::<Sha256>(password, salt, iterations, key.as_mut_slice())
+            }
+            Algorithm::HS384 => {
+                pbkdf2::pbkdf2_hmac::<Sha384>(password, salt, iterations, key.as_mut_slice())
+            }
+            _ => pbkdf2::pbkdf2_hmac::<Sha512>(password, salt, iterations, key.as_mut_slice()),
+        };
+
+        Ok(HMACKey { key, strict: false })
     }
 
-    fn hmac_sign<T>(&self, content: String) -> Result<String, Error>
+    /// Enable or disable strict mode. In strict mode keys shorter than the hash output of the
+    /// algorithm are rejected, as required by RFC 7518 section 3.2.
+    #[wasm_bindgen(js_name = "setStrict")]
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Check if strict mode is enabled
+    #[wasm_bindgen(js_name = "isStrict")]
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Length of the secret in bytes
+    #[wasm_bindgen(js_name = "keyLength")]
+    pub fn key_length(&self) -> usize {
+        self.key.len()
+    }
+
+    /// Check the secret against the hash size of the algorithm in strict mode. The key policy
+    /// is checked by the signer and the verifier, see [`SignFromKey::check_key_policy`].
+    fn check_key_length(&self, alg: Algorithm) -> Result<(), Error> {
+        let min_length = match min_key_length(alg) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if self.strict && self.key.len() < min_length {
+            fi_common::logger::error(
+                format!(
+                    "HMAC key is {} bytes, {} requires at least {} bytes",
+                    self.key.len(),
+                    alg.to_str(),
+                    min_length
+                )
+                .as_str(),
+            );
+            return Err(Error::new(crate::errors::HMAC_KEY_TOO_SHORT));
+        }
+
+        Ok(())
+    }
+
+    fn hmac_sign<T>(&self, content: &[u8]) -> Result<Vec<u8>, Error>
     where
         T: CoreProxy,
         T::Core: HashMarker
@@ -47,7 +249,7 @@ impl HMACKey {
         <T::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
         Le<<T::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
     {
-        let mut hmac_wrapper = match Hmac::<T>::new_from_slice(self.key.as_bytes()) {
+        let mut hmac_wrapper = match Hmac::<T>::new_from_slice(self.key.as_slice()) {
             Ok(val) => val,
             Err(error) => {
                 fi_common::logger::error(error.to_string().as_str());
@@ -55,13 +257,12 @@ impl HMACKey {
             }
         };
 
-        hmac_wrapper.update(content.as_bytes());
+        hmac_wrapper.update(content);
 
-        let signed_bytes = hmac_wrapper.finalize().into_bytes();
-        Ok(base64_url::encode(&signed_bytes.to_vec()))
+        Ok(hmac_wrapper.finalize().into_bytes().to_vec())
     }
 
-    fn hmac_verify<T>(&self, content: String, signature: String) -> Result<bool, Error>
+    fn hmac_verify<T>(&self, content: &[u8], signature: &[u8]) -> Result<bool, Error>
     where
         T: CoreProxy,
         T::Core: HashMarker
@@ -73,15 +274,7 @@ impl HMACKey {
         <T::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
         Le<<T::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
     {
-        let sig = match base64_url::decode(&signature) {
-            Ok(val) => val,
-            Err(error) => {
-                fi_common::logger::error(error.to_string().as_str());
-                return Err(Error::new(crate::errors::DECODING_ERROR));
-            }
-        };
-
-        let mut hmac_wrapper = match Hmac::<T>::new_from_slice(self.key.as_bytes()) {
+        let mut hmac_wrapper = match Hmac::<T>::new_from_slice(self.key.as_slice()) {
             Ok(val) => val,
             Err(error) => {
                 fi_common::logger::error(error.to_string().as_str());
@@ -89,9 +282,9 @@ impl HMACKey {
             }
         };
 
-        hmac_wrapper.update(content.as_bytes());
+        hmac_wrapper.update(content);
 
-        match hmac_wrapper.verify_slice(sig.as_slice()) {
+        match hmac_wrapper.verify_slice(signature) {
             Ok(()) => Ok(true),
             Err(error) => {
                 fi_common::logger::error(error.to_string().as_str());
@@ -102,8 +295,22 @@ impl HMACKey {
 
     pub fn from_js_object(value: Object) -> Result<HMACKey, Error> {
         let phrase = JsValue::from_str("passphrase");
+        let k_field = JsValue::from_str("k");
+
+        if value.has_own_property(&k_field) {
+            let k = match js_sys::Reflect::get(&value, &k_field) {
+                Ok(val) => match val.as_string() {
+                    Some(v) => Zeroizing::new(v),
+                    None => return Err(Error::new(crate::errors::MISSING_FIELD)),
+                },
+                Err(error) => {
+                    fi_common::logger::error(error.as_string().unwrap().as_str());
+                    return Err(Error::new(crate::errors::MISSING_FIELD));
+                }
+            };
 
-        if value.has_own_property(&phrase) {
+            return HMACKey::from_base64_url(k.as_str());
+        } else if value.has_own_property(&phrase) {
             let phrase = match js_sys::Reflect::get(&value, &phrase) {
                 Ok(val) => val.as_string().unwrap(),
                 Err(error) => {
@@ -120,7 +327,11 @@ impl HMACKey {
 }
 
 impl SignFromKey for HMACKey {
-    fn sign(&self, content: String, alg: crate::algorithms::Algorithm) -> Result<String, Error> {
+    fn sign_bytes(&self, content: &[u8], alg: Algorithm) -> Result<Vec<u8>, Error> {
+        if let Err(error) = self.check_key_length(alg) {
+            return Err(error);
+        }
+
         match alg {
             Algorithm::HS256 => self.hmac_sign::<Sha256>(content),
             Algorithm::HS384 => self.hmac_sign::<Sha384>(content),
@@ -128,10 +339,27 @@ impl SignFromKey for HMACKey {
             _ => Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
         }
     }
+
+    fn check_key_policy(&self, policy: &KeyPolicy) -> Result<(), Error> {
+        policy.check_hmac_key_length(self.key.len())
+    }
 }
 
 impl VerifyFromKey for HMACKey {
-    fn verify(&self, content: String, signature: String, alg: Algorithm) -> Result<bool, Error> {
+    fn check_key_policy(&self, policy: &KeyPolicy) -> Result<(), Error> {
+        policy.check_hmac_key_length(self.key.len())
+    }
+
+    fn verify_bytes(
+        &self,
+        content: &[u8],
+        signature: &[u8],
+        alg: Algorithm,
+    ) -> Result<bool, Error> {
+        if let Err(error) = self.check_key_length(alg) {
+            return Err(error);
+        }
+
         match alg {
             Algorithm::HS256 => self.hmac_verify::<Sha256>(content, signature),
             Algorithm::HS384 => self.hmac_verify::<Sha384>(content, signature),
@@ -141,6 +369,45 @@ impl VerifyFromKey for HMACKey {
     }
 }
 
+impl SignStream for HMACKey {
+    fn sign_stream_hasher(&self, alg: Algorithm) -> Result<StreamHasher, Error> {
+        if let Err(error) = self.check_key_length(alg) {
+            return Err(error);
+        }
+
+        StreamHasher::hmac(self.key.as_slice(), alg)
+    }
+
+    fn sign_stream(&self, hasher: StreamHasher, _alg: Algorithm) -> Result<String, Error> {
+        match hasher.finalize_mac() {
+            Ok(val) => Ok(base64_url::encode(&val)),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl VerifyStream for HMACKey {
+    fn verify_stream_hasher(&self, alg: Algorithm) -> Result<StreamHasher, Error> {
+        if let Err(error) = self.check_key_length(alg) {
+            return Err(error);
+        }
+
+        StreamHasher::hmac(self.key.as_slice(), alg)
+    }
+
+    fn verify_stream(
+        &self,
+        hasher: StreamHasher,
+        signature: String,
+        _alg: Algorithm,
+    ) -> Result<bool, Error> {
+        match decode_base64_signature(signature.as_str()) {
+            Ok(val) => hasher.verify_mac(val.as_slice()),
+            Err(error) => Err(error),
+        }
+    }
+}
+
 /// Sign the content with the HMAC pass phrase
 #[cfg(not(feature = "wasm"))]
 pub fn sign_hmac(message: String, key: impl SignFromKey, alg: Algorithm) -> Result<String, Error> {
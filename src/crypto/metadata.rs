@@ -0,0 +1,152 @@
+use crate::{
+    algorithms::Algorithm,
+    crypto::{policy::KeyPolicy, SignFromKey, VerifyFromKey},
+};
+use chrono::Utc;
+use fi_common::error::Error;
+use serde::{Deserialize, Serialize};
+
+/// Operations a key can be used for. Serialized like the JWK <b>key_ops</b> values.
+#[derive(PartialEq, Eq, Serialize, Deserialize, Copy, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyOperation {
+    /// Create signatures
+    Sign,
+    /// Verify signatures
+    Verify,
+}
+
+/// Key ID, intended algorithm, allowed operations and validity window of a key
+#[derive(PartialEq, Eq, Serialize, Deserialize, Clone)]
+pub struct KeyMetadata {
+    /// Key ID, used as the <b>kid</b> of JWT headers
+    pub kid: String,
+    /// The only algorithm the key may be used with
+    pub alg: Algorithm,
+    /// Allowed operations
+    pub ops: Vec<KeyOperation>,
+    /// Unix timestamp in seconds before which the key can't be used
+    pub not_before: Option<i64>,
+    /// Unix timestamp in seconds after which the key can't be used
+    pub not_after: Option<i64>,
+}
+
+impl KeyMetadata {
+    /// Create <b>KeyMetadata</b> without a validity window
+    pub fn new(kid: String, alg: Algorithm, ops: Vec<KeyOperation>) -> KeyMetadata {
+        KeyMetadata {
+            kid,
+            alg,
+            ops,
+            not_before: None,
+            not_after: None,
+        }
+    }
+
+    /// Check if the operation with the algorithm is allowed right now
+    pub fn check(&self, op: KeyOperation, alg: Algorithm) -> Result<(), Error> {
+        if self.alg != alg {
+            fi_common::logger::error(
+                format!(
+                    "Key {} is restricted to {}, {} was requested",
+                    self.kid,
+                    self.alg.to_str(),
+                    alg.to_str()
+                )
+                .as_str(),
+            );
+            return Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH));
+        }
+
+        if !self.ops.contains(&op) {
+            return Err(Error::new(crate::errors::KEY_OPERATION_NOT_ALLOWED));
+        }
+
+        let now = Utc::now().timestamp();
+
+        if let Some(not_before) = self.not_before {
+            if now < not_before {
+                return Err(Error::new(crate::errors::KEY_NOT_YET_VALID));
+            }
+        }
+
+        if let Some(not_after) = self.not_after {
+            if now > not_after {
+                return Err(Error::new(crate::errors::KEY_EXPIRED));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Key with [`KeyMetadata`]. The metadata is enforced by [`crate::signer::sign`],
+/// [`crate::verifier::verify`] and [`crate::jwt::JWT::sign`], which also sets the
+/// <b>kid</b> of the JWT header.
+pub struct ManagedKey<K> {
+    key: K,
+    metadata: KeyMetadata,
+}
+
+impl<K> ManagedKey<K> {
+    /// Wrap the key with its metadata
+    pub fn new(key: K, metadata: KeyMetadata) -> ManagedKey<K> {
+        ManagedKey { key, metadata }
+    }
+
+    /// Key ID
+    pub fn kid(&self) -> &str {
+        self.metadata.kid.as_str()
+    }
+
+    /// Metadata of the key
+    pub fn metadata(&self) -> &KeyMetadata {
+        &self.metadata
+    }
+
+    /// Wrapped key
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+}
+
+impl<K: SignFromKey> SignFromKey for ManagedKey<K> {
+    fn sign_bytes(&self, content: &[u8], alg: Algorithm) -> Result<Vec<u8>, Error> {
+        if let Err(error) = self.metadata.check(KeyOperation::Sign, alg) {
+            return Err(error);
+        }
+
+        self.key.sign_bytes(content, alg)
+    }
+
+    fn check_key_policy(&self, policy: &KeyPolicy) -> Result<(), Error> {
+        self.key.check_key_policy(policy)
+    }
+
+    fn key_id(&self) -> Option<String> {
+        Some(self.metadata.kid.clone())
+    }
+}
+
+impl<K: VerifyFromKey> VerifyFromKey for ManagedKey<K> {
+    fn verify_bytes(
+        &self,
+        content: &[u8],
+        signature: &[u8],
+        alg: Algorithm,
+    ) -> Result<bool, Error> {
+        if let Err(error) = self.metadata.check(KeyOperation::Verify, alg) {
+            return Err(error);
+        }
+
+        self.key.verify_bytes(content, signature, alg)
+    }
+
+    fn check_key_policy(&self, policy: &KeyPolicy) -> Result<(), Error> {
+        self.key.check_key_policy(policy)
+    }
+
+    fn key_id(&self) -> Option<String> {
+        Some(self.metadata.kid.clone())
+    }
+}
@@ -0,0 +1,1019 @@
+use crate::algorithms::Algorithm;
+use elliptic_curve::pkcs8::{
+    der::{
+        asn1::{BitStringRef, OctetStringRef},
+        pem, Decode, Document, Encode, Reader, SliceReader,
+    },
+    spki::{AlgorithmIdentifierRef, ObjectIdentifier, SubjectPublicKeyInfoRef},
+    LineEnding, PrivateKeyInfo, SecretDocument,
+};
+use fi_common::error::Error;
+#[cfg(feature = "wasm")]
+use js_sys::{Object, Uint8Array};
+use ml_dsa::{
+    EncodedSignature, EncodedSigningKey, EncodedVerifyingKey, KeyGen, MlDsa44, MlDsa65, MlDsa87,
+    MlDsaParams, Signature, SigningKey, VerifyingKey, B32,
+};
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::wasm_bindgen;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::JsValue;
+use zeroize::Zeroizing;
+
+use super::{SignFromKey, VerifyFromKey};
+
+const OID_ML_DSA_44: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.3.17");
+const OID_ML_DSA_65: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.3.18");
+const OID_ML_DSA_87: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.3.19");
+
+/// Length of the ML-DSA private key seed
+pub const SEED_LENGTH: usize = 32;
+
+/// Maximum length of the ML-DSA context string
+pub const MAX_CONTEXT_LENGTH: usize = 255;
+
+/// ML-DSA parameter sets of FIPS 204
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Serialize, Deserialize, Copy, Clone, Debug)]
+pub enum MlDsaParameterSet {
+    /// ML-DSA-44, NIST security category 2
+    #[serde(rename = "ML-DSA-44")]
+    MlDsa44,
+    /// ML-DSA-65, NIST security category 3
+    #[serde(rename = "ML-DSA-65")]
+    MlDsa65,
+    /// ML-DSA-87, NIST security category 5
+    #[serde(rename = "ML-DSA-87")]
+    MlDsa87,
+}
+
+impl MlDsaParameterSet {
+    /// [`Algorithm`] that signs with this parameter set
+    pub fn algorithm(&self) -> Algorithm {
+        match self {
+            MlDsaParameterSet::MlDsa44 => Algorithm::MLDSA44,
+            MlDsaParameterSet::MlDsa65 => Algorithm::MLDSA65,
+            MlDsaParameterSet::MlDsa87 => Algorithm::MLDSA87,
+        }
+    }
+
+    /// Parameter set of an ML-DSA [`Algorithm`]
+    pub fn from_algorithm(alg: Algorithm) -> Option<MlDsaParameterSet> {
+        match alg {
+            Algorithm::MLDSA44 => Some(MlDsaParameterSet::MlDsa44),
+            Algorithm::MLDSA65 => Some(MlDsaParameterSet::MlDsa65),
+            Algorithm::MLDSA87 => Some(MlDsaParameterSet::MlDsa87),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn oid(&self) -> ObjectIdentifier {
+        match self {
+            MlDsaParameterSet::MlDsa44 => OID_ML_DSA_44,
+            MlDsaParameterSet::MlDsa65 => OID_ML_DSA_65,
+            MlDsaParameterSet::MlDsa87 => OID_ML_DSA_87,
+        }
+    }
+
+    pub(crate) fn from_oid(oid: ObjectIdentifier) -> Option<MlDsaParameterSet> {
+        if oid == OID_ML_DSA_44 {
+            Some(MlDsaParameterSet::MlDsa44)
+        } else if oid == OID_ML_DSA_65 {
+            Some(MlDsaParameterSet::MlDsa65)
+        } else if oid == OID_ML_DSA_87 {
+            Some(MlDsaParameterSet::MlDsa87)
+        } else {
+            None
+        }
+    }
+
+    /// Length of the encoded public key
+    pub fn public_key_length(&self) -> usize {
+        match self {
+            MlDsaParameterSet::MlDsa44 => 1312,
+            MlDsaParameterSet::MlDsa65 => 1952,
+            MlDsaParameterSet::MlDsa87 => 2592,
+        }
+    }
+
+    /// Length of the expanded private key
+    pub fn private_key_length(&self) -> usize {
+        match self {
+            MlDsaParameterSet::MlDsa44 => 2560,
+            MlDsaParameterSet::MlDsa65 => 4032,
+            MlDsaParameterSet::MlDsa87 => 4896,
+        }
+    }
+
+    /// Length of the signature
+    pub fn signature_length(&self) -> usize {
+        match self {
+            MlDsaParameterSet::MlDsa44 => 2420,
+            MlDsaParameterSet::MlDsa65 => 3309,
+            MlDsaParameterSet::MlDsa87 => 4627,
+        }
+    }
+
+    fn from_public_key_length(length: usize) -> Option<MlDsaParameterSet> {
+        [
+            MlDsaParameterSet::MlDsa44,
+            MlDsaParameterSet::MlDsa65,
+            MlDsaParameterSet::MlDsa87,
+        ]
+        .into_iter()
+        .find(|parameter_set| parameter_set.public_key_length() == length)
+    }
+}
+
+#[derive(Clone)]
+enum PrivateKey {
+    MlDsa44(Box<SigningKey<MlDsa44>>),
+    MlDsa65(Box<SigningKey<MlDsa65>>),
+    MlDsa87(Box<SigningKey<MlDsa87>>),
+}
+
+#[derive(Clone)]
+enum PublicKey {
+    MlDsa44(Box<VerifyingKey<MlDsa44>>),
+    MlDsa65(Box<VerifyingKey<MlDsa65>>),
+    MlDsa87(Box<VerifyingKey<MlDsa87>>),
+}
+
+/// Parsed ML-DSA private key. Keys created from a seed also keep the seed and the public key.
+#[derive(Clone)]
+struct ParsedPrivateKey {
+    key: PrivateKey,
+    seed: Option<Zeroizing<Vec<u8>>>,
+    public_key: Option<PublicKey>,
+}
+
+fn expand_seed<P: MlDsaParams>(seed: &B32) -> (Box<SigningKey<P>>, Box<VerifyingKey<P>>) {
+    let key_pair = P::key_gen_internal(seed);
+    (
+        Box::new(key_pair.signing_key().clone()),
+        Box::new(key_pair.verifying_key().clone()),
+    )
+}
+
+fn decode_expanded_key<P: MlDsaParams>(bytes: &[u8]) -> Result<Box<SigningKey<P>>, Error> {
+    match EncodedSigningKey::<P>::try_from(bytes) {
+        Ok(val) => Ok(Box::new(SigningKey::<P>::decode(&val))),
+        Err(_) => Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+    }
+}
+
+fn decode_public_key<P: MlDsaParams>(bytes: &[u8]) -> Result<Box<VerifyingKey<P>>, Error> {
+    match EncodedVerifyingKey::<P>::try_from(bytes) {
+        Ok(val) => Ok(Box::new(VerifyingKey::<P>::decode(&val))),
+        Err(_) => Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+    }
+}
+
+fn sign_with<P: MlDsaParams>(
+    key: &SigningKey<P>,
+    message: &[u8],
+    context: &[u8],
+    deterministic: bool,
+) -> Result<Vec<u8>, Error> {
+    let signature = match deterministic {
+        true => key.sign_deterministic(message, context),
+        false => key.sign_randomized(message, context, &mut OsRng),
+    };
+
+    match signature {
+        Ok(val) => Ok(val.encode().to_vec()),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::SIGNING_FAILED))
+        }
+    }
+}
+
+fn verify_with<P: MlDsaParams>(
+    key: &VerifyingKey<P>,
+    message: &[u8],
+    context: &[u8],
+    signature: &[u8],
+) -> Result<bool, Error> {
+    let encoded = match EncodedSignature::<P>::try_from(signature) {
+        Ok(val) => val,
+        Err(_) => return Err(Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED)),
+    };
+
+    match Signature::<P>::decode(&encoded) {
+        Some(val) => Ok(key.verify_with_context(message, context, &val)),
+        None => Err(Error::new(crate::errors::SIGNATURE_IDENTIFICATION_FAILED)),
+    }
+}
+
+impl PrivateKey {
+    fn parameter_set(&self) -> MlDsaParameterSet {
+        match self {
+            PrivateKey::MlDsa44(_) => MlDsaParameterSet::MlDsa44,
+            PrivateKey::MlDsa65(_) => MlDsaParameterSet::MlDsa65,
+            PrivateKey::MlDsa87(_) => MlDsaParameterSet::MlDsa87,
+        }
+    }
+
+    fn encode(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(match self {
+            PrivateKey::MlDsa44(key) => key.encode().to_vec(),
+            PrivateKey::MlDsa65(key) => key.encode().to_vec(),
+            PrivateKey::MlDsa87(key) => key.encode().to_vec(),
+        })
+    }
+
+    fn sign(&self, message: &[u8], context: &[u8], deterministic: bool) -> Result<Vec<u8>, Error> {
+        match self {
+            PrivateKey::MlDsa44(key) => sign_with(key, message, context, deterministic),
+            PrivateKey::MlDsa65(key) => sign_with(key, message, context, deterministic),
+            PrivateKey::MlDsa87(key) => sign_with(key, message, context, deterministic),
+        }
+    }
+}
+
+impl PublicKey {
+    fn parameter_set(&self) -> MlDsaParameterSet {
+        match self {
+            PublicKey::MlDsa44(_) => MlDsaParameterSet::MlDsa44,
+            PublicKey::MlDsa65(_) => MlDsaParameterSet::MlDsa65,
+            PublicKey::MlDsa87(_) => MlDsaParameterSet::MlDsa87,
+        }
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        match self {
+            PublicKey::MlDsa44(key) => key.encode().to_vec(),
+            PublicKey::MlDsa65(key) => key.encode().to_vec(),
+            PublicKey::MlDsa87(key) => key.encode().to_vec(),
+        }
+    }
+
+    fn verify(&self, message: &[u8], context: &[u8], signature: &[u8]) -> Result<bool, Error> {
+        match self {
+            PublicKey::MlDsa44(key) => verify_with(key, message, context, signature),
+            PublicKey::MlDsa65(key) => verify_with(key, message, context, signature),
+            PublicKey::MlDsa87(key) => verify_with(key, message, context, signature),
+        }
+    }
+}
+
+fn get_private_key_from_seed(
+    parameter_set: MlDsaParameterSet,
+    seed: &[u8],
+) -> Result<ParsedPrivateKey, Error> {
+    let seed_array = match B32::try_from(seed) {
+        Ok(val) => val,
+        Err(_) => return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+    };
+
+    let (key, public_key) = match parameter_set {
+        MlDsaParameterSet::MlDsa44 => {
+            let (key, public_key) = expand_seed::<MlDsa44>(&seed_array);
+            (PrivateKey::MlDsa44(key), PublicKey::MlDsa44(public_key))
+        }
+        MlDsaParameterSet::MlDsa65 => {
+            let (key, public_key) = expand_seed::<MlDsa65>(&seed_array);
+            (PrivateKey::MlDsa65(key), PublicKey::MlDsa65(public_key))
+        }
+        MlDsaParameterSet::MlDsa87 => {
+            let (key, public_key) = expand_seed::<MlDsa87>(&seed_array);
+            (PrivateKey::MlDsa87(key), PublicKey::MlDsa87(public_key))
+        }
+    };
+
+    Ok(ParsedPrivateKey {
+        key,
+        seed: Some(Zeroizing::new(seed.to_vec())),
+        public_key: Some(public_key),
+    })
+}
+
+fn get_private_key_from_expanded(
+    parameter_set: MlDsaParameterSet,
+    bytes: &[u8],
+) -> Result<ParsedPrivateKey, Error> {
+    let key = match parameter_set {
+        MlDsaParameterSet::MlDsa44 => {
+            decode_expanded_key::<MlDsa44>(bytes).map(PrivateKey::MlDsa44)
+        }
+        MlDsaParameterSet::MlDsa65 => {
+            decode_expanded_key::<MlDsa65>(bytes).map(PrivateKey::MlDsa65)
+        }
+        MlDsaParameterSet::MlDsa87 => {
+            decode_expanded_key::<MlDsa87>(bytes).map(PrivateKey::MlDsa87)
+        }
+    };
+
+    match key {
+        Ok(val) => Ok(ParsedPrivateKey {
+            key: val,
+            seed: None,
+            public_key: None,
+        }),
+        Err(error) => Err(error),
+    }
+}
+
+fn get_private_key_from_bytes(
+    parameter_set: MlDsaParameterSet,
+    bytes: &[u8],
+) -> Result<ParsedPrivateKey, Error> {
+    match bytes.len() {
+        SEED_LENGTH => get_private_key_from_seed(parameter_set, bytes),
+        _ => get_private_key_from_expanded(parameter_set, bytes),
+    }
+}
+
+/// Private key of the <b>pkcs8</b> ML-DSA key formats: the <b>seed</b>, <b>expandedKey</b> and
+/// <b>both</b> choices of the IETF LAMPS profile, and the bare 32 bytes seed of earlier drafts
+fn get_private_key_from_der(der: &[u8]) -> Result<ParsedPrivateKey, Error> {
+    let key_info = match PrivateKeyInfo::try_from(der) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+        }
+    };
+
+    let parameter_set = match MlDsaParameterSet::from_oid(key_info.algorithm.oid) {
+        Some(val) => val,
+        None => return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+    };
+
+    let private_key = key_info.private_key;
+    match private_key.first() {
+        _ if private_key.len() == SEED_LENGTH => {
+            get_private_key_from_seed(parameter_set, private_key)
+        }
+        Some(0x80)
+            if private_key.len() == SEED_LENGTH + 2 && private_key[1] as usize == SEED_LENGTH =>
+        {
+            get_private_key_from_seed(parameter_set, &private_key[2..])
+        }
+        Some(0x04) => match OctetStringRef::from_der(private_key) {
+            Ok(val) => get_private_key_from_expanded(parameter_set, val.as_bytes()),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR))
+            }
+        },
+        Some(0x30) => {
+            let both = SliceReader::new(private_key).and_then(|mut reader| {
+                reader.sequence(|reader| {
+                    let seed = OctetStringRef::decode(reader)?;
+                    let expanded = OctetStringRef::decode(reader)?;
+                    Ok((seed.as_bytes().to_vec(), expanded.as_bytes().to_vec()))
+                })
+            });
+
+            let (seed, expanded) = match both {
+                Ok((seed, expanded)) => (Zeroizing::new(seed), Zeroizing::new(expanded)),
+                Err(error) => {
+                    fi_common::logger::error(error.to_string().as_str());
+                    return Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR));
+                }
+            };
+
+            let key = match get_private_key_from_seed(parameter_set, seed.as_slice()) {
+                Ok(val) => val,
+                Err(error) => return Err(error),
+            };
+
+            // Both encodings have to describe the same key
+            match key.key.encode().as_slice() == expanded.as_slice() {
+                true => Ok(key),
+                false => Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+            }
+        }
+        _ => Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+    }
+}
+
+fn get_private_key_from_pem(key_str: &str) -> Result<ParsedPrivateKey, Error> {
+    match SecretDocument::from_pem(key_str) {
+        Ok((_, document)) => get_private_key_from_der(document.as_bytes()),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR))
+        }
+    }
+}
+
+/// <b>pkcs8</b> DER of the private key, the <b>seed</b> choice when the seed is known and the
+/// <b>expandedKey</b> choice otherwise
+fn private_key_to_der(key: &ParsedPrivateKey) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let private_key = match &key.seed {
+        Some(seed) => {
+            let mut encoded = Zeroizing::new(vec![0x80, SEED_LENGTH as u8]);
+            encoded.extend_from_slice(seed.as_slice());
+            encoded
+        }
+        None => {
+            let expanded = key.key.encode();
+            let octet_string =
+                OctetStringRef::new(expanded.as_slice()).and_then(|val| val.to_der());
+            match octet_string {
+                Ok(val) => Zeroizing::new(val),
+                Err(error) => {
+                    fi_common::logger::error(error.to_string().as_str());
+                    return Err(Error::new(crate::errors::ENCODING_ERROR));
+                }
+            }
+        }
+    };
+
+    let key_info = PrivateKeyInfo::new(
+        AlgorithmIdentifierRef {
+            oid: key.key.parameter_set().oid(),
+            parameters: None,
+        },
+        private_key.as_slice(),
+    );
+
+    match key_info.to_der() {
+        Ok(val) => Ok(Zeroizing::new(val)),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::ENCODING_ERROR))
+        }
+    }
+}
+
+fn get_public_key_from_bytes(bytes: &[u8]) -> Result<PublicKey, Error> {
+    let parameter_set = match MlDsaParameterSet::from_public_key_length(bytes.len()) {
+        Some(val) => val,
+        None => return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+    };
+
+    match parameter_set {
+        MlDsaParameterSet::MlDsa44 => decode_public_key::<MlDsa44>(bytes).map(PublicKey::MlDsa44),
+        MlDsaParameterSet::MlDsa65 => decode_public_key::<MlDsa65>(bytes).map(PublicKey::MlDsa65),
+        MlDsaParameterSet::MlDsa87 => decode_public_key::<MlDsa87>(bytes).map(PublicKey::MlDsa87),
+    }
+}
+
+fn get_public_key_from_der(der: &[u8]) -> Result<PublicKey, Error> {
+    let key_info = match SubjectPublicKeyInfoRef::try_from(der) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR));
+        }
+    };
+
+    let parameter_set = match MlDsaParameterSet::from_oid(key_info.algorithm.oid) {
+        Some(val) => val,
+        None => return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+    };
+
+    let bytes = match key_info.subject_public_key.as_bytes() {
+        Some(val) => val,
+        None => return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+    };
+
+    match get_public_key_from_bytes(bytes) {
+        Ok(val) if val.parameter_set() == parameter_set => Ok(val),
+        Ok(_) => Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+        Err(error) => Err(error),
+    }
+}
+
+fn get_public_key_from_pem(key_str: &str) -> Result<PublicKey, Error> {
+    match Document::from_pem(key_str) {
+        Ok((_, document)) => get_public_key_from_der(document.as_bytes()),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR))
+        }
+    }
+}
+
+fn public_key_to_der(key: &PublicKey) -> Result<Vec<u8>, Error> {
+    let encoded = key.encode();
+    let subject_public_key = match BitStringRef::from_bytes(encoded.as_slice()) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::ENCODING_ERROR));
+        }
+    };
+
+    let key_info = SubjectPublicKeyInfoRef {
+        algorithm: AlgorithmIdentifierRef {
+            oid: key.parameter_set().oid(),
+            parameters: None,
+        },
+        subject_public_key,
+    };
+
+    match key_info.to_der() {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::ENCODING_ERROR))
+        }
+    }
+}
+
+fn to_pem(label: &str, der: &[u8]) -> Result<String, Error> {
+    match pem::encode_string(label, LineEnding::LF, der) {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::ENCODING_ERROR))
+        }
+    }
+}
+
+fn check_context(context: &[u8]) -> Result<(), Error> {
+    match context.len() > MAX_CONTEXT_LENGTH {
+        true => Err(Error::new(crate::errors::MLDSA_CONTEXT_TOO_LONG)),
+        false => Ok(()),
+    }
+}
+
+fn check_algorithm(parameter_set: MlDsaParameterSet, alg: Algorithm) -> Result<(), Error> {
+    match parameter_set.algorithm() == alg {
+        true => Ok(()),
+        false => Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH)),
+    }
+}
+
+/// Signing key for [`crate::algorithms::Algorithm::MLDSA44`],
+/// [`crate::algorithms::Algorithm::MLDSA65`] and [`crate::algorithms::Algorithm::MLDSA87`]
+#[wasm_bindgen]
+pub struct MlDsaSigningKey {
+    #[cfg(not(feature = "wasm"))]
+    key: ParsedPrivateKey,
+
+    #[cfg(feature = "wasm")]
+    key_str: Option<Zeroizing<String>>,
+    #[cfg(feature = "wasm")]
+    key_bytes: Option<Zeroizing<Vec<u8>>>,
+    #[cfg(feature = "wasm")]
+    parameter_set: MlDsaParameterSet,
+}
+
+impl SignFromKey for MlDsaSigningKey {
+    fn sign_bytes(&self, content: &[u8], alg: Algorithm) -> Result<Vec<u8>, Error> {
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if let Err(error) = check_algorithm(key.key.parameter_set(), alg) {
+            return Err(error);
+        }
+
+        key.key.sign(content, &[], false)
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+impl MlDsaSigningKey {
+    /// Create signing key from pem formatted private key. <b>pkcs8</b> only, with the
+    /// <b>seed</b>, <b>expandedKey</b> or <b>both</b> private key formats.
+    pub fn from_pem(key_str: &str) -> Result<MlDsaSigningKey, Error> {
+        match get_private_key_from_pem(key_str) {
+            Ok(val) => Ok(MlDsaSigningKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create signing key from a <b>pkcs8</b> DER encoded private key.
+    pub fn from_der(bytes: &[u8]) -> Result<MlDsaSigningKey, Error> {
+        match get_private_key_from_der(bytes) {
+            Ok(val) => Ok(MlDsaSigningKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create signing key from the 32 bytes seed or the expanded private key.
+    pub fn from_bytes(
+        parameter_set: MlDsaParameterSet,
+        bytes: &[u8],
+    ) -> Result<MlDsaSigningKey, Error> {
+        match get_private_key_from_bytes(parameter_set, bytes) {
+            Ok(val) => Ok(MlDsaSigningKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Parameter set of the key
+    pub fn parameter_set(&self) -> MlDsaParameterSet {
+        self.key.key.parameter_set()
+    }
+
+    fn get_key(&self) -> Result<&ParsedPrivateKey, Error> {
+        Ok(&self.key)
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl MlDsaSigningKey {
+    /// Create signing key from pem formatted private key. <b>pkcs8</b> only, with the
+    /// <b>seed</b>, <b>expandedKey</b> or <b>both</b> private key formats.
+    #[wasm_bindgen(js_name = "fromPem")]
+    pub fn from_pem(key_str: &str) -> MlDsaSigningKey {
+        MlDsaSigningKey {
+            key_str: Some(Zeroizing::new(String::from(key_str))),
+            key_bytes: None,
+            parameter_set: MlDsaParameterSet::MlDsa44,
+        }
+    }
+
+    /// Create signing key from the 32 bytes seed or the expanded private key.
+    #[wasm_bindgen(js_name = "fromBytes")]
+    pub fn from_bytes(parameter_set: MlDsaParameterSet, bytes: &[u8]) -> MlDsaSigningKey {
+        MlDsaSigningKey {
+            key_str: None,
+            key_bytes: Some(Zeroizing::new(bytes.to_vec())),
+            parameter_set,
+        }
+    }
+
+    /// Parameter set of the key
+    #[wasm_bindgen(js_name = "parameterSet")]
+    pub fn parameter_set(&self) -> Result<MlDsaParameterSet, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.key.parameter_set()),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn get_key(&self) -> Result<ParsedPrivateKey, Error> {
+        match (&self.key_str, &self.key_bytes) {
+            (Some(key_str), _) => get_private_key_from_pem(key_str.as_str()),
+            (None, Some(key_bytes)) => {
+                get_private_key_from_bytes(self.parameter_set, key_bytes.as_slice())
+            }
+            (None, None) => Err(Error::new(crate::errors::PRIVATE_KEY_IDENTIFICATION_ERROR)),
+        }
+    }
+
+    /// Key from a <b>{pem: ...}</b> object or the raw key bytes of the parameter set of the
+    /// algorithm
+    pub fn from_js_object(value: Object, alg: Algorithm) -> Result<MlDsaSigningKey, Error> {
+        let pem_field = JsValue::from_str("pem");
+
+        if value.has_own_property(&pem_field) {
+            let pem = match js_sys::Reflect::get(&value, &pem_field) {
+                Ok(val) => match val.as_string() {
+                    Some(v) => Zeroizing::new(v),
+                    None => return Err(Error::new(crate::errors::MISSING_FIELD)),
+                },
+                Err(error) => {
+                    fi_common::logger::error(error.as_string().unwrap().as_str());
+                    return Err(Error::new(crate::errors::MISSING_FIELD));
+                }
+            };
+
+            Ok(MlDsaSigningKey::from_pem(pem.as_str()))
+        } else if value.is_array() {
+            let parameter_set = match MlDsaParameterSet::from_algorithm(alg) {
+                Some(val) => val,
+                None => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+            };
+            let arr = Zeroizing::new(Uint8Array::new(&value).to_vec());
+
+            Ok(MlDsaSigningKey::from_bytes(parameter_set, arr.as_slice()))
+        } else {
+            Err(Error::new(crate::errors::MISSING_FIELD))
+        }
+    }
+}
+
+impl MlDsaSigningKey {
+    pub(crate) fn key_parameter_set(&self) -> Result<MlDsaParameterSet, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.key.parameter_set()),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl MlDsaSigningKey {
+    /// Create a signing key from a new random seed
+    pub fn generate(parameter_set: MlDsaParameterSet) -> Result<MlDsaSigningKey, Error> {
+        let mut seed = Zeroizing::new([0u8; SEED_LENGTH]);
+        OsRng.fill_bytes(seed.as_mut_slice());
+
+        #[cfg(not(feature = "wasm"))]
+        return MlDsaSigningKey::from_bytes(parameter_set, seed.as_slice());
+
+        #[cfg(feature = "wasm")]
+        return Ok(MlDsaSigningKey::from_bytes(parameter_set, seed.as_slice()));
+    }
+
+    /// Raw private key, the 32 bytes seed when the key was created from a seed and the expanded
+    /// private key otherwise
+    #[wasm_bindgen(js_name = "toBytes")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        match self.get_key() {
+            Ok(val) => match &val.seed {
+                Some(seed) => Ok(seed.to_vec()),
+                None => Ok(val.key.encode().to_vec()),
+            },
+            Err(error) => Err(error),
+        }
+    }
+
+    /// <b>pkcs8</b> pem formatted private key
+    #[wasm_bindgen(js_name = "toPem")]
+    pub fn to_pem(&self) -> Result<String, Error> {
+        #[cfg(not(feature = "wasm"))]
+        let key = &self.key;
+
+        #[cfg(feature = "wasm")]
+        let key = &match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match private_key_to_der(key) {
+            Ok(val) => to_pem("PRIVATE KEY", val.as_slice()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Verifying key of this key. Only available for keys created from a seed.
+    #[wasm_bindgen(js_name = "verifyingKey")]
+    pub fn verifying_key(&self) -> Result<MlDsaVerifyingKey, Error> {
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match &key.public_key {
+            Some(val) => Ok(MlDsaVerifyingKey::from_key(val.clone())),
+            None => {
+                fi_common::logger::error("The expanded ML-DSA private key has no public key");
+                Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR))
+            }
+        }
+    }
+
+    /// Hedged ML-DSA signature of the message with a context string of up to 255 bytes
+    #[wasm_bindgen(js_name = "signWithContext")]
+    pub fn sign_with_context(&self, message: &[u8], context: &[u8]) -> Result<Vec<u8>, Error> {
+        if let Err(error) = check_context(context) {
+            return Err(error);
+        }
+
+        match self.get_key() {
+            Ok(val) => val.key.sign(message, context, false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Deterministic ML-DSA signature of the message with a context string of up to 255 bytes
+    #[wasm_bindgen(js_name = "signDeterministic")]
+    pub fn sign_deterministic(&self, message: &[u8], context: &[u8]) -> Result<Vec<u8>, Error> {
+        if let Err(error) = check_context(context) {
+            return Err(error);
+        }
+
+        match self.get_key() {
+            Ok(val) => val.key.sign(message, context, true),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Verifying key for [`crate::algorithms::Algorithm::MLDSA44`],
+/// [`crate::algorithms::Algorithm::MLDSA65`] and [`crate::algorithms::Algorithm::MLDSA87`]
+#[wasm_bindgen]
+pub struct MlDsaVerifyingKey {
+    #[cfg(not(feature = "wasm"))]
+    key: PublicKey,
+
+    #[cfg(feature = "wasm")]
+    key_str: Option<String>,
+    #[cfg(feature = "wasm")]
+    key_bytes: Option<Vec<u8>>,
+}
+
+impl VerifyFromKey for MlDsaVerifyingKey {
+    fn verify_bytes(
+        &self,
+        content: &[u8],
+        signature: &[u8],
+        alg: Algorithm,
+    ) -> Result<bool, Error> {
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if let Err(error) = check_algorithm(key.parameter_set(), alg) {
+            return Err(error);
+        }
+
+        key.verify(content, &[], signature)
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+impl MlDsaVerifyingKey {
+    /// Create verifying key from pem formatted public key. <b>spki</b> only.
+    pub fn from_pem(key_str: &str) -> Result<MlDsaVerifyingKey, Error> {
+        match get_public_key_from_pem(key_str) {
+            Ok(val) => Ok(MlDsaVerifyingKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create verifying key from a <b>spki</b> DER encoded public key.
+    pub fn from_der(bytes: &[u8]) -> Result<MlDsaVerifyingKey, Error> {
+        match get_public_key_from_der(bytes) {
+            Ok(val) => Ok(MlDsaVerifyingKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Create verifying key from the raw public key. The parameter set follows the key length.
+    pub fn from_bytes(bytes: &[u8]) -> Result<MlDsaVerifyingKey, Error> {
+        match get_public_key_from_bytes(bytes) {
+            Ok(val) => Ok(MlDsaVerifyingKey { key: val }),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn from_key(key: PublicKey) -> MlDsaVerifyingKey {
+        MlDsaVerifyingKey { key }
+    }
+
+    /// Parameter set of the key
+    pub fn parameter_set(&self) -> MlDsaParameterSet {
+        self.key.parameter_set()
+    }
+
+    fn get_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.key.clone())
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl MlDsaVerifyingKey {
+    /// Create verifying key from pem formatted public key. <b>spki</b> only.
+    #[wasm_bindgen(js_name = "fromPem")]
+    pub fn from_pem(key_str: &str) -> MlDsaVerifyingKey {
+        MlDsaVerifyingKey {
+            key_str: Some(String::from(key_str)),
+            key_bytes: None,
+        }
+    }
+
+    /// Create verifying key from the raw public key. The parameter set follows the key length.
+    #[wasm_bindgen(js_name = "fromBytes")]
+    pub fn from_bytes(bytes: &[u8]) -> MlDsaVerifyingKey {
+        MlDsaVerifyingKey {
+            key_str: None,
+            key_bytes: Some(bytes.to_vec()),
+        }
+    }
+
+    fn from_key(key: PublicKey) -> MlDsaVerifyingKey {
+        MlDsaVerifyingKey::from_bytes(key.encode().as_slice())
+    }
+
+    /// Parameter set of the key
+    #[wasm_bindgen(js_name = "parameterSet")]
+    pub fn parameter_set(&self) -> Result<MlDsaParameterSet, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.parameter_set()),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn get_key(&self) -> Result<PublicKey, Error> {
+        match (&self.key_str, &self.key_bytes) {
+            (Some(key_str), _) => get_public_key_from_pem(key_str.as_str()),
+            (None, Some(key_bytes)) => get_public_key_from_bytes(key_bytes.as_slice()),
+            (None, None) => Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+        }
+    }
+
+    pub fn from_js_object(value: Object) -> Result<MlDsaVerifyingKey, Error> {
+        let pem_field = JsValue::from_str("pem");
+
+        if value.has_own_property(&pem_field) {
+            let pem = match js_sys::Reflect::get(&value, &pem_field) {
+                Ok(val) => match val.as_string() {
+                    Some(v) => v,
+                    None => return Err(Error::new(crate::errors::MISSING_FIELD)),
+                },
+                Err(error) => {
+                    fi_common::logger::error(error.as_string().unwrap().as_str());
+                    return Err(Error::new(crate::errors::MISSING_FIELD));
+                }
+            };
+
+            Ok(MlDsaVerifyingKey::from_pem(pem.as_str()))
+        } else if value.is_array() {
+            Ok(MlDsaVerifyingKey::from_bytes(
+                Uint8Array::new(&value).to_vec().as_slice(),
+            ))
+        } else {
+            Err(Error::new(crate::errors::MISSING_FIELD))
+        }
+    }
+}
+
+impl MlDsaVerifyingKey {
+    pub(crate) fn key_parameter_set(&self) -> Result<MlDsaParameterSet, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.parameter_set()),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl MlDsaVerifyingKey {
+    /// Raw public key
+    #[wasm_bindgen(js_name = "toBytes")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        match self.get_key() {
+            Ok(val) => Ok(val.encode()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// <b>spki</b> pem formatted public key
+    #[wasm_bindgen(js_name = "toPem")]
+    pub fn to_pem(&self) -> Result<String, Error> {
+        let key = match self.get_key() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match public_key_to_der(&key) {
+            Ok(val) => to_pem("PUBLIC KEY", val.as_slice()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Verify an ML-DSA signature of the message created with the context string
+    #[wasm_bindgen(js_name = "verifyWithContext")]
+    pub fn verify_with_context(
+        &self,
+        message: &[u8],
+        context: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, Error> {
+        if let Err(error) = check_context(context) {
+            return Err(error);
+        }
+
+        match self.get_key() {
+            Ok(val) => val.verify(message, context, signature),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Sign content with [`crate::algorithms::Algorithm::MLDSA44`],
+/// [`crate::algorithms::Algorithm::MLDSA65`] or [`crate::algorithms::Algorithm::MLDSA87`]
+#[cfg(not(feature = "wasm"))]
+pub fn sign_mldsa(message: String, key: impl SignFromKey, alg: Algorithm) -> Result<String, Error> {
+    key.sign(message, alg)
+}
+
+#[cfg(feature = "wasm")]
+pub fn sign_mldsa(message: String, key: Object, alg: Algorithm) -> Result<String, Error> {
+    match MlDsaSigningKey::from_js_object(key, alg) {
+        Ok(val) => val.sign(message, alg),
+        Err(error) => Err(error),
+    }
+}
+
+/// Verify signature using [`crate::algorithms::Algorithm::MLDSA44`],
+/// [`crate::algorithms::Algorithm::MLDSA65`] or [`crate::algorithms::Algorithm::MLDSA87`]
+#[cfg(not(feature = "wasm"))]
+pub fn verify_mldsa(
+    message: String,
+    signature: String,
+    key: impl VerifyFromKey,
+    alg: Algorithm,
+) -> Result<bool, Error> {
+    key.verify(message, signature, alg)
+}
+
+#[cfg(feature = "wasm")]
+pub fn verify_mldsa(
+    message: String,
+    signature: String,
+    key: Object,
+    alg: Algorithm,
+) -> Result<bool, Error> {
+    match MlDsaVerifyingKey::from_js_object(key) {
+        Ok(val) => val.verify(message, signature, alg),
+        Err(error) => Err(error),
+    }
+}
@@ -1,23 +1,196 @@
-use crate::algorithms::Algorithm;
+use crate::{algorithms::Algorithm, stream::StreamHasher};
 use fi_common::error::Error;
 
+use self::policy::KeyPolicy;
+
+/// Algorithm-agnostic signing and verifying keys
+#[cfg(not(feature = "wasm"))]
+pub mod any;
+/// BBS multi-message signatures and selective disclosure proofs
+#[cfg(not(feature = "wasm"))]
+pub mod bbs;
+/// BLS12-381 signing, verifying and signature aggregation
+pub mod bls;
+/// Composite ML-DSA and ECDSA or EdDSA signing and verifying
+pub mod composite;
 /// ECDSA based algorithms signing and verifying
 pub mod ecdsa;
+/// ed448 curve signing and verifying
+pub mod ed448;
 /// ed25519 algorithm signing and verifying
 pub mod eddsa;
 /// HMAC and verifying
 pub mod hmac;
+/// Key ID, algorithm, usage and validity metadata for keys
+#[cfg(not(feature = "wasm"))]
+pub mod metadata;
+/// ML-DSA (FIPS 204) signing and verifying
+pub mod mldsa;
+/// Key strength policy
+pub mod policy;
 /// RSA signing and verifying
 pub mod rsa;
 
 /// Common trait that distributes the <b>sign</b> function into [`crate::crypto::ecdsa`], [`crate::crypto::eddsa`], [`crate::crypto::hmac`], [`crate::crypto::rsa`]
 pub trait SignFromKey {
-    /// Sign the content with the provided algorithm using this key
-    fn sign(&self, content: String, alg: Algorithm) -> Result<String, Error>;
+    /// Sign the content with the provided algorithm using this key, the signature is base64url
+    /// encoded. Wraps [`SignFromKey::sign_bytes`].
+    fn sign(&self, content: String, alg: Algorithm) -> Result<String, Error> {
+        match self.sign_bytes(content.as_bytes(), alg) {
+            Ok(val) => Ok(base64_url::encode(&val)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Sign the content bytes with the provided algorithm using this key, returning the raw
+    /// signature bytes
+    fn sign_bytes(&self, content: &[u8], alg: Algorithm) -> Result<Vec<u8>, Error>;
+
+    /// Check this key against the key policy. Used by [`crate::signer::sign`] before signing.
+    fn check_key_policy(&self, _policy: &KeyPolicy) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Key ID of this key, if it has one
+    fn key_id(&self) -> Option<String> {
+        None
+    }
 }
 
 /// Common trait that distributes the <b>verify</b> function into [`crate::crypto::ecdsa`], [`crate::crypto::eddsa`], [`crate::crypto::hmac`], [`crate::crypto::rsa`]
 pub trait VerifyFromKey {
-    /// Verify the signature with the provided algorithm using this key
-    fn verify(&self, content: String, signature: String, alg: Algorithm) -> Result<bool, Error>;
+    /// Verify the base64url encoded signature with the provided algorithm using this key. Wraps
+    /// [`VerifyFromKey::verify_bytes`].
+    fn verify(&self, content: String, signature: String, alg: Algorithm) -> Result<bool, Error> {
+        match decode_base64_signature(signature.as_str()) {
+            Ok(val) => self.verify_bytes(content.as_bytes(), &val, alg),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Verify the raw signature bytes of the content bytes with the provided algorithm using
+    /// this key
+    fn verify_bytes(
+        &self,
+        content: &[u8],
+        signature: &[u8],
+        alg: Algorithm,
+    ) -> Result<bool, Error>;
+
+    /// Check this key against the key policy. Used by [`crate::verifier::verify`] before verifying.
+    fn check_key_policy(&self, _policy: &KeyPolicy) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Key ID of this key, if it has one
+    fn key_id(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Signing of a digest computed elsewhere, implemented by the RSA and ECDSA signing keys
+pub trait SignPrehashed {
+    /// Sign the digest of the content, created with the hash of the provided algorithm
+    fn sign_prehashed(&self, digest: &[u8], alg: Algorithm) -> Result<String, Error>;
+}
+
+/// Verifying of a signature over a digest computed elsewhere, implemented by the RSA and ECDSA
+/// verifying keys
+pub trait VerifyPrehashed: VerifyFromKey {
+    /// Verify the signature of the digest, created with the hash of the provided algorithm
+    fn verify_prehashed(
+        &self,
+        digest: &[u8],
+        signature: String,
+        alg: Algorithm,
+    ) -> Result<bool, Error>;
+}
+
+/// Decode a base64url encoded signature into its raw bytes
+pub(crate) fn decode_base64_signature(signature: &str) -> Result<Vec<u8>, Error> {
+    match base64_url::decode(signature) {
+        Ok(val) => Ok(val),
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            Err(Error::new(crate::errors::DECODING_ERROR))
+        }
+    }
+}
+
+/// Check that the digest is as long as the output of the hash of the algorithm
+pub(crate) fn check_digest_length(digest: &[u8], alg: Algorithm) -> Result<(), Error> {
+    match alg.digest_length() {
+        Some(val) if val == digest.len() => Ok(()),
+        Some(val) => {
+            fi_common::logger::error(
+                format!(
+                    "Digest is {} bytes, {} uses a {} byte hash",
+                    digest.len(),
+                    alg.to_str(),
+                    val
+                )
+                .as_str(),
+            );
+            Err(Error::new(crate::errors::DIGEST_LENGTH_MISMATCH))
+        }
+        None => Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+    }
+}
+
+/// Signing of content streamed in chunks, see [`crate::stream::StreamSigner`]. Implemented by
+/// the HMAC, RSA, ECDSA and Ed25519 signing keys.
+pub trait SignStream: SignFromKey {
+    /// Start the hash of the streamed content for the algorithm
+    fn sign_stream_hasher(&self, alg: Algorithm) -> Result<StreamHasher, Error>;
+
+    /// Sign the streamed content once all of it went through the hasher
+    fn sign_stream(&self, hasher: StreamHasher, alg: Algorithm) -> Result<String, Error>;
+}
+
+/// Verifying of content streamed in chunks, see [`crate::stream::StreamVerifier`]. Implemented
+/// by the HMAC, RSA, ECDSA and Ed25519 verifying keys.
+pub trait VerifyStream: VerifyFromKey {
+    /// Start the hash of the streamed content for the algorithm
+    fn verify_stream_hasher(&self, alg: Algorithm) -> Result<StreamHasher, Error>;
+
+    /// Verify the signature once all of the streamed content went through the hasher
+    fn verify_stream(
+        &self,
+        hasher: StreamHasher,
+        signature: String,
+        alg: Algorithm,
+    ) -> Result<bool, Error>;
+}
+
+impl<K: SignFromKey + ?Sized> SignFromKey for Box<K> {
+    fn sign_bytes(&self, content: &[u8], alg: Algorithm) -> Result<Vec<u8>, Error> {
+        (**self).sign_bytes(content, alg)
+    }
+
+    fn check_key_policy(&self, policy: &KeyPolicy) -> Result<(), Error> {
+        (**self).check_key_policy(policy)
+    }
+
+    fn key_id(&self) -> Option<String> {
+        (**self).key_id()
+    }
+}
+
+impl<K: VerifyFromKey + ?Sized> VerifyFromKey for Box<K> {
+    fn verify_bytes(
+        &self,
+        content: &[u8],
+        signature: &[u8],
+        alg: Algorithm,
+    ) -> Result<bool, Error> {
+        (**self).verify_bytes(content, signature, alg)
+    }
+
+    fn check_key_policy(&self, policy: &KeyPolicy) -> Result<(), Error> {
+        (**self).check_key_policy(policy)
+    }
+
+    fn key_id(&self) -> Option<String> {
+        (**self).key_id()
+    }
 }
@@ -0,0 +1,203 @@
+use fi_common::error::Error;
+use rsa::BigUint;
+use std::sync::RwLock;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Curves used by the EC and OKP keys of this library
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Curve {
+    /// NIST P-256, used by [`crate::algorithms::Algorithm::ES256`]
+    P256,
+    /// Secp256k1, used by [`crate::algorithms::Algorithm::ES256K`]
+    Secp256k1,
+    /// NIST P-384, used by [`crate::algorithms::Algorithm::ES384`]
+    P384,
+    /// NIST P-521, used by [`crate::algorithms::Algorithm::ES512`]
+    P521,
+    /// Ed25519, used by [`crate::algorithms::Algorithm::EdDSA`]
+    Ed25519,
+    /// Ed448, used by [`crate::algorithms::Algorithm::EdDSA`]
+    Ed448,
+    /// brainpoolP256r1, used by [`crate::algorithms::Algorithm::BP256R1`]
+    BrainpoolP256r1,
+    /// brainpoolP384r1, used by [`crate::algorithms::Algorithm::BP384R1`]
+    BrainpoolP384r1,
+    /// brainpoolP512r1, used by [`crate::algorithms::Algorithm::BP512R1`]
+    BrainpoolP512r1,
+    /// BLS12-381 G1 public keys, used by [`crate::algorithms::Algorithm::BLS12381MinPk`]
+    Bls12381G1,
+    /// BLS12-381 G2 public keys, used by [`crate::algorithms::Algorithm::BLS12381MinSig`]
+    Bls12381G2,
+}
+
+impl Curve {
+    pub fn to_str<'a>(&self) -> &'a str {
+        match self {
+            Curve::P256 => "P-256",
+            Curve::Secp256k1 => "secp256k1",
+            Curve::P384 => "P-384",
+            Curve::P521 => "P-521",
+            Curve::Ed25519 => "Ed25519",
+            Curve::Ed448 => "Ed448",
+            Curve::BrainpoolP256r1 => "brainpoolP256r1",
+            Curve::BrainpoolP384r1 => "brainpoolP384r1",
+            Curve::BrainpoolP512r1 => "brainpoolP512r1",
+            Curve::Bls12381G1 => "BLS12381G1",
+            Curve::Bls12381G2 => "BLS12381G2",
+        }
+    }
+}
+
+/// Minimum strength requirements for keys.
+///
+/// The policy set with [`set_key_policy`] is checked when keys are loaded and
+/// by [`crate::verifier::verify`]. The default policy requires 2048 bit RSA
+/// keys with the public exponent 65537, allows every supported curve and
+/// requires HMAC secrets of at least 32 bytes.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct KeyPolicy {
+    /// Minimum RSA modulus size in bits
+    pub min_rsa_modulus_bits: usize,
+    /// Allowed RSA public exponents
+    pub allowed_rsa_exponents: Vec<u64>,
+    /// Allowed EC and OKP curves
+    pub allowed_curves: Vec<Curve>,
+    /// Minimum HMAC secret length in bytes
+    pub min_hmac_key_length: usize,
+}
+
+impl Default for KeyPolicy {
+    fn default() -> Self {
+        KeyPolicy {
+            min_rsa_modulus_bits: 2048,
+            allowed_rsa_exponents: vec![65537],
+            allowed_curves: vec![
+                Curve::P256,
+                Curve::Secp256k1,
+                Curve::P384,
+                Curve::P521,
+                Curve::Ed25519,
+                Curve::Ed448,
+                Curve::BrainpoolP256r1,
+                Curve::BrainpoolP384r1,
+                Curve::BrainpoolP512r1,
+                Curve::Bls12381G1,
+                Curve::Bls12381G2,
+            ],
+            min_hmac_key_length: 32,
+        }
+    }
+}
+
+impl KeyPolicy {
+    /// Check the modulus size and public exponent of an RSA key
+    pub fn check_rsa_key(&self, n: &BigUint, e: &BigUint) -> Result<(), Error> {
+        if n.bits() < self.min_rsa_modulus_bits {
+            fi_common::logger::error(
+                format!(
+                    "RSA key is {} bits, the key policy requires at least {} bits",
+                    n.bits(),
+                    self.min_rsa_modulus_bits
+                )
+                .as_str(),
+            );
+            return Err(Error::new(crate::errors::RSA_KEY_TOO_SMALL));
+        }
+
+        let allowed = self
+            .allowed_rsa_exponents
+            .iter()
+            .any(|exponent| BigUint::from(*exponent) == *e);
+        if !allowed {
+            fi_common::logger::error(
+                format!("RSA public exponent {} isn't allowed by the key policy", e).as_str(),
+            );
+            return Err(Error::new(crate::errors::RSA_EXPONENT_NOT_ALLOWED));
+        }
+
+        Ok(())
+    }
+
+    /// Check if keys on the curve are allowed
+    pub fn check_curve(&self, curve: Curve) -> Result<(), Error> {
+        if !self.allowed_curves.contains(&curve) {
+            fi_common::logger::error(
+                format!("Curve {} isn't allowed by the key policy", curve.to_str()).as_str(),
+            );
+            return Err(Error::new(crate::errors::CURVE_NOT_ALLOWED));
+        }
+
+        Ok(())
+    }
+
+    /// Check the length of an HMAC secret in bytes
+    pub fn check_hmac_key_length(&self, length: usize) -> Result<(), Error> {
+        if length < self.min_hmac_key_length {
+            fi_common::logger::error(
+                format!(
+                    "HMAC key is {} bytes, the key policy requires at least {} bytes",
+                    length, self.min_hmac_key_length
+                )
+                .as_str(),
+            );
+            return Err(Error::new(crate::errors::HMAC_KEY_BELOW_POLICY));
+        }
+
+        Ok(())
+    }
+}
+
+static KEY_POLICY: RwLock<Option<KeyPolicy>> = RwLock::new(None);
+
+/// Replace the key policy used by key loading, [`crate::signer::sign`] and
+/// [`crate::verifier::verify`]
+pub fn set_key_policy(policy: KeyPolicy) {
+    match KEY_POLICY.write() {
+        Ok(mut val) => *val = Some(policy),
+        Err(poisoned) => *poisoned.into_inner() = Some(policy),
+    }
+}
+
+/// Current key policy, [`KeyPolicy::default`] unless replaced with [`set_key_policy`]
+pub fn get_key_policy() -> KeyPolicy {
+    let current = match KEY_POLICY.read() {
+        Ok(val) => val.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    };
+
+    current.unwrap_or_default()
+}
+
+/// Rules [`crate::verifier::verify`] applies to signatures on top of the cryptographic check.
+#[wasm_bindgen]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum VerificationMode {
+    /// Accept every signature the underlying implementation accepts. The default mode.
+    Lenient,
+    /// Reject non-canonical signature encodings, small order Ed25519 points and high-S ECDSA
+    /// signatures, so every message has a single valid signature per key.
+    Strict,
+    /// Verify Ed25519 signatures with the ZIP-215 rules: cofactored verification that accepts
+    /// non-canonical point encodings. Other signatures are verified like [`VerificationMode::Strict`].
+    Zip215,
+}
+
+static VERIFICATION_MODE: RwLock<VerificationMode> = RwLock::new(VerificationMode::Lenient);
+
+/// Replace the verification mode used by [`crate::verifier::verify`]
+#[wasm_bindgen(js_name = "setVerificationMode")]
+pub fn set_verification_mode(mode: VerificationMode) {
+    match VERIFICATION_MODE.write() {
+        Ok(mut val) => *val = mode,
+        Err(poisoned) => *poisoned.into_inner() = mode,
+    }
+}
+
+/// Current verification mode, [`VerificationMode::Lenient`] unless replaced with [`set_verification_mode`]
+#[wasm_bindgen(js_name = "getVerificationMode")]
+pub fn get_verification_mode() -> VerificationMode {
+    match VERIFICATION_MODE.read() {
+        Ok(val) => *val,
+        Err(poisoned) => *poisoned.into_inner(),
+    }
+}
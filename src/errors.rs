@@ -57,6 +57,10 @@ pub const FAILED_TO_CONVERT_TIMESTAMP_TO_DATETTIME: &'static str =
 /// Failed to create HMAC key
 pub const HMAC_KEY_ERROR: &'static str = "Failed to create the HMAC key";
 
+/// HMAC key is shorter than the hash output of the algorithm (RFC 7518 section 3.2)
+pub const HMAC_KEY_TOO_SHORT: &'static str =
+    "HMAC key is shorter than the hash output of the algorithm";
+
 /// Invalid signing key instance
 pub const NOT_A_SIGNING_KEY_INSTANCE: &'static str = "Provided value is not a signing key instace";
 
@@ -72,3 +76,135 @@ pub const JSON_DESERIALIZATION_FAILED: &'static str = "Failed to deserialize pro
 
 ///
 pub const NOT_USING_ASYMMETRIC_KEYS: &'static str = "This algorithm doesn't use asymmetric keys.";
+
+/// Key type or curve of the provided key isn't supported
+pub const UNSUPPORTED_KEY_TYPE: &'static str = "Unsupported or unidentified key type";
+
+/// Provided key can't be used with the requested algorithm
+pub const KEY_ALGORITHM_MISMATCH: &'static str = "Key can't be used with the provided algorithm";
+
+/// RSA modulus is smaller than the key policy allows
+pub const RSA_KEY_TOO_SMALL: &'static str = "RSA key size is below the minimum of the key policy";
+
+/// RSA public exponent isn't allowed by the key policy
+pub const RSA_EXPONENT_NOT_ALLOWED: &'static str =
+    "RSA public exponent isn't allowed by the key policy";
+
+/// Curve of the key isn't allowed by the key policy
+pub const CURVE_NOT_ALLOWED: &'static str = "Key curve isn't allowed by the key policy";
+
+/// HMAC secret is shorter than the key policy allows
+pub const HMAC_KEY_BELOW_POLICY: &'static str =
+    "HMAC key is shorter than the minimum of the key policy";
+
+/// Key metadata doesn't allow the requested operation
+pub const KEY_OPERATION_NOT_ALLOWED: &'static str = "Key can't be used for the requested operation";
+
+/// Key is used before its not_before time
+pub const KEY_NOT_YET_VALID: &'static str = "Key isn't valid yet";
+
+/// Key is used after its not_after time
+pub const KEY_EXPIRED: &'static str = "Key validity period has ended";
+
+/// Key ID of the key doesn't match the kid of the JWT header
+pub const KEY_ID_MISMATCH: &'static str = "Key ID doesn't match the kid of the JWT header";
+
+/// Failed to serialize a value into a json string
+pub const JSON_SERIALIZATION_FAILED: &'static str = "Failed to serialize the value into json";
+
+/// Failed to generate a new key
+pub const KEY_GENERATION_FAILED: &'static str = "Failed to generate the key";
+
+/// Failed to read or write the keystore file
+pub const KEYSTORE_IO_ERROR: &'static str = "Failed to read or write the keystore file";
+
+/// Keystore file content is invalid
+pub const KEYSTORE_FORMAT_ERROR: &'static str = "Invalid keystore file format";
+
+/// Failed to encrypt a private key of the keystore
+pub const KEYSTORE_ENCRYPTION_FAILED: &'static str = "Failed to encrypt the private key";
+
+/// Failed to decrypt a private key of the keystore, usually a wrong passphrase
+pub const KEYSTORE_DECRYPTION_FAILED: &'static str =
+    "Failed to decrypt the keystore, check the passphrase";
+
+/// Keys of the keystore file were changed without the passphrase
+pub const KEYSTORE_INTEGRITY_ERROR: &'static str = "Keystore keys failed the integrity check";
+
+/// No key with the provided key ID
+pub const KEY_NOT_FOUND: &'static str = "Key not found";
+
+/// No active key for the requested algorithm
+pub const NO_ACTIVE_KEY: &'static str = "No active key for the algorithm";
+
+/// Key has been revoked
+pub const KEY_REVOKED: &'static str = "Key has been revoked";
+
+/// Failed to derive a key from the provided secret
+pub const KEY_DERIVATION_FAILED: &'static str = "Failed to derive the key";
+
+/// Ed25519ctx needs a non-empty context string
+pub const EDDSA_CONTEXT_REQUIRED: &'static str = "Ed25519ctx requires a non-empty context";
+
+/// Ed25519 context string is longer than 255 bytes
+pub const EDDSA_CONTEXT_TOO_LONG: &'static str = "Ed25519 context can't be longer than 255 bytes";
+
+/// Public key can't be recovered from the signature
+pub const PUBLIC_KEY_RECOVERY_FAILED: &'static str =
+    "Failed to recover the public key from the signature";
+
+/// Provided Ethereum address isn't a 20 bytes hex string
+pub const INVALID_ADDRESS: &'static str = "Invalid address";
+
+/// EIP-712 typed data doesn't match its type description
+pub const TYPED_DATA_ERROR: &'static str = "Invalid EIP-712 typed data";
+
+/// BIP-340 message or auxiliary randomness isn't 32 bytes
+pub const SCHNORR_INPUT_LENGTH: &'static str =
+    "BIP-340 message and auxiliary randomness must be 32 bytes";
+
+/// ML-DSA context string longer than 255 bytes
+pub const MLDSA_CONTEXT_TOO_LONG: &'static str = "ML-DSA context can't be longer than 255 bytes";
+
+/// Composite signature context string longer than 255 bytes
+pub const COMPOSITE_CONTEXT_TOO_LONG: &'static str =
+    "Composite signature context can't be longer than 255 bytes";
+
+/// id-RSASSA-PSS key parameters with an unsupported hash, mask generation function or trailer
+pub const RSA_PSS_PARAMETERS_UNSUPPORTED: &'static str = "Unsupported RSA-PSS key parameters";
+
+/// RSA-PSS salt length or MGF1 hash conflicts with the parameters of an id-RSASSA-PSS key
+pub const RSA_PSS_PARAMETERS_MISMATCH: &'static str =
+    "RSA-PSS settings don't match the key parameters";
+
+/// BLS input keying material is shorter than 32 bytes
+pub const BLS_IKM_TOO_SHORT: &'static str = "BLS input keying material must be at least 32 bytes";
+
+/// BLS aggregation without any input, or with a different number of public keys and messages
+pub const BLS_AGGREGATION_INPUT: &'static str =
+    "BLS aggregation needs at least one input and one message per public key";
+
+/// BBS key material is shorter than 32 bytes
+pub const BBS_KEY_MATERIAL_TOO_SHORT: &'static str = "BBS key material must be at least 32 bytes";
+
+/// BBS disclosed indexes aren't ascending, repeat or are out of range of the messages
+pub const BBS_DISCLOSED_INDEXES: &'static str =
+    "BBS disclosed indexes must be ascending and match the messages";
+
+/// BBS proof has an invalid length, point or scalar
+pub const BBS_PROOF_IDENTIFICATION_FAILED: &'static str = "Failed to identify the BBS proof";
+
+/// Batch verification input error
+pub const EDDSA_BATCH_INPUT: &'static str =
+    "Ed25519 batch verification needs one message and signature per key";
+
+/// Prehashed signing or verifying error
+pub const DIGEST_LENGTH_MISMATCH: &'static str =
+    "Digest length doesn't match the hash of the algorithm";
+
+/// Streaming isn't available for the key, its variant or the algorithm
+pub const STREAMING_NOT_SUPPORTED: &'static str =
+    "Streaming isn't supported by the key or the algorithm";
+
+/// Streamed content couldn't be read
+pub const STREAM_READ_FAILED: &'static str = "Failed to read the streamed content";
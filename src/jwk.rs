@@ -0,0 +1,237 @@
+use crate::{
+    algorithms::{Algorithm, AlgorithmFamily},
+    crypto::{
+        bls::{BlsVariant, BlsVerifyingKey},
+        composite::CompositeVerifyingKey,
+        ecdsa::brainpool::{BrainpoolCurve, BrainpoolVerifyingKey},
+        mldsa::MlDsaParameterSet,
+        policy::Curve,
+    },
+};
+use elliptic_curve::{
+    sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint},
+    AffinePoint, CurveArithmetic, FieldBytesSize,
+};
+use fi_common::error::Error;
+use rsa::{pkcs8::DecodePublicKey, traits::PublicKeyParts};
+use serde::{Deserialize, Serialize};
+
+/// Public JSON Web Key (RFC 7517)
+#[derive(PartialEq, Eq, Serialize, Deserialize, Clone, Debug)]
+pub struct Jwk {
+    /// Key type, <b>RSA</b>, <b>EC</b>, <b>OKP</b> or <b>AKP</b>
+    pub kty: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+    #[serde(rename = "use", skip_serializing_if = "Option::is_none")]
+    pub key_use: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_ops: Option<Vec<String>>,
+    /// Curve of <b>EC</b> and <b>OKP</b> keys
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+    /// Modulus of <b>RSA</b> keys
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    /// Public exponent of <b>RSA</b> keys
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+    /// Public key of <b>AKP</b> (ML-DSA and composite ML-DSA) keys
+    #[serde(rename = "pub", skip_serializing_if = "Option::is_none")]
+    pub public: Option<String>,
+}
+
+fn ec_jwk<C>(curve: Curve, bytes: &[u8]) -> Result<Jwk, Error>
+where
+    C: CurveArithmetic,
+    FieldBytesSize<C>: ModulusSize,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    let public_key = match elliptic_curve::PublicKey::<C>::from_sec1_bytes(bytes) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR));
+        }
+    };
+
+    let point = public_key.to_encoded_point(false);
+    let (x, y) = match (point.x(), point.y()) {
+        (Some(x), Some(y)) => (x, y),
+        _ => return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+    };
+
+    let mut jwk = Jwk::new("EC");
+    jwk.crv = Some(String::from(curve.to_str()));
+    jwk.x = Some(base64_url::encode(x.as_slice()));
+    jwk.y = Some(base64_url::encode(y.as_slice()));
+    Ok(jwk)
+}
+
+fn brainpool_jwk(curve: BrainpoolCurve, bytes: &[u8]) -> Result<Jwk, Error> {
+    #[cfg(not(feature = "wasm"))]
+    let key = match BrainpoolVerifyingKey::from_bytes(curve, bytes) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    #[cfg(feature = "wasm")]
+    let key = BrainpoolVerifyingKey::from_bytes(curve, bytes);
+
+    let point = match key.to_bytes() {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    let (x, y) = point[1..].split_at(curve.field_size());
+
+    let mut jwk = Jwk::new("EC");
+    jwk.crv = Some(String::from(curve.policy_curve().to_str()));
+    jwk.x = Some(base64_url::encode(x));
+    jwk.y = Some(base64_url::encode(y));
+    Ok(jwk)
+}
+
+fn bls_jwk(alg: Algorithm, bytes: &[u8]) -> Result<Jwk, Error> {
+    #[cfg(not(feature = "wasm"))]
+    let key_variant = match BlsVerifyingKey::from_bytes(bytes) {
+        Ok(val) => val.variant(),
+        Err(error) => return Err(error),
+    };
+
+    #[cfg(feature = "wasm")]
+    let key_variant = match BlsVerifyingKey::from_bytes(bytes).variant() {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    let variant = match BlsVariant::from_algorithm(alg) {
+        Some(val) if val == key_variant => val,
+        _ => return Err(Error::new(crate::errors::KEY_ALGORITHM_MISMATCH)),
+    };
+
+    let mut jwk = Jwk::new("OKP");
+    jwk.crv = Some(String::from(variant.policy_curve().to_str()));
+    jwk.x = Some(base64_url::encode(bytes));
+    Ok(jwk)
+}
+
+impl Jwk {
+    fn new(kty: &str) -> Jwk {
+        Jwk {
+            kty: String::from(kty),
+            kid: None,
+            alg: None,
+            key_use: None,
+            key_ops: None,
+            crv: None,
+            x: None,
+            y: None,
+            n: None,
+            e: None,
+            public: None,
+        }
+    }
+
+    /// Create a public JWK from verifying key bytes in the formats accepted by
+    /// [`crate::verifier::get_verifying_key`]: <b>SPKI</b> DER for RSA, SEC1 encoded points for
+    /// EC, raw 32 or 57 bytes for Ed25519 and Ed448, raw public keys for ML-DSA and composite
+    /// ML-DSA and compressed points for BLS.
+    pub fn from_public_key_bytes(alg: Algorithm, bytes: &[u8]) -> Result<Jwk, Error> {
+        let mut jwk = match alg {
+            Algorithm::ES256 => ec_jwk::<p256::NistP256>(Curve::P256, bytes),
+            Algorithm::ES256K => ec_jwk::<k256::Secp256k1>(Curve::Secp256k1, bytes),
+            Algorithm::ES384 => ec_jwk::<p384::NistP384>(Curve::P384, bytes),
+            Algorithm::ES512 => ec_jwk::<p521::NistP521>(Curve::P521, bytes),
+            Algorithm::BP256R1 | Algorithm::BP384R1 | Algorithm::BP512R1 => {
+                match BrainpoolCurve::from_algorithm(alg) {
+                    Some(curve) => brainpool_jwk(curve, bytes),
+                    None => Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+                }
+            }
+            Algorithm::EdDSA => {
+                let curve = match bytes.len() {
+                    32 => Curve::Ed25519,
+                    ed448_goldilocks_plus::PUBLIC_KEY_LENGTH => Curve::Ed448,
+                    _ => return Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+                };
+
+                let mut jwk = Jwk::new("OKP");
+                jwk.crv = Some(String::from(curve.to_str()));
+                jwk.x = Some(base64_url::encode(bytes));
+                Ok(jwk)
+            }
+            Algorithm::MLDSA44 | Algorithm::MLDSA65 | Algorithm::MLDSA87 => {
+                match MlDsaParameterSet::from_algorithm(alg) {
+                    Some(val) if val.public_key_length() == bytes.len() => {
+                        let mut jwk = Jwk::new("AKP");
+                        jwk.public = Some(base64_url::encode(bytes));
+                        Ok(jwk)
+                    }
+                    _ => Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR)),
+                }
+            }
+            Algorithm::BLS12381MinPk | Algorithm::BLS12381MinSig => bls_jwk(alg, bytes),
+            _ => match alg.get_family() {
+                AlgorithmFamily::RSA => match rsa::RsaPublicKey::from_public_key_der(bytes) {
+                    Ok(val) => {
+                        let mut jwk = Jwk::new("RSA");
+                        jwk.n = Some(base64_url::encode(&val.n().to_bytes_be()));
+                        jwk.e = Some(base64_url::encode(&val.e().to_bytes_be()));
+                        Ok(jwk)
+                    }
+                    Err(error) => {
+                        fi_common::logger::error(error.to_string().as_str());
+                        Err(Error::new(crate::errors::PUBLIC_KEY_IDENTIFICATION_ERROR))
+                    }
+                },
+                AlgorithmFamily::Composite => match CompositeVerifyingKey::from_bytes(alg, bytes) {
+                    Ok(_) => {
+                        let mut jwk = Jwk::new("AKP");
+                        jwk.public = Some(base64_url::encode(bytes));
+                        Ok(jwk)
+                    }
+                    Err(error) => Err(error),
+                },
+                _ => Err(Error::new(crate::errors::NOT_USING_ASYMMETRIC_KEYS)),
+            },
+        };
+
+        if let Ok(val) = jwk.as_mut() {
+            val.alg = Some(String::from(alg.to_str()));
+            val.key_use = Some(String::from("sig"));
+        }
+
+        jwk
+    }
+}
+
+/// Set of public JSON Web Keys (RFC 7517 section 5)
+#[derive(PartialEq, Eq, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct JwkSet {
+    pub keys: Vec<Jwk>,
+}
+
+impl JwkSet {
+    /// Find a key by its <b>kid</b>
+    pub fn find(&self, kid: &str) -> Option<&Jwk> {
+        self.keys.iter().find(|jwk| jwk.kid.as_deref() == Some(kid))
+    }
+
+    /// Serialize the set into a JSON string
+    pub fn to_json(&self) -> Result<String, Error> {
+        match serde_json::to_string(self) {
+            Ok(val) => Ok(val),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::JSON_SERIALIZATION_FAILED))
+            }
+        }
+    }
+}
@@ -252,7 +252,12 @@ impl JWT {
     }
 
     /// Sign the current [`JWT`] token object  
+    /// The header <b>kid</b> is replaced with the key ID when the key has one.
     pub fn sign(&mut self, private_key: impl SignFromKey) -> Result<(), Error> {
+        if let Some(kid) = private_key.key_id() {
+            self.header.kid = kid;
+        }
+
         let content = format!(
             "{}.{}",
             self.header.to_base64_encoded(),
@@ -309,6 +314,12 @@ impl JWT {
     pub fn validate(&self, public_key: impl VerifyFromKey) -> Result<bool, Error> {
         let algorithm = self.header.alg;
 
+        if let Some(kid) = public_key.key_id() {
+            if kid != self.header.kid {
+                return Err(Error::new(crate::errors::KEY_ID_MISMATCH));
+            }
+        }
+
         let signature = match &self.signature {
             Some(val) => val.clone(),
             None => return Err(Error::new(crate::errors::JWT_NO_SIGNATURE_FOUND)),
@@ -0,0 +1,837 @@
+use crate::{
+    algorithms::{Algorithm, AlgorithmFamily},
+    crypto::{
+        bls::{BlsSigningKey, BlsVariant},
+        composite::CompositeSigningKey,
+        ecdsa::brainpool::{BrainpoolCurve, BrainpoolSigningKey},
+        hmac::{min_key_length, HMACKey},
+        metadata::{KeyMetadata, KeyOperation, ManagedKey},
+        mldsa::{MlDsaParameterSet, MlDsaSigningKey},
+        policy::get_key_policy,
+        SignFromKey, VerifyFromKey,
+    },
+    jwk::JwkSet,
+    signer::get_signing_key,
+    verifier::get_verifying_key,
+};
+use aes_gcm::{
+    aead::{Aead, Payload},
+    Aes256Gcm, KeyInit, Nonce,
+};
+use chrono::Utc;
+use fi_common::error::Error;
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use rand::{rngs::OsRng, RngCore};
+use rsa::{
+    pkcs8::{EncodePrivateKey, EncodePublicKey},
+    RsaPrivateKey,
+};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::{
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
+use zeroize::Zeroizing;
+
+const KEYSTORE_VERSION: u32 = 1;
+const KDF_NAME: &'static str = "PBKDF2-HMAC-SHA256";
+const PASSPHRASE_CHECK_AAD: &'static str = "keystore passphrase check";
+const KEYS_MAC_INFO: &'static str = "keystore keys mac";
+/// PBKDF2 iterations used for new keystores
+pub const DEFAULT_KDF_ITERATIONS: u32 = 600_000;
+
+/// Status of a key in the [`Keystore`]
+#[derive(PartialEq, Eq, Serialize, Deserialize, Copy, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyStatus {
+    /// Used for signing and verifying
+    Active,
+    /// Demoted after a rotation, only used for verifying
+    Retiring,
+    /// Can't be used anymore
+    Revoked,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct KdfParams {
+    name: String,
+    iterations: u32,
+    salt: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct KeystoreEntry {
+    #[serde(flatten)]
+    metadata: KeyMetadata,
+    status: KeyStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    public_key: Option<String>,
+    nonce: String,
+    encrypted_key: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeystoreFile {
+    version: u32,
+    kdf: KdfParams,
+    /// Empty value encrypted with the derived key, used to check the passphrase
+    check_nonce: String,
+    check: String,
+    keys: Vec<KeystoreEntry>,
+    /// HMAC-SHA256 of the keys, so their status, metadata and public keys can't be changed
+    /// without the passphrase
+    mac: String,
+}
+
+/// Signing keys with their metadata and status, saved to a JSON file. Private keys are
+/// encrypted with AES-256-GCM using a key derived from the passphrase with PBKDF2, and the
+/// keys are authenticated with an HMAC keyed by a subkey of it.
+pub struct Keystore {
+    path: PathBuf,
+    kdf: KdfParams,
+    encryption_key: Zeroizing<[u8; 32]>,
+    check_nonce: String,
+    check: String,
+    entries: Vec<KeystoreEntry>,
+}
+
+fn derive_encryption_key(passphrase: &str, kdf: &KdfParams) -> Result<Zeroizing<[u8; 32]>, Error> {
+    if kdf.name != KDF_NAME {
+        return Err(Error::new(crate::errors::KEYSTORE_FORMAT_ERROR));
+    }
+
+    let salt = match base64_url::decode(&kdf.salt) {
+        Ok(val) => val,
+        Err(error) => {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::KEYSTORE_FORMAT_ERROR));
+        }
+    };
+
+    let mut key = Zeroizing::new([0u8; 32]);
+    pbkdf2::pbkdf2_hmac::<Sha256>(
+        passphrase.as_bytes(),
+        salt.as_slice(),
+        kdf.iterations,
+        key.as_mut_slice(),
+    );
+    Ok(key)
+}
+
+/// Private key bytes and, for asymmetric keys, public key bytes
+type GeneratedKey = (Zeroizing<Vec<u8>>, Option<Vec<u8>>);
+
+/// Generate private and public key bytes in the formats accepted by
+/// [`crate::signer::get_signing_key`] and [`crate::verifier::get_verifying_key`]
+fn generate_key_bytes(alg: Algorithm) -> Result<GeneratedKey, Error> {
+    match alg.get_family() {
+        AlgorithmFamily::HMAC => {
+            let length = match min_key_length(alg) {
+                Ok(val) => val,
+                Err(error) => return Err(error),
+            };
+            let length = length.max(get_key_policy().min_hmac_key_length);
+
+            let mut secret = Zeroizing::new(vec![0u8; length]);
+            OsRng.fill_bytes(secret.as_mut_slice());
+            Ok((secret, None))
+        }
+        AlgorithmFamily::RSA => {
+            let bits = get_key_policy().min_rsa_modulus_bits.max(2048);
+            let private_key = match RsaPrivateKey::new(&mut OsRng, bits) {
+                Ok(val) => val,
+                Err(error) => {
+                    fi_common::logger::error(error.to_string().as_str());
+                    return Err(Error::new(crate::errors::KEY_GENERATION_FAILED));
+                }
+            };
+
+            let private_der = match private_key.to_pkcs8_der() {
+                Ok(val) => Zeroizing::new(val.as_bytes().to_vec()),
+                Err(error) => {
+                    fi_common::logger::error(error.to_string().as_str());
+                    return Err(Error::new(crate::errors::KEY_GENERATION_FAILED));
+                }
+            };
+
+            match private_key.to_public_key().to_public_key_der() {
+                Ok(val) => Ok((private_der, Some(val.as_bytes().to_vec()))),
+                Err(error) => {
+                    fi_common::logger::error(error.to_string().as_str());
+                    Err(Error::new(crate::errors::KEY_GENERATION_FAILED))
+                }
+            }
+        }
+        AlgorithmFamily::EC => match alg {
+            Algorithm::ES256 => {
+                let key = p256::ecdsa::SigningKey::random(&mut OsRng);
+                let public = key.verifying_key().to_encoded_point(false);
+                Ok((
+                    Zeroizing::new(key.to_bytes().to_vec()),
+                    Some(public.as_bytes().to_vec()),
+                ))
+            }
+            Algorithm::ES256K => {
+                let key = k256::ecdsa::SigningKey::random(&mut OsRng);
+                let public = key.verifying_key().to_encoded_point(false);
+                Ok((
+                    Zeroizing::new(key.to_bytes().to_vec()),
+                    Some(public.as_bytes().to_vec()),
+                ))
+            }
+            Algorithm::ES384 => {
+                let key = p384::ecdsa::SigningKey::random(&mut OsRng);
+                let public = key.verifying_key().to_encoded_point(false);
+                Ok((
+                    Zeroizing::new(key.to_bytes().to_vec()),
+                    Some(public.as_bytes().to_vec()),
+                ))
+            }
+            Algorithm::ES512 => {
+                let key = p521::ecdsa::SigningKey::random(&mut OsRng);
+                let public = p521::ecdsa::VerifyingKey::from(&key).to_encoded_point(false);
+                Ok((
+                    Zeroizing::new(key.to_bytes().to_vec()),
+                    Some(public.as_bytes().to_vec()),
+                ))
+            }
+            Algorithm::BP256R1 | Algorithm::BP384R1 | Algorithm::BP512R1 => {
+                let curve = match BrainpoolCurve::from_algorithm(alg) {
+                    Some(val) => val,
+                    None => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+                };
+
+                let key = match BrainpoolSigningKey::generate(curve) {
+                    Ok(val) => val,
+                    Err(error) => return Err(error),
+                };
+
+                match (
+                    key.to_bytes(),
+                    key.verifying_key().and_then(|val| val.to_bytes()),
+                ) {
+                    (Ok(private), Ok(public)) => Ok((Zeroizing::new(private), Some(public))),
+                    (Err(error), _) | (_, Err(error)) => Err(error),
+                }
+            }
+            _ => Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+        },
+        AlgorithmFamily::OKP => {
+            let mut seed = Zeroizing::new([0u8; 32]);
+            OsRng.fill_bytes(seed.as_mut_slice());
+
+            let key = ed25519_dalek::SigningKey::from_bytes(&seed);
+            Ok((
+                Zeroizing::new(seed.to_vec()),
+                Some(key.verifying_key().to_bytes().to_vec()),
+            ))
+        }
+        AlgorithmFamily::MLDSA => {
+            let parameter_set = match MlDsaParameterSet::from_algorithm(alg) {
+                Some(val) => val,
+                None => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+            };
+
+            let key = match MlDsaSigningKey::generate(parameter_set) {
+                Ok(val) => val,
+                Err(error) => return Err(error),
+            };
+
+            match (
+                key.to_bytes(),
+                key.verifying_key().and_then(|val| val.to_bytes()),
+            ) {
+                (Ok(seed), Ok(public)) => Ok((Zeroizing::new(seed), Some(public))),
+                (Err(error), _) | (_, Err(error)) => Err(error),
+            }
+        }
+        AlgorithmFamily::Composite => {
+            let key = match CompositeSigningKey::generate(alg) {
+                Ok(val) => val,
+                Err(error) => return Err(error),
+            };
+
+            match (
+                key.to_bytes(),
+                key.verifying_key().and_then(|val| val.to_bytes()),
+            ) {
+                (Ok(private), Ok(public)) => Ok((Zeroizing::new(private), Some(public))),
+                (Err(error), _) | (_, Err(error)) => Err(error),
+            }
+        }
+        AlgorithmFamily::BLS => {
+            let variant = match BlsVariant::from_algorithm(alg) {
+                Some(val) => val,
+                None => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+            };
+
+            let key = match BlsSigningKey::generate(variant) {
+                Ok(val) => val,
+                Err(error) => return Err(error),
+            };
+
+            match (
+                key.to_bytes(),
+                key.verifying_key().and_then(|val| val.to_bytes()),
+            ) {
+                (Ok(private), Ok(public)) => Ok((Zeroizing::new(private), Some(public))),
+                (Err(error), _) | (_, Err(error)) => Err(error),
+            }
+        }
+        _ => Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+    }
+}
+
+fn generate_kid() -> String {
+    let mut bytes = [0u8; 16];
+    OsRng.fill_bytes(&mut bytes);
+    base64_url::encode(&bytes)
+}
+
+/// Write the content to a temporary file in the directory of the path, sync it and rename it
+/// over the path, so a crash leaves either the old or the new file behind
+fn write_atomic(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let mut temp_name = match path.file_name() {
+        Some(val) => val.to_os_string(),
+        None => return Err(std::io::Error::from(std::io::ErrorKind::InvalidInput)),
+    };
+    temp_name.push(format!(".{}.tmp", std::process::id()));
+    let temp_path = path.with_file_name(temp_name);
+
+    let result =
+        write_and_sync(&temp_path, path, content).and_then(|()| std::fs::rename(&temp_path, path));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+        return result;
+    }
+
+    // Persist the rename itself
+    #[cfg(unix)]
+    if let Some(parent) = path.parent() {
+        let directory = match parent.as_os_str().is_empty() {
+            true => Path::new("."),
+            false => parent,
+        };
+        if let Err(error) = File::open(directory).and_then(|dir| dir.sync_all()) {
+            return Err(error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Write and sync the temporary file, with the permissions of the file it replaces
+fn write_and_sync(temp_path: &Path, path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let mut file = match File::create(temp_path) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    if let Ok(metadata) = std::fs::metadata(path) {
+        if let Err(error) = file.set_permissions(metadata.permissions()) {
+            return Err(error);
+        }
+    }
+
+    match file.write_all(content) {
+        Ok(()) => file.sync_all(),
+        Err(error) => Err(error),
+    }
+}
+
+impl Keystore {
+    /// Create an empty keystore at the path and save it
+    pub fn create(path: impl AsRef<Path>, passphrase: &str) -> Result<Keystore, Error> {
+        Self::create_with_iterations(path, passphrase, DEFAULT_KDF_ITERATIONS)
+    }
+
+    /// Create an empty keystore at the path using the provided PBKDF2 iteration count
+    pub fn create_with_iterations(
+        path: impl AsRef<Path>,
+        passphrase: &str,
+        iterations: u32,
+    ) -> Result<Keystore, Error> {
+        let mut salt = [0u8; 16];
+        OsRng.fill_bytes(&mut salt);
+
+        let kdf = KdfParams {
+            name: String::from(KDF_NAME),
+            iterations,
+            salt: base64_url::encode(&salt),
+        };
+
+        let encryption_key = match derive_encryption_key(passphrase, &kdf) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let mut keystore = Keystore {
+            path: path.as_ref().to_path_buf(),
+            kdf,
+            encryption_key,
+            check_nonce: String::new(),
+            check: String::new(),
+            entries: Vec::new(),
+        };
+
+        let (check_nonce, check) = match keystore.encrypt(PASSPHRASE_CHECK_AAD, &[]) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+        keystore.check_nonce = check_nonce;
+        keystore.check = check;
+
+        match keystore.save() {
+            Ok(()) => Ok(keystore),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Open an existing keystore file. Fails with
+    /// [`crate::errors::KEYSTORE_DECRYPTION_FAILED`] if the passphrase is wrong.
+    pub fn open(path: impl AsRef<Path>, passphrase: &str) -> Result<Keystore, Error> {
+        let content = match std::fs::read_to_string(path.as_ref()) {
+            Ok(val) => val,
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                return Err(Error::new(crate::errors::KEYSTORE_IO_ERROR));
+            }
+        };
+
+        let file: KeystoreFile = match serde_json::from_str(content.as_str()) {
+            Ok(val) => val,
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                return Err(Error::new(crate::errors::KEYSTORE_FORMAT_ERROR));
+            }
+        };
+
+        if file.version != KEYSTORE_VERSION {
+            return Err(Error::new(crate::errors::KEYSTORE_FORMAT_ERROR));
+        }
+
+        let encryption_key = match derive_encryption_key(passphrase, &file.kdf) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let keystore = Keystore {
+            path: path.as_ref().to_path_buf(),
+            kdf: file.kdf,
+            encryption_key,
+            check_nonce: file.check_nonce,
+            check: file.check,
+            entries: file.keys,
+        };
+
+        if let Err(error) =
+            keystore.decrypt(PASSPHRASE_CHECK_AAD, &keystore.check_nonce, &keystore.check)
+        {
+            return Err(error);
+        }
+
+        let mac = match base64_url::decode(&file.mac) {
+            Ok(val) => val,
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                return Err(Error::new(crate::errors::KEYSTORE_FORMAT_ERROR));
+            }
+        };
+
+        let keys_mac = match keystore.keys_mac() {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        match keys_mac.verify_slice(mac.as_slice()) {
+            Ok(()) => Ok(keystore),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::KEYSTORE_INTEGRITY_ERROR))
+            }
+        }
+    }
+
+    /// Write the keystore to its file
+    pub fn save(&self) -> Result<(), Error> {
+        let mac = match self.keys_mac() {
+            Ok(val) => val.finalize().into_bytes(),
+            Err(error) => return Err(error),
+        };
+
+        let file = KeystoreFile {
+            version: KEYSTORE_VERSION,
+            kdf: self.kdf.clone(),
+            check_nonce: self.check_nonce.clone(),
+            check: self.check.clone(),
+            keys: self.entries.clone(),
+            mac: base64_url::encode(&mac),
+        };
+
+        let content = match serde_json::to_string_pretty(&file) {
+            Ok(val) => val,
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                return Err(Error::new(crate::errors::JSON_SERIALIZATION_FAILED));
+            }
+        };
+
+        match write_atomic(&self.path, content.as_bytes()) {
+            Ok(()) => Ok(()),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::KEYSTORE_IO_ERROR))
+            }
+        }
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(self.encryption_key.as_slice().into())
+    }
+
+    /// HMAC-SHA256 over the serialized keys, keyed by an HKDF subkey of the encryption key
+    fn keys_mac(&self) -> Result<Hmac<Sha256>, Error> {
+        let mut mac_key = Zeroizing::new([0u8; 32]);
+        if let Err(error) = Hkdf::<Sha256>::new(None, self.encryption_key.as_slice())
+            .expand(KEYS_MAC_INFO.as_bytes(), mac_key.as_mut_slice())
+        {
+            fi_common::logger::error(error.to_string().as_str());
+            return Err(Error::new(crate::errors::KEY_DERIVATION_FAILED));
+        }
+
+        let keys = match serde_json::to_vec(&self.entries) {
+            Ok(val) => val,
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                return Err(Error::new(crate::errors::JSON_SERIALIZATION_FAILED));
+            }
+        };
+
+        match <Hmac<Sha256> as Mac>::new_from_slice(mac_key.as_slice()) {
+            Ok(mac) => Ok(mac.chain_update(keys)),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::KEY_DERIVATION_FAILED))
+            }
+        }
+    }
+
+    fn encrypt(&self, aad: &str, private_key: &[u8]) -> Result<(String, String), Error> {
+        let mut nonce = [0u8; 12];
+        OsRng.fill_bytes(&mut nonce);
+
+        let payload = Payload {
+            msg: private_key,
+            aad: aad.as_bytes(),
+        };
+
+        match self.cipher().encrypt(Nonce::from_slice(&nonce), payload) {
+            Ok(val) => Ok((base64_url::encode(&nonce), base64_url::encode(&val))),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::KEYSTORE_ENCRYPTION_FAILED))
+            }
+        }
+    }
+
+    fn decrypt(
+        &self,
+        aad: &str,
+        nonce: &str,
+        encrypted_key: &str,
+    ) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let nonce = match base64_url::decode(nonce) {
+            Ok(val) if val.len() == 12 => val,
+            _ => return Err(Error::new(crate::errors::KEYSTORE_FORMAT_ERROR)),
+        };
+
+        let encrypted_key = match base64_url::decode(encrypted_key) {
+            Ok(val) => val,
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                return Err(Error::new(crate::errors::KEYSTORE_FORMAT_ERROR));
+            }
+        };
+
+        let payload = Payload {
+            msg: encrypted_key.as_slice(),
+            aad: aad.as_bytes(),
+        };
+
+        match self.cipher().decrypt(Nonce::from_slice(&nonce), payload) {
+            Ok(val) => Ok(Zeroizing::new(val)),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::KEYSTORE_DECRYPTION_FAILED))
+            }
+        }
+    }
+
+    fn decrypt_entry(&self, entry: &KeystoreEntry) -> Result<Zeroizing<Vec<u8>>, Error> {
+        self.decrypt(
+            entry.metadata.kid.as_str(),
+            entry.nonce.as_str(),
+            entry.encrypted_key.as_str(),
+        )
+    }
+
+    fn entry(&self, kid: &str) -> Result<&KeystoreEntry, Error> {
+        match self.entries.iter().find(|entry| entry.metadata.kid == kid) {
+            Some(val) => Ok(val),
+            None => Err(Error::new(crate::errors::KEY_NOT_FOUND)),
+        }
+    }
+
+    fn entry_mut(&mut self, kid: &str) -> Result<&mut KeystoreEntry, Error> {
+        match self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.metadata.kid == kid)
+        {
+            Some(val) => Ok(val),
+            None => Err(Error::new(crate::errors::KEY_NOT_FOUND)),
+        }
+    }
+
+    /// Generate a new active key for the algorithm and return its key ID
+    pub fn generate(&mut self, alg: Algorithm) -> Result<String, Error> {
+        let (private_key, public_key) = match generate_key_bytes(alg) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let kid = generate_kid();
+        let (nonce, encrypted_key) = match self.encrypt(kid.as_str(), private_key.as_slice()) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let mut metadata = KeyMetadata::new(
+            kid.clone(),
+            alg,
+            vec![KeyOperation::Sign, KeyOperation::Verify],
+        );
+        metadata.not_before = Some(Utc::now().timestamp());
+
+        self.entries.push(KeystoreEntry {
+            metadata,
+            status: KeyStatus::Active,
+            public_key: public_key.map(|val| base64_url::encode(&val)),
+            nonce,
+            encrypted_key,
+        });
+
+        Ok(kid)
+    }
+
+    /// Demote a key to verify-only. It stops verifying after <b>not_after</b> (unix timestamp
+    /// in seconds).
+    pub fn demote(&mut self, kid: &str, not_after: i64) -> Result<(), Error> {
+        let entry = match self.entry_mut(kid) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if entry.status == KeyStatus::Revoked {
+            return Err(Error::new(crate::errors::KEY_REVOKED));
+        }
+
+        entry.status = KeyStatus::Retiring;
+        entry.metadata.ops = vec![KeyOperation::Verify];
+        entry.metadata.not_after = Some(not_after);
+        Ok(())
+    }
+
+    /// Generate a new active key for the algorithm and demote the current active keys of the
+    /// algorithm, which keep verifying for <b>grace_period_secs</b>. Returns the new key ID.
+    pub fn rotate(&mut self, alg: Algorithm, grace_period_secs: i64) -> Result<String, Error> {
+        let not_after = Utc::now().timestamp() + grace_period_secs;
+        let active: Vec<String> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.status == KeyStatus::Active && entry.metadata.alg == alg)
+            .map(|entry| entry.metadata.kid.clone())
+            .collect();
+
+        let kid = match self.generate(alg) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        for old_kid in active.iter() {
+            if let Err(error) = self.demote(old_kid.as_str(), not_after) {
+                return Err(error);
+            }
+        }
+
+        Ok(kid)
+    }
+
+    /// Revoke a key, it can't be used for signing or verifying anymore
+    pub fn revoke(&mut self, kid: &str) -> Result<(), Error> {
+        match self.entry_mut(kid) {
+            Ok(entry) => {
+                entry.status = KeyStatus::Revoked;
+                entry.metadata.ops = Vec::new();
+                Ok(())
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Remove revoked keys and keys past their <b>not_after</b> time. Returns the removed key IDs.
+    pub fn purge_expired(&mut self) -> Vec<String> {
+        let now = Utc::now().timestamp();
+        let mut purged = Vec::new();
+
+        self.entries.retain(|entry| {
+            let expired = match entry.metadata.not_after {
+                Some(not_after) => now > not_after,
+                None => false,
+            };
+
+            if expired || entry.status == KeyStatus::Revoked {
+                purged.push(entry.metadata.kid.clone());
+                return false;
+            }
+
+            true
+        });
+
+        purged
+    }
+
+    /// Key IDs of all keys in the keystore
+    pub fn kids(&self) -> Vec<String> {
+        self.entries
+            .iter()
+            .map(|entry| entry.metadata.kid.clone())
+            .collect()
+    }
+
+    /// Status of the key
+    pub fn status(&self, kid: &str) -> Result<KeyStatus, Error> {
+        match self.entry(kid) {
+            Ok(entry) => Ok(entry.status),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Metadata of the key
+    pub fn metadata(&self, kid: &str) -> Result<KeyMetadata, Error> {
+        match self.entry(kid) {
+            Ok(entry) => Ok(entry.metadata.clone()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Newest active signing key of the algorithm
+    pub fn active_signing_key(
+        &self,
+        alg: Algorithm,
+    ) -> Result<ManagedKey<Box<dyn SignFromKey>>, Error> {
+        let entry = match self
+            .entries
+            .iter()
+            .rev()
+            .find(|entry| entry.status == KeyStatus::Active && entry.metadata.alg == alg)
+        {
+            Some(val) => val,
+            None => return Err(Error::new(crate::errors::NO_ACTIVE_KEY)),
+        };
+
+        let mut private_key = match self.decrypt_entry(entry) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        let key: Box<dyn SignFromKey> = match alg.get_family() {
+            AlgorithmFamily::HMAC => Box::new(HMACKey::from_bytes(private_key.as_slice())),
+            _ => match get_signing_key(alg, private_key.as_mut_slice()) {
+                Ok(val) => val,
+                Err(error) => return Err(error),
+            },
+        };
+
+        Ok(ManagedKey::new(key, entry.metadata.clone()))
+    }
+
+    /// Verifying key of an active or retiring key
+    pub fn verifying_key(&self, kid: &str) -> Result<ManagedKey<Box<dyn VerifyFromKey>>, Error> {
+        let entry = match self.entry(kid) {
+            Ok(val) => val,
+            Err(error) => return Err(error),
+        };
+
+        if entry.status == KeyStatus::Revoked {
+            return Err(Error::new(crate::errors::KEY_REVOKED));
+        }
+
+        let alg = entry.metadata.alg;
+        let key: Box<dyn VerifyFromKey> = match &entry.public_key {
+            Some(public_key) => {
+                let mut bytes = match base64_url::decode(public_key) {
+                    Ok(val) => val,
+                    Err(error) => {
+                        fi_common::logger::error(error.to_string().as_str());
+                        return Err(Error::new(crate::errors::KEYSTORE_FORMAT_ERROR));
+                    }
+                };
+
+                match get_verifying_key(alg, bytes.as_mut_slice()) {
+                    Ok(val) => val,
+                    Err(error) => return Err(error),
+                }
+            }
+            None => match self.decrypt_entry(entry) {
+                Ok(secret) => Box::new(HMACKey::from_bytes(secret.as_slice())),
+                Err(error) => return Err(error),
+            },
+        };
+
+        Ok(ManagedKey::new(key, entry.metadata.clone()))
+    }
+
+    /// Public keys of every active and retiring asymmetric key that hasn't expired
+    pub fn jwk_set(&self) -> Result<JwkSet, Error> {
+        let now = Utc::now().timestamp();
+        let mut jwk_set = JwkSet::default();
+
+        for entry in self.entries.iter() {
+            if entry.status == KeyStatus::Revoked {
+                continue;
+            }
+
+            if let Some(not_after) = entry.metadata.not_after {
+                if now > not_after {
+                    continue;
+                }
+            }
+
+            let public_key = match &entry.public_key {
+                Some(val) => match base64_url::decode(val) {
+                    Ok(val) => val,
+                    Err(error) => {
+                        fi_common::logger::error(error.to_string().as_str());
+                        return Err(Error::new(crate::errors::KEYSTORE_FORMAT_ERROR));
+                    }
+                },
+                None => continue,
+            };
+
+            let mut jwk =
+                match crate::jwk::Jwk::from_public_key_bytes(entry.metadata.alg, &public_key) {
+                    Ok(val) => val,
+                    Err(error) => return Err(error),
+                };
+            jwk.kid = Some(entry.metadata.kid.clone());
+            jwk.key_ops = Some(vec![String::from("verify")]);
+            jwk_set.keys.push(jwk);
+        }
+
+        Ok(jwk_set)
+    }
+}
@@ -7,9 +7,16 @@ pub mod algorithms;
 pub mod crypto;
 /// Constant error values
 pub mod errors;
+/// JSON Web Keys
+pub mod jwk;
 /// JWT token management
 pub mod jwt;
+/// Encrypted keystore file with key rotation
+#[cfg(not(feature = "wasm"))]
+pub mod keystore;
 /// Content signer
 pub mod signer;
+/// Incremental signing and verifying of streamed content
+pub mod stream;
 /// Signature verifier
 pub mod verifier;
@@ -1,15 +1,31 @@
 #[cfg(not(feature = "wasm"))]
 use crate::crypto::ecdsa::{
-    _256k::P256kSigningKey, _256::P256SigningKey, _384::P384SigningKey, _512::P512SigningKey,
+    brainpool::{BrainpoolCurve, BrainpoolSigningKey},
+    _256k::P256kSigningKey,
+    _256::P256SigningKey,
+    _384::P384SigningKey,
+    _512::P512SigningKey,
 };
 #[cfg(feature = "wasm")]
 use crate::crypto::hmac::HMACKey;
 #[cfg(not(feature = "wasm"))]
-use crate::crypto::SignFromKey;
-use crate::crypto::{ecdsa::sign_ec, eddsa::EDDSASigningKey, rsa::RsaSigningKey};
+use crate::crypto::policy::KeyPolicy;
+#[cfg(not(feature = "wasm"))]
+use crate::crypto::{
+    bls::{BlsSigningKey, BlsVariant},
+    composite::CompositeSigningKey,
+    mldsa::{MlDsaParameterSet, MlDsaSigningKey},
+};
+use crate::crypto::{ecdsa::sign_ec, rsa::RsaSigningKey};
+#[cfg(not(feature = "wasm"))]
+use crate::crypto::{ed448::Ed448SigningKey, eddsa::EDDSASigningKey, SignPrehashed};
+use crate::crypto::{policy::get_key_policy, SignFromKey};
 use crate::{
     algorithms::{Algorithm, AlgorithmFamily},
-    crypto::{eddsa::sign_eddsa, hmac::sign_hmac, rsa::sign_rsa},
+    crypto::{
+        bls::sign_bls, composite::sign_composite, eddsa::sign_eddsa, hmac::sign_hmac,
+        mldsa::sign_mldsa, rsa::sign_rsa,
+    },
 };
 use fi_common::error::Error;
 #[cfg(feature = "wasm")]
@@ -17,15 +33,33 @@ use js_sys::Object;
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::wasm_bindgen;
 
-/// Signs the content with a provided Key
+/// Signs the content with a provided Key. The key is checked against [`get_key_policy`] first.
 #[cfg(not(feature = "wasm"))]
 pub fn sign(message: String, key: impl SignFromKey, alg: Algorithm) -> Result<String, Error> {
+    sign_with_policy(message, key, alg, &get_key_policy())
+}
+
+/// Signs the content with a provided Key after checking the key against the provided policy
+#[cfg(not(feature = "wasm"))]
+pub fn sign_with_policy(
+    message: String,
+    key: impl SignFromKey,
+    alg: Algorithm,
+    policy: &KeyPolicy,
+) -> Result<String, Error> {
+    if let Err(error) = key.check_key_policy(policy) {
+        return Err(error);
+    }
+
     let alg_family = alg.get_family();
     match alg_family {
         AlgorithmFamily::HMAC => sign_hmac(message, key, alg),
         AlgorithmFamily::RSA => sign_rsa(message, key, alg),
         AlgorithmFamily::EC => sign_ec(message, key, alg),
         AlgorithmFamily::OKP => sign_eddsa(message, key, alg),
+        AlgorithmFamily::MLDSA => sign_mldsa(message, key, alg),
+        AlgorithmFamily::Composite => sign_composite(message, key, alg),
+        AlgorithmFamily::BLS => sign_bls(message, key, alg),
         _ => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
     }
 }
@@ -35,14 +69,18 @@ pub fn sign(message: String, key: impl SignFromKey, alg: Algorithm) -> Result<St
 pub fn sign(message: String, key: Object, alg: Algorithm) -> Result<String, Error> {
     let alg_family = alg.get_family();
     match alg_family {
-        AlgorithmFamily::HMAC => sign_hmac(
-            message,
-            match HMACKey::from_js_object(key) {
+        AlgorithmFamily::HMAC => {
+            let hmac_key = match HMACKey::from_js_object(key) {
                 Ok(val) => val,
                 Err(error) => return Err(error),
-            },
-            alg,
-        ),
+            };
+
+            if let Err(error) = SignFromKey::check_key_policy(&hmac_key, &get_key_policy()) {
+                return Err(error);
+            }
+
+            sign_hmac(message, hmac_key, alg)
+        }
         AlgorithmFamily::RSA => sign_rsa(
             message,
             match RsaSigningKey::from_js_object(key) {
@@ -52,18 +90,28 @@ pub fn sign(message: String, key: Object, alg: Algorithm) -> Result<String, Erro
             alg,
         ),
         AlgorithmFamily::EC => sign_ec(message, key, alg),
-        AlgorithmFamily::OKP => sign_eddsa(
-            message,
-            match EDDSASigningKey::from_js_object(key) {
-                Ok(val) => val,
-                Err(error) => return Err(error),
-            },
-            alg,
-        ),
+        AlgorithmFamily::OKP => sign_eddsa(message, key, alg),
+        AlgorithmFamily::MLDSA => sign_mldsa(message, key, alg),
+        AlgorithmFamily::Composite => sign_composite(message, key, alg),
+        AlgorithmFamily::BLS => sign_bls(message, key, alg),
         _ => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
     }
 }
 
+/// Signs a digest of the content with a provided RSA or ECDSA Key. The digest has to be created
+/// with the hash function of the algorithm.
+#[cfg(not(feature = "wasm"))]
+pub fn sign_prehashed(
+    digest: &[u8],
+    key: impl SignPrehashed,
+    alg: Algorithm,
+) -> Result<String, Error> {
+    match alg.get_family() {
+        AlgorithmFamily::RSA | AlgorithmFamily::EC => key.sign_prehashed(digest, alg),
+        _ => Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+    }
+}
+
 #[cfg(not(feature = "wasm"))]
 pub fn get_signing_key(
     alg: Algorithm,
@@ -86,6 +134,17 @@ pub fn get_signing_key(
             Ok(val) => return Ok(Box::new(val)),
             Err(error) => return Err(error),
         },
+        Algorithm::BP256R1 | Algorithm::BP384R1 | Algorithm::BP512R1 => {
+            let curve = match BrainpoolCurve::from_algorithm(alg) {
+                Some(val) => val,
+                None => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+            };
+
+            match BrainpoolSigningKey::from_bytes(curve, key_bytes) {
+                Ok(val) => return Ok(Box::new(val)),
+                Err(error) => return Err(error),
+            }
+        }
         Algorithm::RS256 => match RsaSigningKey::from_bytes(key_bytes) {
             Ok(val) => return Ok(Box::new(val)),
             Err(error) => return Err(error),
@@ -113,9 +172,48 @@ pub fn get_signing_key(
         Algorithm::HS256 => return Err(Error::new(crate::errors::NOT_USING_ASYMMETRIC_KEYS)),
         Algorithm::HS384 => return Err(Error::new(crate::errors::NOT_USING_ASYMMETRIC_KEYS)),
         Algorithm::HS512 => return Err(Error::new(crate::errors::NOT_USING_ASYMMETRIC_KEYS)),
-        Algorithm::EdDSA => match EDDSASigningKey::from_bytes(key_bytes) {
+        Algorithm::EdDSA => match key_bytes.len() {
+            ed448_goldilocks_plus::SECRET_KEY_LENGTH => {
+                match Ed448SigningKey::from_bytes(key_bytes) {
+                    Ok(val) => return Ok(Box::new(val)),
+                    Err(error) => return Err(error),
+                }
+            }
+            _ => match EDDSASigningKey::from_bytes(key_bytes) {
+                Ok(val) => return Ok(Box::new(val)),
+                Err(error) => return Err(error),
+            },
+        },
+        Algorithm::MLDSA44 | Algorithm::MLDSA65 | Algorithm::MLDSA87 => {
+            let parameter_set = match MlDsaParameterSet::from_algorithm(alg) {
+                Some(val) => val,
+                None => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+            };
+
+            match MlDsaSigningKey::from_bytes(parameter_set, key_bytes) {
+                Ok(val) => return Ok(Box::new(val)),
+                Err(error) => return Err(error),
+            }
+        }
+        Algorithm::MLDSA44ES256
+        | Algorithm::MLDSA65ES256
+        | Algorithm::MLDSA87ES384
+        | Algorithm::MLDSA44Ed25519
+        | Algorithm::MLDSA65Ed25519
+        | Algorithm::MLDSA87Ed448 => match CompositeSigningKey::from_bytes(alg, key_bytes) {
             Ok(val) => return Ok(Box::new(val)),
             Err(error) => return Err(error),
         },
+        Algorithm::BLS12381MinPk | Algorithm::BLS12381MinSig => {
+            let variant = match BlsVariant::from_algorithm(alg) {
+                Some(val) => val,
+                None => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+            };
+
+            match BlsSigningKey::from_bytes(variant, key_bytes) {
+                Ok(val) => return Ok(Box::new(val)),
+                Err(error) => return Err(error),
+            }
+        }
     }
 }
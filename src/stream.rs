@@ -0,0 +1,277 @@
+use crate::{
+    algorithms::Algorithm,
+    crypto::{policy::get_key_policy, SignPrehashed, SignStream, VerifyPrehashed, VerifyStream},
+};
+use fi_common::error::Error;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use std::io::{ErrorKind, Read};
+
+/// Size of the chunks read from a [`Read`] source
+const CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Clone)]
+enum HasherState {
+    Sha256(Sha256),
+    Sha384(Sha384),
+    Sha512(Sha512),
+    HmacSha256(Hmac<Sha256>),
+    HmacSha384(Hmac<Sha384>),
+    HmacSha512(Hmac<Sha512>),
+}
+
+/// Running hash of streamed content. It is the message digest of the algorithm for RSA and
+/// ECDSA, the SHA-512 prehash for Ed25519ph and the MAC for HMAC.
+#[derive(Clone)]
+pub struct StreamHasher {
+    state: HasherState,
+}
+
+impl StreamHasher {
+    /// Message digest of the algorithm, SHA-512 for [`crate::algorithms::Algorithm::EdDSA`]
+    pub(crate) fn digest(alg: Algorithm) -> Result<StreamHasher, Error> {
+        let state = match alg {
+            Algorithm::RS256
+            | Algorithm::PS256
+            | Algorithm::ES256
+            | Algorithm::ES256K
+            | Algorithm::BP256R1 => HasherState::Sha256(Sha256::new()),
+            Algorithm::RS384 | Algorithm::PS384 | Algorithm::ES384 | Algorithm::BP384R1 => {
+                HasherState::Sha384(Sha384::new())
+            }
+            Algorithm::RS512
+            | Algorithm::PS512
+            | Algorithm::ES512
+            | Algorithm::BP512R1
+            | Algorithm::EdDSA => HasherState::Sha512(Sha512::new()),
+            _ => {
+                fi_common::logger::error(
+                    format!("{} doesn't support streamed content", alg.to_str()).as_str(),
+                );
+                return Err(Error::new(crate::errors::STREAMING_NOT_SUPPORTED));
+            }
+        };
+
+        Ok(StreamHasher { state })
+    }
+
+    /// MAC of the HMAC algorithm with the secret
+    pub(crate) fn hmac(secret: &[u8], alg: Algorithm) -> Result<StreamHasher, Error> {
+        let state = match alg {
+            Algorithm::HS256 => Hmac::<Sha256>::new_from_slice(secret).map(HasherState::HmacSha256),
+            Algorithm::HS384 => Hmac::<Sha384>::new_from_slice(secret).map(HasherState::HmacSha384),
+            Algorithm::HS512 => Hmac::<Sha512>::new_from_slice(secret).map(HasherState::HmacSha512),
+            _ => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+        };
+
+        match state {
+            Ok(val) => Ok(StreamHasher { state: val }),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Err(Error::new(crate::errors::HMAC_KEY_ERROR))
+            }
+        }
+    }
+
+    /// Add the next chunk of the content
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.state {
+            HasherState::Sha256(hasher) => hasher.update(data),
+            HasherState::Sha384(hasher) => hasher.update(data),
+            HasherState::Sha512(hasher) => hasher.update(data),
+            HasherState::HmacSha256(mac) => mac.update(data),
+            HasherState::HmacSha384(mac) => mac.update(data),
+            HasherState::HmacSha512(mac) => mac.update(data),
+        }
+    }
+
+    /// Message digest of the content
+    pub(crate) fn finalize_digest(self) -> Result<Vec<u8>, Error> {
+        match self.state {
+            HasherState::Sha256(hasher) => Ok(hasher.finalize().to_vec()),
+            HasherState::Sha384(hasher) => Ok(hasher.finalize().to_vec()),
+            HasherState::Sha512(hasher) => Ok(hasher.finalize().to_vec()),
+            _ => Err(Error::new(crate::errors::STREAMING_NOT_SUPPORTED)),
+        }
+    }
+
+    /// SHA-512 state of the content, the prehash of Ed25519ph
+    pub(crate) fn into_prehash(self) -> Result<Sha512, Error> {
+        match self.state {
+            HasherState::Sha512(hasher) => Ok(hasher),
+            _ => Err(Error::new(crate::errors::STREAMING_NOT_SUPPORTED)),
+        }
+    }
+
+    /// MAC of the content
+    pub(crate) fn finalize_mac(self) -> Result<Vec<u8>, Error> {
+        match self.state {
+            HasherState::HmacSha256(mac) => Ok(mac.finalize().into_bytes().to_vec()),
+            HasherState::HmacSha384(mac) => Ok(mac.finalize().into_bytes().to_vec()),
+            HasherState::HmacSha512(mac) => Ok(mac.finalize().into_bytes().to_vec()),
+            _ => Err(Error::new(crate::errors::STREAMING_NOT_SUPPORTED)),
+        }
+    }
+
+    /// Compare the MAC of the content with the expected one in constant time
+    pub(crate) fn verify_mac(self, expected: &[u8]) -> Result<bool, Error> {
+        let verify_result = match self.state {
+            HasherState::HmacSha256(mac) => mac.verify_slice(expected),
+            HasherState::HmacSha384(mac) => mac.verify_slice(expected),
+            HasherState::HmacSha512(mac) => mac.verify_slice(expected),
+            _ => return Err(Error::new(crate::errors::STREAMING_NOT_SUPPORTED)),
+        };
+
+        match verify_result {
+            Ok(()) => Ok(true),
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                Ok(false)
+            }
+        }
+    }
+}
+
+/// Sign the streamed content as a digest, used by the RSA and ECDSA keys
+pub(crate) fn sign_digest_stream(
+    key: &impl SignPrehashed,
+    hasher: StreamHasher,
+    alg: Algorithm,
+) -> Result<String, Error> {
+    match hasher.finalize_digest() {
+        Ok(val) => key.sign_prehashed(&val, alg),
+        Err(error) => Err(error),
+    }
+}
+
+/// Verify the signature of the streamed content as a digest, used by the RSA and ECDSA keys
+pub(crate) fn verify_digest_stream(
+    key: &impl VerifyPrehashed,
+    hasher: StreamHasher,
+    signature: String,
+    alg: Algorithm,
+) -> Result<bool, Error> {
+    match hasher.finalize_digest() {
+        Ok(val) => key.verify_prehashed(&val, signature, alg),
+        Err(error) => Err(error),
+    }
+}
+
+fn read_into(mut reader: impl Read, hasher: &mut StreamHasher) -> Result<(), Error> {
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(length) => hasher.update(&buffer[..length]),
+            Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+            Err(error) => {
+                fi_common::logger::error(error.to_string().as_str());
+                return Err(Error::new(crate::errors::STREAM_READ_FAILED));
+            }
+        }
+    }
+}
+
+/// Signer of content streamed in chunks. The signature is the same as the one
+/// [`crate::signer::sign`] creates for the whole content, hedged ECDSA and P-521 signatures
+/// still use fresh randomness. Ed25519 keys have to use [`crate::crypto::eddsa::Ed25519Variant::Ph`].
+/// The key is checked against [`get_key_policy`] when the signer is created.
+pub struct StreamSigner<K: SignStream> {
+    key: K,
+    alg: Algorithm,
+    hasher: StreamHasher,
+}
+
+impl<K: SignStream> StreamSigner<K> {
+    /// Start signing content streamed with the key and the algorithm
+    pub fn new(key: K, alg: Algorithm) -> Result<StreamSigner<K>, Error> {
+        if let Err(error) = key.check_key_policy(&get_key_policy()) {
+            return Err(error);
+        }
+
+        match key.sign_stream_hasher(alg) {
+            Ok(hasher) => Ok(StreamSigner { key, alg, hasher }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Add the next chunk of the content
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    /// Sign all of the content streamed so far
+    pub fn finalize(self) -> Result<String, Error> {
+        self.key.sign_stream(self.hasher, self.alg)
+    }
+}
+
+/// Verifier of content streamed in chunks, with the same result as [`crate::verifier::verify`]
+/// for the whole content. The key is checked against [`get_key_policy`] when the verifier is
+/// created.
+pub struct StreamVerifier<K: VerifyStream> {
+    key: K,
+    alg: Algorithm,
+    hasher: StreamHasher,
+}
+
+impl<K: VerifyStream> StreamVerifier<K> {
+    /// Start verifying content streamed with the key and the algorithm
+    pub fn new(key: K, alg: Algorithm) -> Result<StreamVerifier<K>, Error> {
+        if let Err(error) = key.check_key_policy(&get_key_policy()) {
+            return Err(error);
+        }
+
+        match key.verify_stream_hasher(alg) {
+            Ok(hasher) => Ok(StreamVerifier { key, alg, hasher }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Add the next chunk of the content
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    /// Verify the signature of all of the content streamed so far
+    pub fn finalize(self, signature: String) -> Result<bool, Error> {
+        self.key.verify_stream(self.hasher, signature, self.alg)
+    }
+}
+
+/// Sign all of the content of the reader, see [`StreamSigner`]
+pub fn sign_reader(
+    reader: impl Read,
+    key: impl SignStream,
+    alg: Algorithm,
+) -> Result<String, Error> {
+    let mut signer = match StreamSigner::new(key, alg) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    if let Err(error) = read_into(reader, &mut signer.hasher) {
+        return Err(error);
+    }
+
+    signer.finalize()
+}
+
+/// Verify the signature of all of the content of the reader, see [`StreamVerifier`]
+pub fn verify_reader(
+    reader: impl Read,
+    signature: String,
+    key: impl VerifyStream,
+    alg: Algorithm,
+) -> Result<bool, Error> {
+    let mut verifier = match StreamVerifier::new(key, alg) {
+        Ok(val) => val,
+        Err(error) => return Err(error),
+    };
+
+    if let Err(error) = read_into(reader, &mut verifier.hasher) {
+        return Err(error);
+    }
+
+    verifier.finalize(signature)
+}
@@ -1,18 +1,33 @@
 #[cfg(not(feature = "wasm"))]
 use crate::crypto::ecdsa::{
-    _256k::P256kVerifyingKey, _256::P256VerifyingKey, _384::P384VerifyingKey,
+    brainpool::{BrainpoolCurve, BrainpoolVerifyingKey},
+    _256k::P256kVerifyingKey,
+    _256::P256VerifyingKey,
+    _384::P384VerifyingKey,
     _512::P512VerifyingKey,
 };
 #[cfg(feature = "wasm")]
 use crate::crypto::hmac::HMACKey;
+use crate::crypto::policy::get_key_policy;
 #[cfg(not(feature = "wasm"))]
-use crate::crypto::VerifyFromKey;
-use crate::crypto::{eddsa::EDDSAVerifyingKey, rsa::RsaVerifyingKey};
+use crate::crypto::policy::KeyPolicy;
+#[cfg(not(feature = "wasm"))]
+use crate::crypto::{
+    bls::BlsVerifyingKey, composite::CompositeVerifyingKey, mldsa::MlDsaVerifyingKey,
+};
+#[cfg(not(feature = "wasm"))]
+use crate::crypto::{ed448::Ed448VerifyingKey, eddsa::EDDSAVerifyingKey};
+#[cfg(not(feature = "wasm"))]
+use crate::crypto::VerifyPrehashed;
+use crate::crypto::{rsa::RsaVerifyingKey, VerifyFromKey};
 #[cfg(feature = "wasm")]
 use crate::errors::UNKNOWN_ALGORITHM;
 use crate::{
     algorithms::{Algorithm, AlgorithmFamily},
-    crypto::{ecdsa::verify_ec, eddsa::verify_eddsa, hmac::verify_hmac, rsa::verify_rsa},
+    crypto::{
+        bls::verify_bls, composite::verify_composite, ecdsa::verify_ec, eddsa::verify_eddsa,
+        hmac::verify_hmac, mldsa::verify_mldsa, rsa::verify_rsa,
+    },
 };
 use fi_common::error::Error;
 #[cfg(feature = "wasm")]
@@ -20,7 +35,8 @@ use js_sys::Object;
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::wasm_bindgen;
 
-/// Verify the signature with a provided Key
+/// Verify the signature with a provided Key. The key is checked against [`get_key_policy`] first.
+/// Signatures are checked with the rules of [`crate::crypto::policy::get_verification_mode`].
 #[cfg(not(feature = "wasm"))]
 pub fn verify(
     message: String,
@@ -28,12 +44,31 @@ pub fn verify(
     key: impl VerifyFromKey,
     alg: Algorithm,
 ) -> Result<bool, Error> {
+    verify_with_policy(message, signature, key, alg, &get_key_policy())
+}
+
+/// Verify the signature with a provided Key after checking the key against the provided policy
+#[cfg(not(feature = "wasm"))]
+pub fn verify_with_policy(
+    message: String,
+    signature: String,
+    key: impl VerifyFromKey,
+    alg: Algorithm,
+    policy: &KeyPolicy,
+) -> Result<bool, Error> {
+    if let Err(error) = key.check_key_policy(policy) {
+        return Err(error);
+    }
+
     let alg_family = alg.get_family();
     match alg_family {
         AlgorithmFamily::HMAC => verify_hmac(message, signature, key, alg),
         AlgorithmFamily::EC => verify_ec(message, signature, key, alg),
         AlgorithmFamily::RSA => verify_rsa(message, signature, key, alg),
         AlgorithmFamily::OKP => verify_eddsa(message, signature, key, alg),
+        AlgorithmFamily::MLDSA => verify_mldsa(message, signature, key, alg),
+        AlgorithmFamily::Composite => verify_composite(message, signature, key, alg),
+        AlgorithmFamily::BLS => verify_bls(message, signature, key, alg),
         _ => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
     }
 }
@@ -48,15 +83,18 @@ pub fn verify(
 ) -> Result<bool, Error> {
     let alg_family = alg.get_family();
     match alg_family {
-        AlgorithmFamily::HMAC => verify_hmac(
-            message,
-            signature,
-            match HMACKey::from_js_object(key) {
+        AlgorithmFamily::HMAC => {
+            let hmac_key = match HMACKey::from_js_object(key) {
                 Ok(val) => val,
                 Err(error) => return Err(error),
-            },
-            alg,
-        ),
+            };
+
+            if let Err(error) = hmac_key.check_key_policy(&get_key_policy()) {
+                return Err(error);
+            }
+
+            verify_hmac(message, signature, hmac_key, alg)
+        }
         AlgorithmFamily::EC => verify_ec(message, signature, key, alg),
         AlgorithmFamily::RSA => verify_rsa(
             message,
@@ -67,19 +105,34 @@ pub fn verify(
             },
             alg,
         ),
-        AlgorithmFamily::OKP => verify_eddsa(
-            message,
-            signature,
-            match EDDSAVerifyingKey::from_js_object(key) {
-                Ok(val) => val,
-                Err(error) => return Err(error),
-            },
-            alg,
-        ),
+        AlgorithmFamily::OKP => verify_eddsa(message, signature, key, alg),
+        AlgorithmFamily::MLDSA => verify_mldsa(message, signature, key, alg),
+        AlgorithmFamily::Composite => verify_composite(message, signature, key, alg),
+        AlgorithmFamily::BLS => verify_bls(message, signature, key, alg),
         _ => return Err(Error::new(UNKNOWN_ALGORITHM)),
     }
 }
 
+/// Verify the signature of a digest of the content with a provided RSA or ECDSA Key. The digest
+/// has to be created with the hash function of the algorithm. The key is checked against
+/// [`get_key_policy`] first.
+#[cfg(not(feature = "wasm"))]
+pub fn verify_prehashed(
+    digest: &[u8],
+    signature: String,
+    key: impl VerifyPrehashed,
+    alg: Algorithm,
+) -> Result<bool, Error> {
+    if let Err(error) = key.check_key_policy(&get_key_policy()) {
+        return Err(error);
+    }
+
+    match alg.get_family() {
+        AlgorithmFamily::RSA | AlgorithmFamily::EC => key.verify_prehashed(digest, signature, alg),
+        _ => Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+    }
+}
+
 #[cfg(not(feature = "wasm"))]
 pub fn get_verifying_key(
     alg: Algorithm,
@@ -102,6 +155,17 @@ pub fn get_verifying_key(
             Ok(val) => return Ok(Box::new(val)),
             Err(error) => return Err(error),
         },
+        Algorithm::BP256R1 | Algorithm::BP384R1 | Algorithm::BP512R1 => {
+            let curve = match BrainpoolCurve::from_algorithm(alg) {
+                Some(val) => val,
+                None => return Err(Error::new(crate::errors::UNKNOWN_ALGORITHM)),
+            };
+
+            match BrainpoolVerifyingKey::from_bytes(curve, key_bytes) {
+                Ok(val) => return Ok(Box::new(val)),
+                Err(error) => return Err(error),
+            }
+        }
         Algorithm::RS256 => match RsaVerifyingKey::from_bytes(key_bytes) {
             Ok(val) => return Ok(Box::new(val)),
             Err(error) => return Err(error),
@@ -129,9 +193,38 @@ pub fn get_verifying_key(
         Algorithm::HS256 => return Err(Error::new(crate::errors::NOT_USING_ASYMMETRIC_KEYS)),
         Algorithm::HS384 => return Err(Error::new(crate::errors::NOT_USING_ASYMMETRIC_KEYS)),
         Algorithm::HS512 => return Err(Error::new(crate::errors::NOT_USING_ASYMMETRIC_KEYS)),
-        Algorithm::EdDSA => match EDDSAVerifyingKey::from_bytes(key_bytes) {
+        Algorithm::EdDSA => match key_bytes.len() {
+            ed448_goldilocks_plus::PUBLIC_KEY_LENGTH => {
+                match Ed448VerifyingKey::from_bytes(key_bytes) {
+                    Ok(val) => return Ok(Box::new(val)),
+                    Err(error) => return Err(error),
+                }
+            }
+            _ => match EDDSAVerifyingKey::from_bytes(key_bytes) {
+                Ok(val) => return Ok(Box::new(val)),
+                Err(error) => return Err(error),
+            },
+        },
+        Algorithm::MLDSA44 | Algorithm::MLDSA65 | Algorithm::MLDSA87 => {
+            match MlDsaVerifyingKey::from_bytes(key_bytes) {
+                Ok(val) => return Ok(Box::new(val)),
+                Err(error) => return Err(error),
+            }
+        }
+        Algorithm::MLDSA44ES256
+        | Algorithm::MLDSA65ES256
+        | Algorithm::MLDSA87ES384
+        | Algorithm::MLDSA44Ed25519
+        | Algorithm::MLDSA65Ed25519
+        | Algorithm::MLDSA87Ed448 => match CompositeVerifyingKey::from_bytes(alg, key_bytes) {
             Ok(val) => return Ok(Box::new(val)),
             Err(error) => return Err(error),
         },
+        Algorithm::BLS12381MinPk | Algorithm::BLS12381MinSig => {
+            match BlsVerifyingKey::from_bytes(key_bytes) {
+                Ok(val) => return Ok(Box::new(val)),
+                Err(error) => return Err(error),
+            }
+        }
     }
 }
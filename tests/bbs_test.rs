@@ -0,0 +1,276 @@
+use fi_digital_signatures::{
+    crypto::bbs::{BbsSigningKey, BbsVerifyingKey},
+    errors,
+};
+
+// BLS12-381-SHA-256 test vectors of the IRTF CFRG BBS signatures draft
+const KEY_MATERIAL: &'static str = "746869732d49532d6a7573742d616e2d546573742d494b4d2d746f2d67656e65726174652d246528724074232d6b6579";
+const KEY_INFO: &'static str = "746869732d49532d736f6d652d6b65792d6d657461646174612d746f2d62652d757365642d696e2d746573742d6b65792d67656e";
+const PRIVATE_KEY: &'static str =
+    "60e55110f76883a13d030b2f6bd11883422d5abde717569fc0731f51237169fc";
+const PUBLIC_KEY: &'static str = "a820f230f6ae38503b86c70dc50b61c58a77e45c39ab25c0652bbaa8fa136f2851bd4781c9dcde39fc9d1d52c9e60268061e7d7632171d91aa8d460acee0e96f1e7c4cfb12d3ff9ab5d5dc91c277db75c845d649ef3c4f63aebc364cd55ded0c";
+
+const HEADER: &'static str = "11223344556677889900aabbccddeeff";
+const PRESENTATION_HEADER: &'static str =
+    "bed231d880675ed101ead304512e043ade9958dd0241ea70b4b3957fba941501";
+const MESSAGES: [&'static str; 10] = [
+    "9872ad089e452c7b6e283dfac2a80d58e8d0ff71cc4d5e310a1debdda4a45f02",
+    "c344136d9ab02da4dd5908bbba913ae6f58c2cc844b802a6f811f5fb075f9b80",
+    "7372e9daa5ed31e6cd5c825eac1b855e84476a1d94932aa348e07b73",
+    "77fe97eb97a1ebe2e81e4e3597a3ee740a66e9ef2412472c",
+    "496694774c5604ab1b2544eababcf0f53278ff50",
+    "515ae153e22aae04ad16f759e07237b4",
+    "d183ddc6e2665aa4e2f088af",
+    "ac55fb33a75909ed",
+    "96012096",
+    "",
+];
+
+const SIGNATURE_SINGLE: &'static str = "84773160b824e194073a57493dac1a20b667af70cd2352d8af241c77658da5253aa8458317cca0eae615690d55b1f27164657dcafee1d5c1973947aa70e2cfbb4c892340be5969920d0916067b4565a0";
+const SIGNATURE_MULTI: &'static str = "8339b285a4acd89dec7777c09543a43e3cc60684b0a6f8ab335da4825c96e1463e28f8c5f4fd0641d19cec5920d3a8ff4bedb6c9691454597bbd298288abed3632078557b2ace7d44caed846e1a0a1e8";
+
+// Proofs of the signatures above created with the mocked random scalars of the draft
+// fixtures, disclosing the single message, all messages and messages 0, 2, 4 and 6
+const PROOF_SINGLE: &'static str = "94916292a7a6bade28456c601d3af33fcf39278d6594b467e128a3f83686a104ef2b2fcf72df0215eeaf69262ffe8194a19fab31a82ddbe06908985abc4c9825788b8a1610942d12b7f5debbea8985296361206dbace7af0cc834c80f33e0aadaeea5597befbb651827b5eed5a66f1a959bb46cfd5ca1a817a14475960f69b32c54db7587b5ee3ab665fbd37b506830a49f21d592f5e634f47cee05a025a2f8f94e73a6c15f02301d1178a92873b6e8634bafe4983c3e15a663d64080678dbf29417519b78af042be2b3e1c4d08b8d520ffab008cbaaca5671a15b22c239b38e940cfeaa5e72104576a9ec4a6fad78c532381aeaa6fb56409cef56ee5c140d455feeb04426193c57086c9b6d397d9418";
+const PROOF_ALL_DISCLOSED: &'static str = "b1f468aec2001c4f54cb56f707c6222a43e5803a25b2253e67b2210ab2ef9eab52db2d4b379935c4823281eaf767fd37b08ce80dc65de8f9769d27099ae649ad4c9b4bd2cc23edcba52073a298087d2495e6d57aaae051ef741adf1cbce65c64a73c8c97264177a76c4a03341956d2ae45ed3438ce598d5cda4f1bf9507fecef47855480b7b30b5e4052c92a4360110c67327365763f5aa9fb85ddcbc2975449b8c03db1216ca66b310f07d0ccf12ab460cdc6003b677fed36d0a23d0818a9d4d098d44f749e91008cf50e8567ef936704c8277b7710f41ab7e6e16408ab520edc290f9801349aee7b7b4e318e6a76e028e1dea911e2e7baec6a6a174da1a22362717fbae1cd961d7bf4adce1d31c2ab";
+const PROOF_SELECTIVE: &'static str = "a2ed608e8e12ed21abc2bf154e462d744a367c7f1f969bdbf784a2a134c7db2d340394223a5397a3011b1c340ebc415199462ba6f31106d8a6da8b513b37a47afe93c9b3474d0d7a354b2edc1b88818b063332df774c141f7a07c48fe50d452f897739228c88afc797916dca01e8f03bd9c5375c7a7c59996e514bb952a436afd24457658acbaba5ddac2e693ac481356918cd38025d86b28650e909defe9604a7259f44386b861608be742af7775a2e71a6070e5836f5f54dc43c60096834a5b6da295bf8f081f72b7cdf7f3b4347fb3ff19edaa9e74055c8ba46dbcb7594fb2b06633bb5324192eb9be91be0d33e453b4d3127459de59a5e2193c900816f049a02cb9127dac894418105fa1641d5a206ec9c42177af9316f433417441478276ca0303da8f941bf2e0222a43251cf5c2bf6eac1961890aa740534e519c1767e1223392a3a286b0f4d91f7f25217a7862b8fcc1810cdcfddde2a01c80fcc90b632585fec12dc4ae8fea1918e9ddeb9414623a457e88f53f545841f9d5dcb1f8e160d1560770aa79d65e2eca8edeaecb73fb7e995608b820c4a64de6313a370ba05dc25ed7c1d185192084963652f2870341bdaa4b1a37f8c06348f38a4f80c5a2650a21d59f09e8305dcd3fc3ac30e2a";
+
+fn messages() -> Vec<Vec<u8>> {
+    MESSAGES
+        .iter()
+        .map(|message| hex::decode(message).unwrap())
+        .collect()
+}
+
+#[cfg(not(feature = "wasm"))]
+fn test_key() -> BbsSigningKey {
+    BbsSigningKey::from_key_material(
+        &hex::decode(KEY_MATERIAL).unwrap(),
+        &hex::decode(KEY_INFO).unwrap(),
+        None,
+    )
+    .unwrap()
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn bbs_key_generation() {
+    let signing_key = test_key();
+    assert_eq!(hex::encode(signing_key.to_bytes().as_slice()), PRIVATE_KEY);
+    assert_eq!(
+        hex::encode(signing_key.verifying_key().to_bytes()),
+        PUBLIC_KEY
+    );
+
+    let signing_key = BbsSigningKey::from_bytes(&hex::decode(PRIVATE_KEY).unwrap()).unwrap();
+    assert_eq!(
+        hex::encode(signing_key.verifying_key().to_bytes()),
+        PUBLIC_KEY
+    );
+
+    let generated = BbsSigningKey::generate().unwrap();
+    let verifying_key = BbsVerifyingKey::from_bytes(&generated.verifying_key().to_bytes()).unwrap();
+    assert_eq!(
+        verifying_key.to_bytes(),
+        generated.verifying_key().to_bytes()
+    );
+
+    match BbsSigningKey::from_key_material(&[0u8; 31], &[], None) {
+        Ok(_) => panic!("Key material below 32 bytes must be rejected"),
+        Err(error) => assert_eq!(error.to_string(), errors::BBS_KEY_MATERIAL_TOO_SHORT),
+    }
+
+    assert!(BbsSigningKey::from_bytes(&[0u8; 32]).is_err());
+    assert!(BbsVerifyingKey::from_bytes(&hex::decode(PUBLIC_KEY).unwrap()[..48]).is_err());
+
+    let mut identity = [0u8; 96];
+    identity[0] = 0xc0;
+    assert!(BbsVerifyingKey::from_bytes(&identity).is_err());
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn bbs_sign_and_verify() {
+    let signing_key = test_key();
+    let verifying_key = BbsVerifyingKey::from_bytes(&hex::decode(PUBLIC_KEY).unwrap()).unwrap();
+    let header = hex::decode(HEADER).unwrap();
+    let messages = messages();
+
+    let signature = signing_key.sign(&header, &messages[..1]).unwrap();
+    assert_eq!(hex::encode(&signature), SIGNATURE_SINGLE);
+    assert!(verifying_key
+        .verify(&signature, &header, &messages[..1])
+        .unwrap());
+
+    let signature = signing_key.sign(&header, &messages).unwrap();
+    assert_eq!(hex::encode(&signature), SIGNATURE_MULTI);
+    assert!(verifying_key
+        .verify(&signature, &header, &messages)
+        .unwrap());
+
+    assert!(!verifying_key.verify(&signature, &[], &messages).unwrap());
+    assert!(!verifying_key
+        .verify(&signature, &header, &messages[..9])
+        .unwrap());
+
+    let mut modified = messages.clone();
+    modified.swap(0, 1);
+    assert!(!verifying_key
+        .verify(&signature, &header, &modified)
+        .unwrap());
+
+    let other_key = BbsSigningKey::generate().unwrap().verifying_key();
+    assert!(!other_key.verify(&signature, &header, &messages).unwrap());
+
+    match verifying_key.verify(&signature[..79], &header, &messages) {
+        Ok(_) => panic!("Truncated signature must be rejected"),
+        Err(error) => assert_eq!(error.to_string(), errors::SIGNATURE_IDENTIFICATION_FAILED),
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn bbs_proof_vectors() {
+    let verifying_key = BbsVerifyingKey::from_bytes(&hex::decode(PUBLIC_KEY).unwrap()).unwrap();
+    let header = hex::decode(HEADER).unwrap();
+    let presentation_header = hex::decode(PRESENTATION_HEADER).unwrap();
+    let messages = messages();
+
+    assert!(verifying_key
+        .verify_proof(
+            &hex::decode(PROOF_SINGLE).unwrap(),
+            &header,
+            &presentation_header,
+            &messages[..1],
+            &[0],
+        )
+        .unwrap());
+
+    let all: Vec<usize> = (0..messages.len()).collect();
+    assert!(verifying_key
+        .verify_proof(
+            &hex::decode(PROOF_ALL_DISCLOSED).unwrap(),
+            &header,
+            &presentation_header,
+            &messages,
+            &all,
+        )
+        .unwrap());
+
+    let disclosed_indexes = [0, 2, 4, 6];
+    let disclosed: Vec<Vec<u8>> = disclosed_indexes
+        .iter()
+        .map(|index| messages[*index].clone())
+        .collect();
+    let proof = hex::decode(PROOF_SELECTIVE).unwrap();
+    assert!(verifying_key
+        .verify_proof(
+            &proof,
+            &header,
+            &presentation_header,
+            &disclosed,
+            &disclosed_indexes,
+        )
+        .unwrap());
+
+    assert!(!verifying_key
+        .verify_proof(&proof, &header, &[], &disclosed, &disclosed_indexes)
+        .unwrap());
+    assert!(!verifying_key
+        .verify_proof(
+            &proof,
+            &header,
+            &presentation_header,
+            &disclosed,
+            &[0, 2, 4, 7],
+        )
+        .unwrap());
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn bbs_selective_disclosure() {
+    let signing_key = BbsSigningKey::generate().unwrap();
+    let verifying_key = signing_key.verifying_key();
+    let header = hex::decode(HEADER).unwrap();
+    let presentation_header = hex::decode(PRESENTATION_HEADER).unwrap();
+    let messages = messages();
+
+    let signature = signing_key.sign(&header, &messages).unwrap();
+
+    let disclosed_indexes = [1, 3, 9];
+    let disclosed: Vec<Vec<u8>> = disclosed_indexes
+        .iter()
+        .map(|index| messages[*index].clone())
+        .collect();
+
+    let proof = verifying_key
+        .create_proof(
+            &signature,
+            &header,
+            &presentation_header,
+            &messages,
+            &disclosed_indexes,
+        )
+        .unwrap();
+    assert_eq!(proof.len(), 272 + 32 * 7);
+    assert!(verifying_key
+        .verify_proof(
+            &proof,
+            &header,
+            &presentation_header,
+            &disclosed,
+            &disclosed_indexes,
+        )
+        .unwrap());
+
+    // proofs are randomized and unlinkable
+    let other_proof = verifying_key
+        .create_proof(
+            &signature,
+            &header,
+            &presentation_header,
+            &messages,
+            &disclosed_indexes,
+        )
+        .unwrap();
+    assert_ne!(proof, other_proof);
+
+    let mut modified = disclosed.clone();
+    modified[0] = b"modified".to_vec();
+    assert!(!verifying_key
+        .verify_proof(
+            &proof,
+            &header,
+            &presentation_header,
+            &modified,
+            &disclosed_indexes,
+        )
+        .unwrap());
+
+    let no_messages: [&[u8]; 0] = [];
+    let proof = verifying_key
+        .create_proof(&signature, &header, &[], &messages, &[])
+        .unwrap();
+    assert!(verifying_key
+        .verify_proof(&proof, &header, &[], &no_messages, &[])
+        .unwrap());
+
+    for indexes in [&[3usize, 1][..], &[1, 1], &[10]] {
+        match verifying_key.create_proof(
+            &signature,
+            &header,
+            &presentation_header,
+            &messages,
+            indexes,
+        ) {
+            Ok(_) => panic!("Invalid disclosed indexes must be rejected"),
+            Err(error) => assert_eq!(error.to_string(), errors::BBS_DISCLOSED_INDEXES),
+        }
+    }
+
+    match verifying_key.verify_proof(&proof[..271], &header, &[], &no_messages, &[]) {
+        Ok(_) => panic!("Truncated proof must be rejected"),
+        Err(error) => assert_eq!(error.to_string(), errors::BBS_PROOF_IDENTIFICATION_FAILED),
+    }
+}
@@ -0,0 +1,281 @@
+use fi_digital_signatures::{
+    algorithms::Algorithm,
+    crypto::{
+        any::AnyVerifyingKey,
+        bls::{
+            aggregate_public_keys, aggregate_signatures, aggregate_verify, fast_aggregate_verify,
+            BlsSigningKey, BlsVariant, BlsVerifyingKey,
+        },
+    },
+    errors,
+    jwk::Jwk,
+    signer::{get_signing_key, sign},
+    verifier::{get_verifying_key, verify},
+};
+
+// Ethereum consensus spec BLS test vector (min-pk, proof of possession ciphersuite)
+const ETH_PRIVATE_KEY: &'static str =
+    "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3";
+const ETH_PUBLIC_KEY: &'static str = "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a";
+const ETH_SIGNATURE: &'static str = "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55";
+
+const CONTENT: &'static str =
+    "eyJhbGciOiJCTFMxMjM4MU1pblBrIiwidHlwIjoiSldUIn0.eyJzdWIiOiIxMjM0NTY3ODkwIn0";
+const KEY_INFO: &'static str = "fi-digital-signatures";
+
+// Keys derived from the input keying material 0x00..0x1f and KEY_INFO, with signatures of
+// CONTENT and proofs of possession, created with the blst library
+const PRIVATE_KEY: &'static str =
+    "2566566defec0172fd543e4ddcf6a8e344c4c9c08ca279ec2b5388efb5ab3b40";
+const PUBLIC_KEY_MIN_PK: &'static str = "80c848809620b1623f882ce1e7cbada41a9852fe8cd4a7f39eb9686204c973c4a3c0ba1a2a7e53b35f237869c9ac4f82";
+const SIGNATURE_MIN_PK: &'static str = "a32e707aa60bbe9bab7987ba1e84f60d40624f78aa6d008c8796ecf2394cffcd0173999b912ea54d05adadf438b39b9a09c122379bb3ebf59bd9a991ea617887cb0837d73aa0660db19d11b9a75929c46e2d5c5c280de0f5590377be5baf82d9";
+const POP_MIN_PK: &'static str = "8b6e0376b3aa254ca539ec0369bb048613e4cbfd80d38229eed82296f542d52d355ce537b20352bef2132ddb895cb8d218d81f90aef17e44883863222dfb645ad1092a30e8369d412f8919a0e756fc6182b8cd991b278da3e2e8cc0c045dd85f";
+const PUBLIC_KEY_MIN_SIG: &'static str = "8a9ca6f323a2240a16c090ac22c760092cd53428eee644abc5bd5dfc313f150fc240defa2f50bd8c1e57ca3070193dcf0c9a240619d57e1e1661cab6f286b6e2cf46b8def739ccdfd200440a458fef21aad0231b8d6782d11b37384298782026";
+const SIGNATURE_MIN_SIG: &'static str = "a61920cc9594a9a03efda5177137b42c4a2b07892c1d33771df2fb8d6f1615b2137b3dcd1fd27ae830480d84cf086ec3";
+const POP_MIN_SIG: &'static str = "93768195d5603bd8782f8548eb158c710282cdbc2b277e4327a476adbf6ca403217e00d7831944416dc235b781e3808d";
+
+fn test_vectors() -> Vec<(BlsVariant, &'static str, &'static str, &'static str)> {
+    vec![
+        (
+            BlsVariant::MinPk,
+            PUBLIC_KEY_MIN_PK,
+            SIGNATURE_MIN_PK,
+            POP_MIN_PK,
+        ),
+        (
+            BlsVariant::MinSig,
+            PUBLIC_KEY_MIN_SIG,
+            SIGNATURE_MIN_SIG,
+            POP_MIN_SIG,
+        ),
+    ]
+}
+
+fn generate_keys(variant: BlsVariant, count: usize) -> (Vec<BlsSigningKey>, Vec<BlsVerifyingKey>) {
+    let signing_keys: Vec<BlsSigningKey> = (0..count)
+        .map(|_| BlsSigningKey::generate(variant).unwrap())
+        .collect();
+    let verifying_keys = signing_keys
+        .iter()
+        .map(|key| key.verifying_key().unwrap())
+        .collect();
+
+    (signing_keys, verifying_keys)
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn bls_known_answer_tests() {
+    let signing_key =
+        BlsSigningKey::from_bytes(BlsVariant::MinPk, &hex::decode(ETH_PRIVATE_KEY).unwrap())
+            .unwrap();
+    assert_eq!(
+        hex::encode(signing_key.verifying_key().unwrap().to_bytes().unwrap()),
+        ETH_PUBLIC_KEY
+    );
+    assert_eq!(
+        hex::encode(signing_key.sign_message(&[0u8; 32]).unwrap()),
+        ETH_SIGNATURE
+    );
+
+    let ikm: Vec<u8> = (0u8..32).collect();
+    for (variant, public_key, signature, pop) in test_vectors() {
+        let signing_key = BlsSigningKey::from_ikm(variant, &ikm, KEY_INFO.as_bytes()).unwrap();
+        assert!(signing_key.variant() == variant);
+        assert_eq!(hex::encode(signing_key.to_bytes().unwrap()), PRIVATE_KEY);
+
+        let verifying_key = signing_key.verifying_key().unwrap();
+        assert_eq!(hex::encode(verifying_key.to_bytes().unwrap()), public_key);
+        assert_eq!(hex::encode(signing_key.prove_possession().unwrap()), pop);
+
+        let result = sign(String::from(CONTENT), signing_key, variant.algorithm()).unwrap();
+        assert_eq!(result, base64_url::encode(&hex::decode(signature).unwrap()));
+
+        let verifying_key = BlsVerifyingKey::from_bytes(&hex::decode(public_key).unwrap()).unwrap();
+        assert!(verifying_key.variant() == variant);
+        assert!(verifying_key
+            .verify_possession(&hex::decode(pop).unwrap())
+            .unwrap());
+        assert!(!verifying_key
+            .verify_message(CONTENT.as_bytes(), &hex::decode(pop).unwrap())
+            .unwrap());
+        assert!(verify(
+            String::from(CONTENT),
+            result,
+            verifying_key,
+            variant.algorithm()
+        )
+        .unwrap());
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn bls_sign_and_verify() {
+    assert!(Algorithm::from_str("BLS12381MinSig") == Some(Algorithm::BLS12381MinSig));
+    assert_eq!(Algorithm::BLS12381MinPk.to_str(), "BLS12381MinPk");
+    assert_eq!(Algorithm::BLS12381MinPk.get_family().to_str(), "BLS");
+    assert!(Algorithm::BLS12381MinPk.to_cose_id().is_none());
+
+    for variant in [BlsVariant::MinPk, BlsVariant::MinSig] {
+        let alg = variant.algorithm();
+        let signing_key = BlsSigningKey::generate(variant).unwrap();
+        let mut private_key = signing_key.to_bytes().unwrap();
+        let mut public_key = signing_key.verifying_key().unwrap().to_bytes().unwrap();
+        assert_eq!(public_key.len(), variant.public_key_length());
+
+        let signing_key = get_signing_key(alg, &mut private_key).unwrap();
+        let signature = sign(String::from(CONTENT), signing_key, alg).unwrap();
+        assert_eq!(
+            base64_url::decode(&signature).unwrap().len(),
+            variant.signature_length()
+        );
+
+        let verifying_key = get_verifying_key(alg, &mut public_key).unwrap();
+        assert!(verify(String::from(CONTENT), signature.clone(), verifying_key, alg).unwrap());
+
+        let verifying_key = BlsVerifyingKey::from_bytes(&public_key).unwrap();
+        assert!(!verify(
+            String::from("eyJhbGciOiJCTFMxMjM4MU1pblBrIn0.eyJzdWIiOiIwIn0"),
+            signature.clone(),
+            verifying_key,
+            alg
+        )
+        .unwrap());
+
+        let other = match variant {
+            BlsVariant::MinPk => Algorithm::BLS12381MinSig,
+            BlsVariant::MinSig => Algorithm::BLS12381MinPk,
+        };
+        let verifying_key = BlsVerifyingKey::from_bytes(&public_key).unwrap();
+        match verify(String::from(CONTENT), signature, verifying_key, other) {
+            Ok(_) => assert!(false),
+            Err(error) => assert_eq!(error.to_string(), errors::KEY_ALGORITHM_MISMATCH),
+        }
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn bls_invalid_keys() {
+    match BlsSigningKey::from_ikm(BlsVariant::MinPk, &[1u8; 31], &[]) {
+        Ok(_) => assert!(false),
+        Err(error) => assert_eq!(error.to_string(), errors::BLS_IKM_TOO_SHORT),
+    }
+
+    match BlsSigningKey::from_bytes(BlsVariant::MinPk, &[0u8; 32]) {
+        Ok(_) => assert!(false),
+        Err(error) => assert_eq!(error.to_string(), errors::PRIVATE_KEY_IDENTIFICATION_ERROR),
+    }
+
+    // Point at infinity
+    let mut identity = vec![0u8; 48];
+    identity[0] = 0xc0;
+    match BlsVerifyingKey::from_bytes(&identity) {
+        Ok(_) => assert!(false),
+        Err(error) => assert_eq!(error.to_string(), errors::PUBLIC_KEY_IDENTIFICATION_ERROR),
+    }
+
+    match BlsVerifyingKey::from_bytes(&[0u8; 64]) {
+        Ok(_) => assert!(false),
+        Err(error) => assert_eq!(error.to_string(), errors::PUBLIC_KEY_IDENTIFICATION_ERROR),
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn bls_aggregation() {
+    for variant in [BlsVariant::MinPk, BlsVariant::MinSig] {
+        let (signing_keys, verifying_keys) = generate_keys(variant, 4);
+        for (signing_key, verifying_key) in signing_keys.iter().zip(verifying_keys.iter()) {
+            let proof = signing_key.prove_possession().unwrap();
+            assert!(verifying_key.verify_possession(&proof).unwrap());
+        }
+
+        // Same message
+        let message = CONTENT.as_bytes();
+        let signatures: Vec<Vec<u8>> = signing_keys
+            .iter()
+            .map(|key| key.sign_message(message).unwrap())
+            .collect();
+        let aggregate = aggregate_signatures(&signatures).unwrap();
+        assert_eq!(aggregate.len(), variant.signature_length());
+        assert!(fast_aggregate_verify(&verifying_keys, message, &aggregate).unwrap());
+        assert!(!fast_aggregate_verify(&verifying_keys, b"other message", &aggregate).unwrap());
+        assert!(!fast_aggregate_verify(&verifying_keys[1..], message, &aggregate).unwrap());
+
+        let aggregate_key = aggregate_public_keys(&verifying_keys).unwrap();
+        assert!(aggregate_key.verify_message(message, &aggregate).unwrap());
+
+        // Distinct messages
+        let messages: Vec<String> = (0..signing_keys.len())
+            .map(|index| format!("attestation {}", index))
+            .collect();
+        let signatures: Vec<Vec<u8>> = signing_keys
+            .iter()
+            .zip(messages.iter())
+            .map(|(key, message)| key.sign_message(message.as_bytes()).unwrap())
+            .collect();
+        let aggregate = aggregate_signatures(&signatures).unwrap();
+        assert!(aggregate_verify(&verifying_keys, &messages, &aggregate).unwrap());
+
+        let mut swapped = messages.clone();
+        swapped.swap(0, 1);
+        assert!(!aggregate_verify(&verifying_keys, &swapped, &aggregate).unwrap());
+
+        match aggregate_verify(&verifying_keys, &messages[1..], &aggregate) {
+            Ok(_) => assert!(false),
+            Err(error) => assert_eq!(error.to_string(), errors::BLS_AGGREGATION_INPUT),
+        }
+
+        let no_signatures: Vec<Vec<u8>> = vec![];
+        match aggregate_signatures(&no_signatures) {
+            Ok(_) => assert!(false),
+            Err(error) => assert_eq!(error.to_string(), errors::BLS_AGGREGATION_INPUT),
+        }
+
+        match fast_aggregate_verify(&[], message, &aggregate) {
+            Ok(_) => assert!(false),
+            Err(error) => assert_eq!(error.to_string(), errors::BLS_AGGREGATION_INPUT),
+        }
+    }
+
+    let (_, mut mixed) = generate_keys(BlsVariant::MinPk, 1);
+    let (_, min_sig) = generate_keys(BlsVariant::MinSig, 1);
+    mixed.extend(min_sig);
+    match aggregate_public_keys(&mixed) {
+        Ok(_) => assert!(false),
+        Err(error) => assert_eq!(error.to_string(), errors::KEY_ALGORITHM_MISMATCH),
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn bls_jwk() {
+    for (variant, public_key, signature, _) in test_vectors() {
+        let alg = variant.algorithm();
+        let public_key = hex::decode(public_key).unwrap();
+
+        let jwk = Jwk::from_public_key_bytes(alg, &public_key).unwrap();
+        assert_eq!(jwk.kty, "OKP");
+        assert_eq!(jwk.crv.as_deref(), Some(variant.policy_curve().to_str()));
+        assert_eq!(jwk.x, Some(base64_url::encode(&public_key)));
+
+        let verifying_key = AnyVerifyingKey::from_jwk(&jwk).unwrap();
+        assert!(matches!(verifying_key, AnyVerifyingKey::Bls(_)));
+        assert!(verifying_key.supported_algorithms() == vec![alg]);
+        assert!(verify(
+            String::from(CONTENT),
+            base64_url::encode(&hex::decode(signature).unwrap()),
+            verifying_key,
+            alg
+        )
+        .unwrap());
+    }
+
+    let public_key = hex::decode(PUBLIC_KEY_MIN_PK).unwrap();
+    match Jwk::from_public_key_bytes(Algorithm::BLS12381MinSig, &public_key) {
+        Ok(_) => assert!(false),
+        Err(error) => assert_eq!(error.to_string(), errors::KEY_ALGORITHM_MISMATCH),
+    }
+}
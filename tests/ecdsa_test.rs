@@ -1,14 +1,22 @@
 use fi_digital_signatures::{
     algorithms::Algorithm,
-    crypto::ecdsa::{
-        _256k::{P256kSigningKey, P256kVerifyingKey},
-        _256::{P256SigningKey, P256VerifyingKey},
-        _384::{P384SigningKey, P384VerifyingKey},
-        _512::{P512SigningKey, P512VerifyingKey},
+    crypto::{
+        ecdsa::{
+            _256k::{P256kSigningKey, P256kVerifyingKey},
+            der_signature::{
+                der_to_jose, der_to_raw, jose_to_der, raw_to_der, sign_der, verify_der,
+            },
+            _256::{P256SigningKey, P256VerifyingKey},
+            _384::{P384SigningKey, P384VerifyingKey},
+            _512::{P512SigningKey, P512VerifyingKey},
+        },
+        SignFromKey, SignPrehashed, VerifyPrehashed,
     },
-    signer::sign,
-    verifier::verify,
+    errors,
+    signer::{sign, sign_prehashed},
+    verifier::{verify, verify_prehashed},
 };
+use sha2::{Digest, Sha256, Sha384, Sha512};
 
 const PUBLIC_KEY_256: &'static str = "-----BEGIN PUBLIC KEY-----
 MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEEVs/o5+uQbTjL3chynL4wXgUg2R9
@@ -329,3 +337,291 @@ pub fn ec256k_hex_signing_and_verifying() {
         }
     })
 }
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn ecdsa_hedged_signing() {
+    let mut key_256 = P256SigningKey::from_pem(PRIVATE_KEY_256).unwrap();
+    let mut key_384 = P384SigningKey::from_pem(PRIVATE_KEY_384).unwrap();
+    let mut key_512 = P512SigningKey::from_pem(PRIVATE_KEY_512).unwrap();
+    let mut key_256k = P256kSigningKey::from_pem(PRIVATE_KEY_256K).unwrap();
+    assert!(!key_256.is_hedged() && !key_384.is_hedged());
+    assert!(!key_512.is_hedged() && !key_256k.is_hedged());
+
+    let deterministic = [
+        key_256
+            .sign(String::from(EC256_CONTENT), Algorithm::ES256)
+            .unwrap(),
+        key_384
+            .sign(String::from(EC384_CONTENT), Algorithm::ES384)
+            .unwrap(),
+        key_512
+            .sign(String::from(EC512_CONTENT), Algorithm::ES512)
+            .unwrap(),
+        key_256k
+            .sign(String::from(EC256K_CONTENT), Algorithm::ES256K)
+            .unwrap(),
+    ];
+    assert_eq!(
+        deterministic[0],
+        key_256
+            .sign(String::from(EC256_CONTENT), Algorithm::ES256)
+            .unwrap()
+    );
+
+    // P-521 nonces are random, hedged or not
+    assert_ne!(
+        deterministic[2],
+        key_512
+            .sign(String::from(EC512_CONTENT), Algorithm::ES512)
+            .unwrap()
+    );
+
+    key_256.set_hedged(true);
+    key_384.set_hedged(true);
+    key_512.set_hedged(true);
+    key_256k.set_hedged(true);
+    assert!(key_256.is_hedged());
+
+    for _ in 0..2 {
+        let hedged = [
+            key_256
+                .sign(String::from(EC256_CONTENT), Algorithm::ES256)
+                .unwrap(),
+            key_384
+                .sign(String::from(EC384_CONTENT), Algorithm::ES384)
+                .unwrap(),
+            key_512
+                .sign(String::from(EC512_CONTENT), Algorithm::ES512)
+                .unwrap(),
+            key_256k
+                .sign(String::from(EC256K_CONTENT), Algorithm::ES256K)
+                .unwrap(),
+        ];
+        for (hedged, deterministic) in hedged.iter().zip(deterministic.iter()) {
+            assert_ne!(hedged, deterministic);
+            assert_eq!(hedged.len(), deterministic.len());
+        }
+
+        let [sig_256, sig_384, sig_512, sig_256k] = hedged;
+        assert!(verify(
+            String::from(EC256_CONTENT),
+            sig_256,
+            P256VerifyingKey::from_pem(PUBLIC_KEY_256).unwrap(),
+            Algorithm::ES256
+        )
+        .unwrap());
+        assert!(verify(
+            String::from(EC384_CONTENT),
+            sig_384,
+            P384VerifyingKey::from_pem(PUBLIC_KEY_384).unwrap(),
+            Algorithm::ES384
+        )
+        .unwrap());
+        assert!(verify(
+            String::from(EC512_CONTENT),
+            sig_512,
+            P512VerifyingKey::from_pem(PUBLIC_KEY_512).unwrap(),
+            Algorithm::ES512
+        )
+        .unwrap());
+        assert!(verify(
+            String::from(EC256K_CONTENT),
+            sig_256k,
+            P256kVerifyingKey::from_pem(PUBLIC_KEY_256K).unwrap(),
+            Algorithm::ES256K
+        )
+        .unwrap());
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn ecdsa_der_conversion() {
+    let mut raw = vec![0u8; 64];
+    raw[31] = 0x01;
+    raw[32] = 0x80;
+    let mut expected = vec![0x30, 0x26, 0x02, 0x01, 0x01, 0x02, 0x21, 0x00, 0x80];
+    expected.extend_from_slice(&[0u8; 31]);
+
+    let der = raw_to_der(Algorithm::ES256, &raw).unwrap();
+    assert_eq!(der, expected);
+    assert_eq!(der_to_raw(Algorithm::ES256, &der).unwrap(), raw);
+
+    let signature = sign(
+        String::from(EC512_CONTENT),
+        P512SigningKey::from_pem(PRIVATE_KEY_512).unwrap(),
+        Algorithm::ES512,
+    )
+    .unwrap();
+    let der = jose_to_der(Algorithm::ES512, signature.as_str()).unwrap();
+    assert_eq!(der[1], 0x81);
+    assert_eq!(der_to_jose(Algorithm::ES512, &der).unwrap(), signature);
+
+    // Non-minimal integer, negative integer, trailing bytes and wrong width
+    let malformed: [&[u8]; 4] = [
+        &[0x30, 0x07, 0x02, 0x02, 0x00, 0x01, 0x02, 0x01, 0x01],
+        &[0x30, 0x06, 0x02, 0x01, 0x81, 0x02, 0x01, 0x01],
+        &[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01, 0x00],
+        &[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01],
+    ];
+    for der in malformed {
+        assert!(der_to_raw(Algorithm::ES256, der).is_err());
+    }
+    assert!(raw_to_der(Algorithm::ES256, &raw[1..]).is_err());
+    assert!(raw_to_der(Algorithm::HS256, &raw).is_err());
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn ecdsa_der_signing_and_verifying() {
+    let der_256 = sign_der(
+        String::from(EC256_CONTENT),
+        P256SigningKey::from_pem(PRIVATE_KEY_256).unwrap(),
+        Algorithm::ES256,
+    )
+    .unwrap();
+    let der_384 = sign_der(
+        String::from(EC384_CONTENT),
+        P384SigningKey::from_pem(PRIVATE_KEY_384).unwrap(),
+        Algorithm::ES384,
+    )
+    .unwrap();
+    let der_512 = sign_der(
+        String::from(EC512_CONTENT),
+        P512SigningKey::from_pem(PRIVATE_KEY_512).unwrap(),
+        Algorithm::ES512,
+    )
+    .unwrap();
+    let der_256k = sign_der(
+        String::from(EC256K_CONTENT),
+        P256kSigningKey::from_pem(PRIVATE_KEY_256K).unwrap(),
+        Algorithm::ES256K,
+    )
+    .unwrap();
+
+    assert!(verify_der(
+        String::from(EC256_CONTENT),
+        &der_256,
+        P256VerifyingKey::from_pem(PUBLIC_KEY_256).unwrap(),
+        Algorithm::ES256
+    )
+    .unwrap());
+    assert!(verify_der(
+        String::from(EC384_CONTENT),
+        &der_384,
+        P384VerifyingKey::from_pem(PUBLIC_KEY_384).unwrap(),
+        Algorithm::ES384
+    )
+    .unwrap());
+    assert!(verify_der(
+        String::from(EC512_CONTENT),
+        &der_512,
+        P512VerifyingKey::from_pem(PUBLIC_KEY_512).unwrap(),
+        Algorithm::ES512
+    )
+    .unwrap());
+    assert!(verify_der(
+        String::from(EC256K_CONTENT),
+        &der_256k,
+        P256kVerifyingKey::from_pem(PUBLIC_KEY_256K).unwrap(),
+        Algorithm::ES256K
+    )
+    .unwrap());
+
+    // The DER signature verifies through the JOSE API as well
+    assert!(verify(
+        String::from(EC256_CONTENT),
+        der_to_jose(Algorithm::ES256, &der_256).unwrap(),
+        P256VerifyingKey::from_pem(PUBLIC_KEY_256).unwrap(),
+        Algorithm::ES256
+    )
+    .unwrap());
+    assert!(verify_der(
+        String::from(EC256_CONTENT),
+        &der_256[..der_256.len() - 1],
+        P256VerifyingKey::from_pem(PUBLIC_KEY_256).unwrap(),
+        Algorithm::ES256
+    )
+    .is_err());
+}
+
+fn check_prehashed<S: SignFromKey + SignPrehashed, V: VerifyPrehashed>(
+    signing_key: impl Fn() -> S,
+    verifying_key: impl Fn() -> V,
+    alg: Algorithm,
+    content: &str,
+    digest: &[u8],
+) {
+    // Signatures of the digest verify as signatures of the content and the other way around
+    let signature = sign_prehashed(digest, signing_key(), alg).unwrap();
+    let content_signature = sign(String::from(content), signing_key(), alg).unwrap();
+    assert!(verify(
+        String::from(content),
+        signature.clone(),
+        verifying_key(),
+        alg
+    )
+    .unwrap());
+    assert!(verify_prehashed(digest, content_signature.clone(), verifying_key(), alg).unwrap());
+
+    // P-521 signing always uses a random nonce, the other curves sign deterministically
+    if alg != Algorithm::ES512 {
+        assert_eq!(signature, content_signature);
+    }
+
+    let mut other_digest = digest.to_vec();
+    other_digest[0] ^= 1;
+    assert!(!verify_prehashed(&other_digest, signature.clone(), verifying_key(), alg).unwrap());
+
+    match sign_prehashed(&digest[1..], signing_key(), alg) {
+        Ok(_) => panic!(),
+        Err(error) => assert_eq!(error.to_string(), errors::DIGEST_LENGTH_MISMATCH),
+    }
+    match verify_prehashed(&digest[1..], signature.clone(), verifying_key(), alg) {
+        Ok(_) => panic!(),
+        Err(error) => assert_eq!(error.to_string(), errors::DIGEST_LENGTH_MISMATCH),
+    }
+
+    let other_alg = match alg {
+        Algorithm::ES256 => Algorithm::ES256K,
+        _ => Algorithm::ES256,
+    };
+    match verify_prehashed(&digest[..32], signature, verifying_key(), other_alg) {
+        Ok(_) => panic!(),
+        Err(error) => assert_eq!(error.to_string(), errors::KEY_ALGORITHM_MISMATCH),
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn ecdsa_prehashed_signing_and_verifying() {
+    check_prehashed(
+        || P256SigningKey::from_pem(PRIVATE_KEY_256).unwrap(),
+        || P256VerifyingKey::from_pem(PUBLIC_KEY_256).unwrap(),
+        Algorithm::ES256,
+        EC256_CONTENT,
+        &Sha256::digest(EC256_CONTENT),
+    );
+    check_prehashed(
+        || P384SigningKey::from_pem(PRIVATE_KEY_384).unwrap(),
+        || P384VerifyingKey::from_pem(PUBLIC_KEY_384).unwrap(),
+        Algorithm::ES384,
+        EC384_CONTENT,
+        &Sha384::digest(EC384_CONTENT),
+    );
+    check_prehashed(
+        || P512SigningKey::from_pem(PRIVATE_KEY_512).unwrap(),
+        || P512VerifyingKey::from_pem(PUBLIC_KEY_512).unwrap(),
+        Algorithm::ES512,
+        EC512_CONTENT,
+        &Sha512::digest(EC512_CONTENT),
+    );
+    check_prehashed(
+        || P256kSigningKey::from_pem(PRIVATE_KEY_256K).unwrap(),
+        || P256kVerifyingKey::from_pem(PUBLIC_KEY_256K).unwrap(),
+        Algorithm::ES256K,
+        EC256K_CONTENT,
+        &Sha256::digest(EC256K_CONTENT),
+    );
+}
@@ -1,6 +1,7 @@
 use fi_digital_signatures::{
     algorithms::Algorithm,
-    crypto::eddsa::{EDDSASigningKey, EDDSAVerifyingKey},
+    crypto::eddsa::{verify_batch, EDDSASigningKey, EDDSAVerifyingKey, Ed25519Variant},
+    errors,
     signer::sign,
     verifier::verify,
 };
@@ -87,3 +88,197 @@ pub fn eddsa_hex_signing_and_verifying() {
         }
     })
 }
+
+// RFC 8032 section 7.3, test "abc"
+const PH_PRIVATE_KEY_HEX: &'static str =
+    "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42";
+const PH_PUBLIC_KEY_HEX: &'static str =
+    "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf";
+const PH_SIGNATURE_HEX: &'static str = "98a70222f0b8121aa9d30f813d683f809e462b469c7ff87639499bb94e6dae4131f85042463c2a355a2003d062adf5aaa10b8c61e636062aaad11c2a26083406";
+
+// Key of RFC 8032 section 7.2, signature created with OpenSSL
+const CTX_PRIVATE_KEY_HEX: &'static str =
+    "0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6";
+const CTX_PUBLIC_KEY_HEX: &'static str =
+    "dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292";
+const CTX_MESSAGE: &'static str = "fi-digital-signatures";
+const CTX_SIGNATURE_HEX: &'static str = "abf50ef8a60cca0bcbdead212a561dcae267f4cc119dac4d0f8f904e0ab00edfa8fb9714dbfc944c76df5629e2c9c5449cd1291331697efe867d91c0ed5cd201";
+
+#[cfg(not(feature = "wasm"))]
+fn variant_keys(
+    private_key_hex: &str,
+    public_key_hex: &str,
+    variant: Ed25519Variant,
+    context: &[u8],
+) -> (EDDSASigningKey, EDDSAVerifyingKey) {
+    let mut signing_key =
+        EDDSASigningKey::from_bytes(hex::decode(private_key_hex).unwrap().as_mut_slice()).unwrap();
+    signing_key.set_variant(variant);
+    signing_key.set_context(context).unwrap();
+
+    let mut verifying_key =
+        EDDSAVerifyingKey::from_bytes(hex::decode(public_key_hex).unwrap().as_mut_slice()).unwrap();
+    verifying_key.set_variant(variant);
+    verifying_key.set_context(context).unwrap();
+
+    (signing_key, verifying_key)
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn ed25519ph_test_vector() {
+    let (signing_key, verifying_key) = variant_keys(
+        PH_PRIVATE_KEY_HEX,
+        PH_PUBLIC_KEY_HEX,
+        Ed25519Variant::Ph,
+        b"",
+    );
+
+    let signature = sign(String::from("abc"), signing_key, Algorithm::EdDSA).unwrap();
+    assert_eq!(
+        hex::encode(base64_url::decode(&signature).unwrap()),
+        PH_SIGNATURE_HEX
+    );
+
+    assert!(verify(
+        String::from("abc"),
+        signature.clone(),
+        verifying_key,
+        Algorithm::EdDSA
+    )
+    .unwrap());
+
+    let pure_key =
+        EDDSAVerifyingKey::from_bytes(hex::decode(PH_PUBLIC_KEY_HEX).unwrap().as_mut_slice())
+            .unwrap();
+    assert_eq!(pure_key.variant(), Ed25519Variant::Pure);
+    assert_eq!(
+        "Ed25519ph".parse::<Ed25519Variant>().unwrap(),
+        Ed25519Variant::Ph
+    );
+    assert!("Ed448".parse::<Ed25519Variant>().is_err());
+    assert!(!verify(String::from("abc"), signature, pure_key, Algorithm::EdDSA).unwrap());
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn ed25519ctx_test_vector() {
+    let (signing_key, verifying_key) = variant_keys(
+        CTX_PRIVATE_KEY_HEX,
+        CTX_PUBLIC_KEY_HEX,
+        Ed25519Variant::Ctx,
+        b"foo",
+    );
+
+    let signature = sign(String::from(CTX_MESSAGE), signing_key, Algorithm::EdDSA).unwrap();
+    assert_eq!(
+        hex::encode(base64_url::decode(&signature).unwrap()),
+        CTX_SIGNATURE_HEX
+    );
+
+    assert!(verify(
+        String::from(CTX_MESSAGE),
+        signature.clone(),
+        verifying_key,
+        Algorithm::EdDSA
+    )
+    .unwrap());
+
+    let (_, other_context_key) = variant_keys(
+        CTX_PRIVATE_KEY_HEX,
+        CTX_PUBLIC_KEY_HEX,
+        Ed25519Variant::Ctx,
+        b"bar",
+    );
+    assert!(!verify(
+        String::from(CTX_MESSAGE),
+        signature,
+        other_context_key,
+        Algorithm::EdDSA
+    )
+    .unwrap());
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn ed25519ctx_context_checks() {
+    let (signing_key, _) = variant_keys(
+        CTX_PRIVATE_KEY_HEX,
+        CTX_PUBLIC_KEY_HEX,
+        Ed25519Variant::Ctx,
+        b"",
+    );
+    match sign(String::from(CTX_MESSAGE), signing_key, Algorithm::EdDSA) {
+        Ok(_) => assert!(false),
+        Err(error) => assert_eq!(error.to_string(), errors::EDDSA_CONTEXT_REQUIRED),
+    }
+
+    let mut signing_key =
+        EDDSASigningKey::from_bytes(hex::decode(CTX_PRIVATE_KEY_HEX).unwrap().as_mut_slice())
+            .unwrap();
+    match signing_key.set_context(&[0u8; 256]) {
+        Ok(_) => assert!(false),
+        Err(error) => assert_eq!(error.to_string(), errors::EDDSA_CONTEXT_TOO_LONG),
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+fn batch_keys(i: usize) -> (EDDSASigningKey, EDDSAVerifyingKey) {
+    match i % 3 {
+        0 => (
+            EDDSASigningKey::from_pem(PRIVATE_KEY).unwrap(),
+            EDDSAVerifyingKey::from_pem(PUBLIC_KEY).unwrap(),
+        ),
+        1 => variant_keys(
+            PH_PRIVATE_KEY_HEX,
+            PH_PUBLIC_KEY_HEX,
+            Ed25519Variant::Ph,
+            b"",
+        ),
+        _ => variant_keys(
+            CTX_PRIVATE_KEY_HEX,
+            CTX_PUBLIC_KEY_HEX,
+            Ed25519Variant::Ctx,
+            b"batch",
+        ),
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn ed25519_batch_verification() {
+    let messages: Vec<String> = (0..12).map(|i| format!("{}.{}", CONTENT, i)).collect();
+    let mut signatures: Vec<String> = messages
+        .iter()
+        .enumerate()
+        .map(|(i, message)| sign(message.clone(), batch_keys(i).0, Algorithm::EdDSA).unwrap())
+        .collect();
+    let keys: Vec<EDDSAVerifyingKey> = (0..12).map(|i| batch_keys(i).1).collect();
+
+    assert_eq!(
+        verify_batch(&messages, &signatures, &keys).unwrap(),
+        vec![true; 12]
+    );
+    assert!(verify_batch(&[] as &[String], &[] as &[String], &[])
+        .unwrap()
+        .is_empty());
+
+    // A signature of another message, a signature of another key and a malformed signature
+    signatures.swap(3, 6);
+    signatures[7] = signatures[8].clone();
+    signatures[10] = String::from("invalid");
+
+    let mut expected = vec![true; 12];
+    for i in [3, 6, 7, 10] {
+        expected[i] = false;
+    }
+    assert_eq!(
+        verify_batch(&messages, &signatures, &keys).unwrap(),
+        expected
+    );
+
+    match verify_batch(&messages[1..], &signatures, &keys) {
+        Ok(_) => assert!(false),
+        Err(error) => assert_eq!(error.to_string(), errors::EDDSA_BATCH_INPUT),
+    }
+}
@@ -0,0 +1,167 @@
+use fi_digital_signatures::{
+    crypto::ecdsa::{
+        _256k::{recover_verifying_key, P256kSigningKey},
+        ethereum::{
+            hash_personal_message, hash_typed_data, recover_personal_message_signer,
+            recover_typed_data_signer, verify_personal_message, verify_typed_data, TypedData,
+        },
+    },
+    errors,
+};
+
+// Private key of the web3.js account documentation
+const PRIVATE_KEY_HEX: &'static str =
+    "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+const ADDRESS: &'static str = "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23";
+
+// web3.eth.accounts.sign("Some data", PRIVATE_KEY_HEX)
+const PERSONAL_MESSAGE: &'static str = "Some data";
+const PERSONAL_MESSAGE_HASH: &'static str =
+    "1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655";
+const PERSONAL_MESSAGE_SIGNATURE: &'static str = "0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c";
+
+// Example of the EIP-712 specification, signed with keccak256("cow")
+const MAIL_PRIVATE_KEY_HEX: &'static str =
+    "c85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4";
+const MAIL_ADDRESS: &'static str = "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826";
+const MAIL_TYPED_DATA: &'static str = r#"{
+    "types": {
+        "EIP712Domain": [
+            { "name": "name", "type": "string" },
+            { "name": "version", "type": "string" },
+            { "name": "chainId", "type": "uint256" },
+            { "name": "verifyingContract", "type": "address" }
+        ],
+        "Person": [
+            { "name": "name", "type": "string" },
+            { "name": "wallet", "type": "address" }
+        ],
+        "Mail": [
+            { "name": "from", "type": "Person" },
+            { "name": "to", "type": "Person" },
+            { "name": "contents", "type": "string" }
+        ]
+    },
+    "primaryType": "Mail",
+    "domain": {
+        "name": "Ether Mail",
+        "version": "1",
+        "chainId": 1,
+        "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+    },
+    "message": {
+        "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+        "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+        "contents": "Hello, Bob!"
+    }
+}"#;
+const MAIL_DOMAIN_SEPARATOR: &'static str =
+    "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f";
+const MAIL_STRUCT_HASH: &'static str =
+    "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e";
+const MAIL_HASH: &'static str = "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2";
+const MAIL_SIGNATURE: &'static str = "0x4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b915621c";
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn personal_sign_test_vector() {
+    assert_eq!(
+        hex::encode(hash_personal_message(PERSONAL_MESSAGE.as_bytes())),
+        PERSONAL_MESSAGE_HASH
+    );
+
+    let signing_key = P256kSigningKey::from_bytes(&hex::decode(PRIVATE_KEY_HEX).unwrap()).unwrap();
+    let signature = signing_key
+        .sign_personal_message(PERSONAL_MESSAGE.as_bytes())
+        .unwrap();
+    assert_eq!(signature, PERSONAL_MESSAGE_SIGNATURE);
+
+    assert!(verify_personal_message(PERSONAL_MESSAGE.as_bytes(), &signature, ADDRESS).unwrap());
+    assert!(!verify_personal_message(b"tampered", &signature, ADDRESS).unwrap());
+    assert!(
+        !verify_personal_message(PERSONAL_MESSAGE.as_bytes(), &signature, MAIL_ADDRESS).unwrap()
+    );
+    assert_eq!(
+        recover_personal_message_signer(PERSONAL_MESSAGE.as_bytes(), &signature).unwrap(),
+        ADDRESS
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn typed_data_test_vector() {
+    let typed_data = TypedData::from_json(MAIL_TYPED_DATA).unwrap();
+    assert_eq!(
+        typed_data.encode_type("Mail").unwrap(),
+        "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+    );
+    assert_eq!(
+        hex::encode(typed_data.domain_separator().unwrap()),
+        MAIL_DOMAIN_SEPARATOR
+    );
+    assert_eq!(
+        hex::encode(typed_data.hash_struct("Mail", &typed_data.message).unwrap()),
+        MAIL_STRUCT_HASH
+    );
+    assert_eq!(
+        hex::encode(hash_typed_data(MAIL_TYPED_DATA).unwrap()),
+        MAIL_HASH
+    );
+
+    let signing_key =
+        P256kSigningKey::from_bytes(&hex::decode(MAIL_PRIVATE_KEY_HEX).unwrap()).unwrap();
+    let signature = signing_key.sign_typed_data(MAIL_TYPED_DATA).unwrap();
+    assert_eq!(signature, MAIL_SIGNATURE);
+
+    assert!(verify_typed_data(MAIL_TYPED_DATA, &signature, MAIL_ADDRESS).unwrap());
+    assert!(!verify_typed_data(MAIL_TYPED_DATA, &signature, ADDRESS).unwrap());
+    assert_eq!(
+        recover_typed_data_signer(MAIL_TYPED_DATA, &signature).unwrap(),
+        MAIL_ADDRESS
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn typed_data_domain_inference_and_errors() {
+    let without_domain_type = MAIL_TYPED_DATA.replace(
+        r#""EIP712Domain": [
+            { "name": "name", "type": "string" },
+            { "name": "version", "type": "string" },
+            { "name": "chainId", "type": "uint256" },
+            { "name": "verifyingContract", "type": "address" }
+        ],"#,
+        "",
+    );
+    assert_eq!(
+        hex::encode(hash_typed_data(without_domain_type.as_str()).unwrap()),
+        MAIL_HASH
+    );
+
+    let invalid_address =
+        MAIL_TYPED_DATA.replace("0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB", "0x1234");
+    match hash_typed_data(invalid_address.as_str()) {
+        Ok(_) => assert!(false),
+        Err(error) => assert_eq!(error.to_string(), errors::INVALID_ADDRESS),
+    }
+
+    let unknown_type =
+        MAIL_TYPED_DATA.replace(r#""primaryType": "Mail""#, r#""primaryType": "Letter""#);
+    match hash_typed_data(unknown_type.as_str()) {
+        Ok(_) => assert!(false),
+        Err(error) => assert_eq!(error.to_string(), errors::TYPED_DATA_ERROR),
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn checksum_address() {
+    let signing_key = P256kSigningKey::from_bytes(&hex::decode(PRIVATE_KEY_HEX).unwrap()).unwrap();
+    let signature = signing_key
+        .sign_recoverable(String::from(PERSONAL_MESSAGE))
+        .unwrap();
+    let verifying_key = recover_verifying_key(String::from(PERSONAL_MESSAGE), signature).unwrap();
+
+    assert_eq!(verifying_key.checksum_address().unwrap(), ADDRESS);
+    assert_eq!(verifying_key.address().unwrap(), ADDRESS.to_lowercase());
+}
@@ -2,7 +2,8 @@ use fi_digital_signatures::{
     algorithms::Algorithm, crypto::hmac::HMACKey, signer::sign, verifier::verify,
 };
 
-const PASS_KEY: &'static str = "password for testing purposes.";
+const PASS_KEY: &'static str = "long enough password for testing purposes.";
+const SHORT_PASS_KEY: &'static str = "short password for testing.";
 
 const HMAC256_CONTENT: &'static str=  "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiaWF0IjoxNTE2MjM5MDIyfQ";
 const HMAC384_CONTENT: &'static str=  "eyJhbGciOiJIUzM4NCIsInR5cCI6IkpXVCJ9.eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiaWF0IjoxNTE2MjM5MDIyfQ";
@@ -46,3 +47,111 @@ pub fn hmac512_signing_and_verifying() {
 
     assert!(verify(String::from(HMAC512_CONTENT), sig, key, Algorithm::HS512).unwrap());
 }
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn hmac_binary_key_signing_and_verifying() {
+    let key_bytes: Vec<u8> = (0..64).collect();
+    let key = HMACKey::from_bytes(key_bytes.as_slice());
+
+    let sig = sign(String::from(HMAC512_CONTENT), key.clone(), Algorithm::HS512).unwrap();
+
+    let hex_key = HMACKey::from_hex(hex::encode(&key_bytes).as_str()).unwrap();
+    assert!(verify(String::from(HMAC512_CONTENT), sig.clone(), hex_key, Algorithm::HS512).unwrap());
+
+    let jwk_key = HMACKey::from_base64_url(base64_url::encode(&key_bytes).as_str()).unwrap();
+    assert!(verify(String::from(HMAC512_CONTENT), sig, jwk_key, Algorithm::HS512).unwrap());
+
+    assert!(HMACKey::from_hex("not hex").is_err());
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn hmac_strict_mode_rejects_short_keys() {
+    let mut key = HMACKey::from_bytes(&[7u8; 40]);
+    key.set_strict(true);
+
+    // 40 byte secret is enough for HS256 but too short for HS384
+    assert!(sign(String::from(HMAC256_CONTENT), key.clone(), Algorithm::HS256).is_ok());
+    assert!(sign(String::from(HMAC384_CONTENT), key.clone(), Algorithm::HS384).is_err());
+    assert!(verify(
+        String::from(HMAC384_CONTENT),
+        String::from("c2lnbmF0dXJl"),
+        key,
+        Algorithm::HS384
+    )
+    .is_err());
+
+    let mut key = HMACKey::from_bytes(&[7u8; 48]);
+    key.set_strict(true);
+
+    assert!(sign(String::from(HMAC384_CONTENT), key.clone(), Algorithm::HS384).is_ok());
+    assert!(sign(String::from(HMAC512_CONTENT), key, Algorithm::HS512).is_err());
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn hmac_key_policy_rejects_short_secret() {
+    let key = HMACKey::new(String::from(SHORT_PASS_KEY));
+
+    // The key policy applies to signing as well as verifying
+    assert!(sign(String::from(HMAC256_CONTENT), key.clone(), Algorithm::HS256).is_err());
+
+    let sig = sign(
+        String::from(HMAC256_CONTENT),
+        HMACKey::new(String::from(PASS_KEY)),
+        Algorithm::HS256,
+    )
+    .unwrap();
+    assert!(verify(String::from(HMAC256_CONTENT), sig, key, Algorithm::HS256).is_err());
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn hmac_key_derivation() {
+    // RFC 5869 test case 1, first 32 bytes of the OKM
+    let derived = HMACKey::derive_hkdf(
+        &[0x0b; 22],
+        &hex::decode("000102030405060708090a0b0c").unwrap(),
+        &hex::decode("f0f1f2f3f4f5f6f7f8f9").unwrap(),
+        Algorithm::HS256,
+    )
+    .unwrap();
+    let expected =
+        HMACKey::from_hex("3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf")
+            .unwrap();
+    assert_eq!(derived.key_length(), 32);
+
+    let sig = sign(String::from(HMAC256_CONTENT), derived, Algorithm::HS256).unwrap();
+    assert!(verify(
+        String::from(HMAC256_CONTENT),
+        sig,
+        expected,
+        Algorithm::HS256
+    )
+    .unwrap());
+
+    // RFC 7914 section 11 PBKDF2-HMAC-SHA256 vector, first 32 bytes
+    let derived = HMACKey::derive_pbkdf2(b"passwd", b"salt", 1, Algorithm::HS256).unwrap();
+    let expected =
+        HMACKey::from_hex("55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc")
+            .unwrap();
+
+    let sig = sign(String::from(HMAC256_CONTENT), derived, Algorithm::HS256).unwrap();
+    assert!(verify(
+        String::from(HMAC256_CONTENT),
+        sig,
+        expected,
+        Algorithm::HS256
+    )
+    .unwrap());
+
+    let derived =
+        HMACKey::derive_hkdf(b"master secret", b"salt", b"tenant-1", Algorithm::HS512).unwrap();
+    assert_eq!(derived.key_length(), 64);
+    let derived = HMACKey::derive_pbkdf2(b"password", b"salt", 10, Algorithm::HS384).unwrap();
+    assert_eq!(derived.key_length(), 48);
+
+    assert!(HMACKey::derive_pbkdf2(b"password", b"salt", 0, Algorithm::HS256).is_err());
+    assert!(HMACKey::derive_hkdf(b"master", b"salt", b"info", Algorithm::ES256).is_err());
+}
@@ -0,0 +1,201 @@
+use chrono::Utc;
+use fi_digital_signatures::{
+    algorithms::Algorithm,
+    errors,
+    jwt::{Header, Payload, JWT},
+    keystore::{KeyStatus, Keystore},
+    signer::sign,
+    verifier::verify,
+};
+use serde_json::json;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+
+const PASSPHRASE: &'static str = "keystore passphrase for testing";
+const ITERATIONS: u32 = 1000;
+
+const CONTENT: &'static str = "eyJhbGciOiJFUzI1NiIsInR5cCI6IkpXVCJ9.eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiYWRtaW4iOnRydWUsImlhdCI6MTUxNjIzOTAyMn0";
+
+fn keystore_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "fi-digital-signatures-{}-{}.json",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn signed_token(keystore: &Keystore) -> String {
+    let mut jwt = JWT::new(
+        Header::new(String::new(), Algorithm::ES256),
+        Payload(json!({ "sub": "1234567890", "exp": Utc::now().timestamp() + 60 })),
+        None,
+    );
+    jwt.sign(keystore.active_signing_key(Algorithm::ES256).unwrap())
+        .unwrap();
+    jwt.to_token().unwrap()
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn keystore_rotation() {
+    let path = keystore_path("rotation");
+
+    let mut keystore = Keystore::create_with_iterations(&path, PASSPHRASE, ITERATIONS).unwrap();
+    let old_kid = keystore.generate(Algorithm::ES256).unwrap();
+    keystore.save().unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains(old_kid.as_str()));
+    assert!(content.contains("\"status\": \"active\""));
+
+    let old_token = signed_token(&keystore);
+
+    #[cfg(unix)]
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).unwrap();
+
+    let mut keystore = Keystore::open(&path, PASSPHRASE).unwrap();
+    let new_kid = keystore.rotate(Algorithm::ES256, 3600).unwrap();
+    keystore.save().unwrap();
+
+    // Saving renames a temporary file over the keystore, keeping the permissions of the file
+    let mut temp_name = path.file_name().unwrap().to_os_string();
+    temp_name.push(format!(".{}.tmp", std::process::id()));
+    assert!(!path.with_file_name(temp_name).exists());
+    #[cfg(unix)]
+    assert_eq!(
+        std::fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+        0o600
+    );
+
+    let keystore = Keystore::open(&path, PASSPHRASE).unwrap();
+    assert_eq!(
+        keystore.status(old_kid.as_str()).unwrap(),
+        KeyStatus::Retiring
+    );
+    assert_eq!(
+        keystore.status(new_kid.as_str()).unwrap(),
+        KeyStatus::Active
+    );
+
+    // Tokens of the demoted key are still accepted
+    let (_, verified) = JWT::validate_token(
+        old_token.as_str(),
+        keystore.verifying_key(old_kid.as_str()).unwrap(),
+    )
+    .unwrap();
+    assert!(verified);
+
+    let new_token = signed_token(&keystore);
+    let (jwt, verified) = JWT::validate_token(
+        new_token.as_str(),
+        keystore.verifying_key(new_kid.as_str()).unwrap(),
+    )
+    .unwrap();
+    assert!(verified);
+    assert_eq!(jwt.to_token().unwrap(), new_token);
+
+    let jwk_set = keystore.jwk_set().unwrap();
+    assert_eq!(jwk_set.keys.len(), 2);
+    let jwk = jwk_set.find(old_kid.as_str()).unwrap();
+    assert_eq!(jwk.kty, "EC");
+    assert_eq!(jwk.crv.as_deref(), Some("P-256"));
+    assert!(jwk_set.to_json().unwrap().contains(new_kid.as_str()));
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn keystore_revoke_and_purge() {
+    let path = keystore_path("purge");
+
+    let mut keystore = Keystore::create_with_iterations(&path, PASSPHRASE, ITERATIONS).unwrap();
+    let eddsa_kid = keystore.generate(Algorithm::EdDSA).unwrap();
+    let hmac_kid = keystore.generate(Algorithm::HS256).unwrap();
+    let expired_kid = keystore.generate(Algorithm::ES256).unwrap();
+
+    keystore
+        .demote(expired_kid.as_str(), Utc::now().timestamp() - 60)
+        .unwrap();
+    keystore.revoke(eddsa_kid.as_str()).unwrap();
+
+    let error = keystore.verifying_key(eddsa_kid.as_str()).err().unwrap();
+    assert_eq!(error.to_string(), errors::KEY_REVOKED);
+    assert!(keystore.jwk_set().unwrap().keys.is_empty());
+
+    let signature = sign(
+        String::from(CONTENT),
+        keystore.active_signing_key(Algorithm::HS256).unwrap(),
+        Algorithm::HS256,
+    )
+    .unwrap();
+    assert!(verify(
+        String::from(CONTENT),
+        signature,
+        keystore.verifying_key(hmac_kid.as_str()).unwrap(),
+        Algorithm::HS256
+    )
+    .unwrap());
+
+    let mut purged = keystore.purge_expired();
+    purged.sort();
+    let mut expected = vec![eddsa_kid, expired_kid];
+    expected.sort();
+    assert_eq!(purged, expected);
+    assert_eq!(keystore.kids(), vec![hmac_kid]);
+
+    keystore.save().unwrap();
+    let error = Keystore::open(&path, "wrong passphrase").err().unwrap();
+    assert_eq!(error.to_string(), errors::KEYSTORE_DECRYPTION_FAILED);
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn keystore_rejects_edited_keys() {
+    let path = keystore_path("edited");
+
+    let mut keystore = Keystore::create_with_iterations(&path, PASSPHRASE, ITERATIONS).unwrap();
+    let revoked_kid = keystore.generate(Algorithm::ES256).unwrap();
+    keystore.generate(Algorithm::ES256).unwrap();
+    keystore.revoke(revoked_kid.as_str()).unwrap();
+    keystore.save().unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    let file: serde_json::Value = serde_json::from_str(content.as_str()).unwrap();
+    assert!(Keystore::open(&path, PASSPHRASE).is_ok());
+
+    let attacker_key = json!(base64_url::encode(
+        &p256::ecdsa::SigningKey::random(&mut rand::rngs::OsRng)
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes()
+    ));
+    let edits: Vec<(&str, serde_json::Value)> = vec![
+        ("status", json!("active")),
+        ("not_after", json!(Utc::now().timestamp() + 3600)),
+        ("public_key", attacker_key),
+    ];
+
+    for (field, value) in edits {
+        let mut edited = file.clone();
+        edited["keys"][0][field] = value;
+        std::fs::write(&path, serde_json::to_string_pretty(&edited).unwrap()).unwrap();
+
+        let error = Keystore::open(&path, PASSPHRASE).err().unwrap();
+        assert_eq!(error.to_string(), errors::KEYSTORE_INTEGRITY_ERROR);
+    }
+
+    // Dropping a key is detected as well
+    let mut edited = file.clone();
+    edited["keys"].as_array_mut().unwrap().remove(0);
+    std::fs::write(&path, serde_json::to_string_pretty(&edited).unwrap()).unwrap();
+    let error = Keystore::open(&path, PASSPHRASE).err().unwrap();
+    assert_eq!(error.to_string(), errors::KEYSTORE_INTEGRITY_ERROR);
+
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,81 @@
+use fi_digital_signatures::{
+    crypto::ecdsa::_256k::{
+        recover_verifying_key, verify_recoverable, verify_recoverable_address, P256kSigningKey,
+        P256kVerifyingKey, RECOVERABLE_SIGNATURE_LENGTH,
+    },
+    errors,
+};
+
+// Private key of the web3.js account documentation
+const PRIVATE_KEY_HEX: &'static str =
+    "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+const ADDRESS: &'static str = "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23";
+
+const OTHER_PUBLIC_KEY_HEX: &'static str =
+    "04a7c59e2772e9b9fc609e354cd07e78b7246a86af68ceb1bb324089eefe61ca70b1c5a7f71f07614b9b63ba91f327a4422f95661c74d9a01fb8a84c9207e622f6";
+
+const CONTENT: &'static str = "Recoverable secp256k1 signature";
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn recoverable_signature_recovers_the_key() {
+    let signing_key = P256kSigningKey::from_bytes(&hex::decode(PRIVATE_KEY_HEX).unwrap()).unwrap();
+    let signature = signing_key.sign_recoverable(String::from(CONTENT)).unwrap();
+
+    let signature_bytes = base64_url::decode(&signature).unwrap();
+    assert_eq!(signature_bytes.len(), RECOVERABLE_SIGNATURE_LENGTH);
+    assert!(signature_bytes[64] <= 1);
+
+    let recovered_key = recover_verifying_key(String::from(CONTENT), signature.clone()).unwrap();
+    assert_eq!(recovered_key.address().unwrap(), ADDRESS.to_lowercase());
+
+    let mut ethereum_signature = signature_bytes.clone();
+    ethereum_signature[64] += 27;
+    let recovered_key = recover_verifying_key(
+        String::from(CONTENT),
+        base64_url::encode(&ethereum_signature),
+    )
+    .unwrap();
+    assert_eq!(recovered_key.address().unwrap(), ADDRESS.to_lowercase());
+
+    let mut invalid_signature = signature_bytes;
+    invalid_signature[64] = 4;
+    match recover_verifying_key(
+        String::from(CONTENT),
+        base64_url::encode(&invalid_signature),
+    ) {
+        Ok(_) => assert!(false),
+        Err(error) => assert_eq!(error.to_string(), errors::SIGNATURE_IDENTIFICATION_FAILED),
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+pub fn recoverable_signature_verification() {
+    let signing_key = P256kSigningKey::from_bytes(&hex::decode(PRIVATE_KEY_HEX).unwrap()).unwrap();
+    let signature = signing_key.sign_recoverable(String::from(CONTENT)).unwrap();
+
+    let expected_key = recover_verifying_key(String::from(CONTENT), signature.clone()).unwrap();
+    let other_key =
+        P256kVerifyingKey::from_bytes(&hex::decode(OTHER_PUBLIC_KEY_HEX).unwrap()).unwrap();
+    assert!(!expected_key.is_same_key(&other_key).unwrap());
+
+    assert!(verify_recoverable(String::from(CONTENT), signature.clone(), &expected_key).unwrap());
+    assert!(!verify_recoverable(String::from(CONTENT), signature.clone(), &other_key).unwrap());
+    assert!(
+        !verify_recoverable(String::from("tampered"), signature.clone(), &expected_key).unwrap()
+    );
+
+    assert!(verify_recoverable_address(String::from(CONTENT), signature.clone(), ADDRESS).unwrap());
+    assert!(!verify_recoverable_address(
+        String::from(CONTENT),
+        signature.clone(),
+        other_key.address().unwrap().as_str()
+    )
+    .unwrap());
+
+    match verify_recoverable_address(String::from(CONTENT), signature, "0x1234") {
+        Ok(_) => assert!(false),
+        Err(error) => assert_eq!(error.to_string(), errors::INVALID_ADDRESS),
+    }
+}